@@ -2,8 +2,8 @@ use serde::Serialize;
 use std::collections::HashMap;
 
 use amlich_api::{
-    get_day_info_for_date, get_day_insight_for_date, get_holidays, DayInfoDto, DayInsightDto,
-    HolidayDto,
+    get_day_info_for_date, get_day_insight_for_date, get_holidays, get_month_days, DayInfoDto,
+    DayInsightDto, HolidayDto,
 };
 
 #[derive(Debug, Serialize, Clone)]
@@ -132,15 +132,9 @@ fn get_month_data(month: u32, year: i32) -> Result<MonthData, String> {
         }
     }
 
-    for day in 1..=31 {
-        let date = chrono::NaiveDate::from_ymd_opt(year, month, day as u32);
-        if date.is_none() {
-            break;
-        }
-
-        let holiday_list = holidays_by_day.remove(&day).unwrap_or_default();
-        let info = get_day_info_for_date(day, month as i32, year)?;
-        if day == 1 {
+    for info in get_month_days(month as i32, year, None)? {
+        let holiday_list = holidays_by_day.remove(&info.solar.day).unwrap_or_default();
+        if info.solar.day == 1 {
             first_weekday = info.solar.day_of_week;
         }
         days.push(to_day_cell(info, holiday_list));
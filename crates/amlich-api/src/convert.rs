@@ -1,17 +1,19 @@
 use crate::dto::{
-    CanChiDto, CanChiInfoDto, CanInsightDto, ChiInsightDto, DayConflictDto, DayDeityDto,
-    DayElementDto, DayFortuneDto, DayGuidanceDto, DayInfoDto, DayStarDto, DayStarsDto, DayTabooDto,
-    ElementInsightDto, FestivalInsightDto, FoodInsightDto, GioHoangDaoDto, HolidayDto,
-    HolidayInsightDto, HourInfoDto, LocalizedListDto, LocalizedTextDto, LunarDto, NguHanhDto,
-    ProverbInsightDto, RegionsInsightDto, RuleEvidenceDto, SolarDto, StarRuleEvidenceDto,
-    TabooInsightDto, TietKhiDto, TietKhiInsightDto, TravelDirectionDto, TrucDto, XungHopDto,
+    ActivityFactorDto, ActivityFilterHitDto, ActivityScoreDto, CanChiDto, CanChiInfoDto,
+    CanInsightDto, ChiInsightDto, DayConflictDto, DayDeityDto, DayElementDto, DayFortuneDto,
+    DayGuidanceDto, DayInfoDto, DayStarDto, DayStarsDto, DayTabooDto, ElementInsightDto,
+    FestivalInsightDto, FoodInsightDto, GioHoangDaoDto, GoodDayDto, GoodHourDto, HolidayDto,
+    HolidayInsightDto, HourInfoDto, LocalizedListDto, LocalizedTextDto, LocationDto, LunarDto,
+    NguHanhDto, PersonDayMatchDto, ProverbInsightDto, RegionsInsightDto, RuleEvidenceDto, SolarDto,
+    StarRuleEvidenceDto, SunTimesDto, TabooInsightDto, TietKhiDto, TietKhiInsightDto,
+    TravelDirectionDto, TrucDto, VariantComparisonDto, VariantDateDto, XungHopDto,
 };
 
 impl From<&amlich_core::NguHanh> for NguHanhDto {
     fn from(value: &amlich_core::NguHanh) -> Self {
         Self {
-            can: value.can.clone(),
-            chi: value.chi.clone(),
+            can: value.can.to_string(),
+            chi: value.chi.to_string(),
         }
     }
 }
@@ -19,10 +21,10 @@ impl From<&amlich_core::NguHanh> for NguHanhDto {
 impl From<&amlich_core::CanChi> for CanChiDto {
     fn from(value: &amlich_core::CanChi) -> Self {
         Self {
-            can_index: value.can_index,
-            chi_index: value.chi_index,
-            can: value.can.clone(),
-            chi: value.chi.clone(),
+            can_index: value.can.index(),
+            chi_index: value.chi.index(),
+            can: value.can.to_string(),
+            chi: value.chi.to_string(),
             full: value.full.clone(),
             con_giap: value.con_giap.clone(),
             ngu_hanh: NguHanhDto::from(&value.ngu_hanh),
@@ -66,6 +68,43 @@ impl From<&amlich_core::LunarInfo> for LunarDto {
     }
 }
 
+impl From<&amlich_core::variant::VariantDate> for VariantDateDto {
+    fn from(value: &amlich_core::variant::VariantDate) -> Self {
+        let lunar = &value.lunar;
+        Self {
+            variant: value.variant.id().to_string(),
+            name: value.variant.name().to_string(),
+            time_zone: value.variant.time_zone(),
+            lunar: LunarDto {
+                day: lunar.day,
+                month: lunar.month,
+                year: lunar.year,
+                is_leap_month: lunar.is_leap,
+                date_string: format!(
+                    "{}/{}/{}{}",
+                    lunar.day,
+                    lunar.month,
+                    lunar.year,
+                    if lunar.is_leap { " (nhuận)" } else { "" }
+                ),
+            },
+            month_name: value.month_name.clone(),
+            year_canchi: CanChiDto::from(&value.year_canchi),
+            zodiac: value.zodiac.clone(),
+        }
+    }
+}
+
+impl From<&amlich_core::variant::VariantComparison> for VariantComparisonDto {
+    fn from(value: &amlich_core::variant::VariantComparison) -> Self {
+        Self {
+            vietnamese: VariantDateDto::from(&value.vietnamese),
+            chinese: VariantDateDto::from(&value.chinese),
+            differs: value.differs,
+        }
+    }
+}
+
 impl From<&amlich_core::tietkhi::SolarTerm> for TietKhiDto {
     fn from(value: &amlich_core::tietkhi::SolarTerm) -> Self {
         Self {
@@ -83,10 +122,14 @@ impl From<&amlich_core::gio_hoang_dao::HourInfo> for HourInfoDto {
     fn from(value: &amlich_core::gio_hoang_dao::HourInfo) -> Self {
         Self {
             hour_index: value.hour_index,
-            hour_chi: value.hour_chi.clone(),
+            hour_chi: value.hour_chi.to_string(),
             time_range: value.time_range.clone(),
+            start_minute: value.start_minute,
             star: value.star.clone(),
             is_good: value.is_good,
+            canchi: CanChiDto::from(&value.canchi),
+            na_am: value.na_am.clone(),
+            tuoi_xung: value.tuoi_xung.clone(),
         }
     }
 }
@@ -94,7 +137,7 @@ impl From<&amlich_core::gio_hoang_dao::HourInfo> for HourInfoDto {
 impl From<&amlich_core::gio_hoang_dao::GioHoangDao> for GioHoangDaoDto {
     fn from(value: &amlich_core::gio_hoang_dao::GioHoangDao) -> Self {
         Self {
-            day_chi: value.day_chi.clone(),
+            day_chi: value.day_chi.to_string(),
             good_hour_count: value.good_hour_count,
             good_hours: value.good_hours.iter().map(HourInfoDto::from).collect(),
             all_hours: value.all_hours.iter().map(HourInfoDto::from).collect(),
@@ -107,9 +150,9 @@ impl From<&amlich_core::almanac::types::DayElement> for DayElementDto {
     fn from(value: &amlich_core::almanac::types::DayElement) -> Self {
         Self {
             na_am: value.na_am.clone(),
-            element: value.element.clone(),
-            can_element: value.can_element.clone(),
-            chi_element: value.chi_element.clone(),
+            element: value.element.to_string(),
+            can_element: value.can_element.to_string(),
+            chi_element: value.chi_element.to_string(),
             evidence: value.evidence.as_ref().map(RuleEvidenceDto::from),
         }
     }
@@ -128,7 +171,7 @@ impl From<&amlich_core::almanac::types::RuleEvidence> for RuleEvidenceDto {
 impl From<&amlich_core::almanac::types::DayConflict> for DayConflictDto {
     fn from(value: &amlich_core::almanac::types::DayConflict) -> Self {
         Self {
-            opposing_chi: value.opposing_chi.clone(),
+            opposing_chi: value.opposing_chi.to_string(),
             opposing_con_giap: value.opposing_con_giap.clone(),
             tuoi_xung: value.tuoi_xung.clone(),
             sat_huong: value.sat_huong.clone(),
@@ -213,9 +256,9 @@ impl From<&amlich_core::almanac::types::DayStars> for DayStarsDto {
 impl From<&amlich_core::almanac::types::XungHopResult> for XungHopDto {
     fn from(value: &amlich_core::almanac::types::XungHopResult) -> Self {
         Self {
-            luc_xung: value.luc_xung.clone(),
-            tam_hop: value.tam_hop.clone(),
-            tu_hanh_xung: value.tu_hanh_xung.clone(),
+            luc_xung: value.luc_xung.to_string(),
+            tam_hop: value.tam_hop.iter().map(ToString::to_string).collect(),
+            tu_hanh_xung: value.tu_hanh_xung.iter().map(ToString::to_string).collect(),
         }
     }
 }
@@ -289,6 +332,42 @@ impl From<&amlich_core::DayInfo> for DayInfoDto {
             tiet_khi: TietKhiDto::from(&value.tiet_khi),
             gio_hoang_dao: GioHoangDaoDto::from(&value.gio_hoang_dao),
             day_fortune: Some(DayFortuneDto::from(&value.day_fortune)),
+            sun_times: value.sun_times.as_ref().map(SunTimesDto::from),
+        }
+    }
+}
+
+impl From<&amlich_core::location::Location> for LocationDto {
+    fn from(value: &amlich_core::location::Location) -> Self {
+        Self {
+            latitude: value.latitude,
+            longitude: value.longitude,
+            elevation: value.elevation,
+        }
+    }
+}
+
+impl From<&LocationDto> for amlich_core::location::Location {
+    fn from(value: &LocationDto) -> Self {
+        amlich_core::location::Location::new(value.latitude, value.longitude)
+            .with_elevation(value.elevation)
+    }
+}
+
+fn format_clock(time: &amlich_core::julian::JulianDateTime) -> String {
+    format!("{:02}:{:02}", time.hour, time.minute)
+}
+
+impl From<&amlich_core::sun_times::SunTimes> for SunTimesDto {
+    fn from(value: &amlich_core::sun_times::SunTimes) -> Self {
+        Self {
+            location: LocationDto::from(&value.location),
+            sunrise: value.sunrise.as_ref().map(format_clock),
+            sunset: value.sunset.as_ref().map(format_clock),
+            solar_noon: format_clock(&value.solar_noon),
+            civil_dawn: value.civil_dawn.as_ref().map(format_clock),
+            civil_dusk: value.civil_dusk.as_ref().map(format_clock),
+            day_length_minutes: value.day_length_minutes,
         }
     }
 }
@@ -480,3 +559,93 @@ impl From<&amlich_core::insight_data::TietKhiInsight> for TietKhiInsightDto {
         }
     }
 }
+
+impl From<&amlich_core::almanac::types::ActivityFactor> for ActivityFactorDto {
+    fn from(value: &amlich_core::almanac::types::ActivityFactor) -> Self {
+        Self {
+            factor_id: value.factor_id.clone(),
+            points: value.points,
+            reason: value.reason.clone(),
+        }
+    }
+}
+
+impl From<&amlich_core::almanac::types::ActivityFilterHit> for ActivityFilterHitDto {
+    fn from(value: &amlich_core::almanac::types::ActivityFilterHit) -> Self {
+        Self {
+            filter_id: value.filter_id.clone(),
+            reason: value.reason.clone(),
+            evidence: value.evidence.as_ref().map(RuleEvidenceDto::from),
+        }
+    }
+}
+
+impl From<&amlich_core::almanac::types::ActivityScore> for ActivityScoreDto {
+    fn from(value: &amlich_core::almanac::types::ActivityScore) -> Self {
+        let verdict = match value.verdict {
+            amlich_core::almanac::types::ActivityVerdict::Good => "good",
+            amlich_core::almanac::types::ActivityVerdict::Fair => "fair",
+            amlich_core::almanac::types::ActivityVerdict::Poor => "poor",
+            amlich_core::almanac::types::ActivityVerdict::Rejected => "rejected",
+        }
+        .to_string();
+
+        Self {
+            activity: value.activity.id().to_string(),
+            activity_name: value.activity_name.clone(),
+            policy_version: value.policy_version.clone(),
+            score: value.score,
+            verdict,
+            hard_filters: value
+                .hard_filters
+                .iter()
+                .map(ActivityFilterHitDto::from)
+                .collect(),
+            factors: value.factors.iter().map(ActivityFactorDto::from).collect(),
+            evidence: value.evidence.as_ref().map(RuleEvidenceDto::from),
+        }
+    }
+}
+
+impl From<&amlich_core::almanac::types::PersonDayEvaluation> for PersonDayMatchDto {
+    fn from(value: &amlich_core::almanac::types::PersonDayEvaluation) -> Self {
+        let verdict = match value.day.verdict {
+            amlich_core::almanac::types::PersonVerdict::Xung => "xung",
+            amlich_core::almanac::types::PersonVerdict::Hop => "hop",
+            amlich_core::almanac::types::PersonVerdict::Binh => "binh",
+        }
+        .to_string();
+
+        Self {
+            tuoi: format!("{} {}", value.birth_year_can, value.birth_year_chi),
+            verdict,
+            reasons: value.day.reasons.clone(),
+        }
+    }
+}
+
+impl From<&amlich_core::almanac::finder::GoodHour> for GoodHourDto {
+    fn from(value: &amlich_core::almanac::finder::GoodHour) -> Self {
+        Self {
+            hour_index: value.hour.hour_index,
+            hour_chi: value.hour.hour_chi.to_string(),
+            time_range: value.hour.time_range.clone(),
+            star: value.hour.star.clone(),
+            reasons: value.reasons.clone(),
+        }
+    }
+}
+
+impl From<&amlich_core::almanac::finder::GoodDay> for GoodDayDto {
+    fn from(value: &amlich_core::almanac::finder::GoodDay) -> Self {
+        Self {
+            solar: SolarDto::from(&value.info.solar),
+            lunar: LunarDto::from(&value.info.lunar),
+            day_canchi: value.info.canchi.day.full.clone(),
+            score: ActivityScoreDto::from(&value.score),
+            persons: value.persons.iter().map(PersonDayMatchDto::from).collect(),
+            good_hours: value.hours.iter().map(GoodHourDto::from).collect(),
+            reasons: value.reasons.clone(),
+        }
+    }
+}
@@ -1,11 +1,59 @@
-use serde::{Deserialize, Serialize};
+use amlich_core::timezone::TimeZone;
+use serde::{Deserialize, Deserializer, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct DateQuery {
     pub day: i32,
     pub month: i32,
     pub year: i32,
-    pub timezone: Option<f64>,
+    /// UTC offset ("+07:00", "UTC-8"), IANA zone ("Asia/Ho_Chi_Minh") or
+    /// "historical-vn" for the North's calendar (UTC+8 before 1967-08-08);
+    /// Vietnam (UTC+7) when absent. A JSON number is read as hours.
+    #[serde(default, deserialize_with = "deserialize_timezone")]
+    pub timezone: Option<String>,
+    pub location: Option<LocationDto>,
+    /// How hour branches are placed on the clock; the solar modes use the
+    /// location's longitude. Clock ranges when absent.
+    #[serde(default)]
+    pub hour_reckoning: Option<HourReckoningDto>,
+}
+
+fn deserialize_timezone<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Timezone {
+        Hours(f64),
+        Name(String),
+    }
+
+    Ok(
+        Option::<Timezone>::deserialize(deserializer)?.map(|value| match value {
+            Timezone::Hours(hours) => TimeZone::from_hours(hours).to_string(),
+            Timezone::Name(name) => name,
+        }),
+    )
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HourReckoningDto {
+    /// Two civil hours per branch, Tý at 23:00-01:00
+    #[default]
+    Clock,
+    /// Local mean solar time
+    LocalMean,
+    /// Apparent solar time (local mean time plus the equation of time)
+    Apparent,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub struct LocationDto {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub elevation: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -67,8 +115,13 @@ pub struct HourInfoDto {
     pub hour_index: usize,
     pub hour_chi: String,
     pub time_range: String,
+    /// Clock minute of the day (0-1439) at which the hour starts
+    pub start_minute: i32,
     pub star: String,
     pub is_good: bool,
+    pub canchi: CanChiDto,
+    pub na_am: String,
+    pub tuoi_xung: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -199,6 +252,37 @@ pub struct DayInfoDto {
     pub tiet_khi: TietKhiDto,
     pub gio_hoang_dao: GioHoangDaoDto,
     pub day_fortune: Option<DayFortuneDto>,
+    pub sun_times: Option<SunTimesDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SunTimesDto {
+    pub location: LocationDto,
+    /// Local time "HH:MM"; `None` during polar day or night
+    pub sunrise: Option<String>,
+    pub sunset: Option<String>,
+    pub solar_noon: String,
+    pub civil_dawn: Option<String>,
+    pub civil_dusk: Option<String>,
+    pub day_length_minutes: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VariantDateDto {
+    pub variant: String,
+    pub name: String,
+    pub time_zone: f64,
+    pub lunar: LunarDto,
+    pub month_name: String,
+    pub year_canchi: CanChiDto,
+    pub zodiac: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VariantComparisonDto {
+    pub vietnamese: VariantDateDto,
+    pub chinese: VariantDateDto,
+    pub differs: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -340,3 +424,107 @@ pub struct DayInsightDto {
     pub day_guidance: Option<DayGuidanceDto>,
     pub tiet_khi: Option<TietKhiInsightDto>,
 }
+
+/// A plain solar date
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct DateDto {
+    pub day: i32,
+    pub month: i32,
+    pub year: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PersonDto {
+    pub birth_day: i32,
+    pub birth_month: i32,
+    pub birth_year: i32,
+    /// "male" or "female"
+    pub gender: String,
+    /// Clock hour of birth (0-23), when known
+    #[serde(default)]
+    pub birth_hour: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GoodDayQuery {
+    /// Activity id: cuoi_hoi, dong_tho, khai_truong, xuat_hanh or an_tang
+    pub activity: String,
+    pub start: DateDto,
+    /// Last day searched (inclusive)
+    pub end: DateDto,
+    /// Same as `DateQuery::timezone`
+    #[serde(default, deserialize_with = "deserialize_timezone")]
+    pub timezone: Option<String>,
+    pub location: Option<LocationDto>,
+    #[serde(default)]
+    pub hour_reckoning: Option<HourReckoningDto>,
+    /// Keep hoàng đạo days only
+    #[serde(default)]
+    pub hoang_dao_only: bool,
+    /// Drop days with any taboo, not only the activity's hard taboos
+    #[serde(default)]
+    pub exclude_taboos: bool,
+    /// Persons whose tuổi must not clash with the day or the hours
+    #[serde(default)]
+    pub persons: Vec<PersonDto>,
+    /// Days of the week to keep (0 = Sunday); all days when empty
+    #[serde(default)]
+    pub weekdays: Vec<usize>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivityFactorDto {
+    pub factor_id: String,
+    pub points: i32,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivityFilterHitDto {
+    pub filter_id: String,
+    pub reason: String,
+    pub evidence: Option<RuleEvidenceDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivityScoreDto {
+    pub activity: String,
+    pub activity_name: String,
+    pub policy_version: String,
+    pub score: i32,
+    /// "good", "fair", "poor" or "rejected"
+    pub verdict: String,
+    pub hard_filters: Vec<ActivityFilterHitDto>,
+    pub factors: Vec<ActivityFactorDto>,
+    pub evidence: Option<RuleEvidenceDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonDayMatchDto {
+    /// Can Chi of the birth year, e.g. "Canh Ngọ"
+    pub tuoi: String,
+    /// "xung", "hop" or "binh"
+    pub verdict: String,
+    pub reasons: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoodHourDto {
+    pub hour_index: usize,
+    pub hour_chi: String,
+    pub time_range: String,
+    pub star: String,
+    pub reasons: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoodDayDto {
+    pub solar: SolarDto,
+    pub lunar: LunarDto,
+    pub day_canchi: String,
+    pub score: ActivityScoreDto,
+    pub persons: Vec<PersonDayMatchDto>,
+    pub good_hours: Vec<GoodHourDto>,
+    pub reasons: Vec<String>,
+}
@@ -1,16 +1,31 @@
 mod convert;
 mod dto;
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
+use amlich_core::almanac::finder::{find_good_days as find_core_good_days, GoodDayCriteria};
+use amlich_core::almanac::person::PersonProfile;
+use amlich_core::almanac::types::{Activity, Gender};
+use amlich_core::ephemeris::EphemerisMode;
+use amlich_core::gio_hoang_dao::{get_gio_hoang_dao_at, get_hour_starts, HourReckoning};
+use amlich_core::historical::TimezonePolicy;
 use amlich_core::holiday_data::{lunar_festivals, solar_holidays};
 use amlich_core::holidays::get_vietnamese_holidays;
 use amlich_core::insight_data::{
     all_elements, find_can, find_chi, find_tiet_khi_insight, get_day_guidance,
 };
+use amlich_core::julian::{is_valid_date, jd_from_date};
+use amlich_core::lunar::SolarDate;
+use amlich_core::sun_times::get_sun_times;
+use amlich_core::timezone::TimeZone;
 
 pub use dto::*;
 
+/// `timezone` value for the historical Vietnamese calendar: UTC+8 before
+/// 1967-08-08 and UTC+7 from then on, as the North computed it
+/// (`TimezonePolicy::HistoricalVietnam`)
+pub const HISTORICAL_VIETNAM_TIMEZONE: &str = "historical-vn";
+
 pub fn get_day_info(query: &DateQuery) -> Result<DayInfoDto, String> {
     if !(1..=12).contains(&query.month) {
         return Err("month must be 1-12".to_string());
@@ -18,9 +33,31 @@ pub fn get_day_info(query: &DateQuery) -> Result<DayInfoDto, String> {
     if !(1..=31).contains(&query.day) {
         return Err("day must be 1-31".to_string());
     }
+    check_date_exists(query.day, query.month, query.year)?;
+    let mode = ephemeris_mode_for(query.year)?;
 
-    let tz = query.timezone.unwrap_or(amlich_core::VIETNAM_TIMEZONE);
-    let info = amlich_core::get_day_info_with_timezone(query.day, query.month, query.year, tz);
+    let policy = timezone_policy(query.timezone.as_deref())?;
+    let tz = policy.offset_for(query.day, query.month, query.year);
+    let reckoning = hour_reckoning(query.hour_reckoning, query.location.as_ref())?;
+    // Month starts follow the offset at each new moon, not the query date's
+    let mut info =
+        amlich_core::get_day_info_with_policy(query.day, query.month, query.year, policy, mode);
+    if let Some(location) = &query.location {
+        if !(-90.0..=90.0).contains(&location.latitude) {
+            return Err("latitude must be -90..90".to_string());
+        }
+        if !(-180.0..=180.0).contains(&location.longitude) {
+            return Err("longitude must be -180..180".to_string());
+        }
+        info.sun_times = Some(get_sun_times(
+            query.day,
+            query.month,
+            query.year,
+            tz,
+            &location.into(),
+        ));
+    }
+    apply_hour_reckoning(&mut info, tz, reckoning);
     Ok(DayInfoDto::from(&info))
 }
 
@@ -30,9 +67,249 @@ pub fn get_day_info_for_date(day: i32, month: i32, year: i32) -> Result<DayInfoD
         month,
         year,
         timezone: None,
+        location: None,
+        hour_reckoning: None,
     })
 }
 
+pub fn get_vn_cn_comparison(query: &DateQuery) -> Result<VariantComparisonDto, String> {
+    if !(1..=12).contains(&query.month) {
+        return Err("month must be 1-12".to_string());
+    }
+    if !(1..=31).contains(&query.day) {
+        return Err("day must be 1-31".to_string());
+    }
+
+    check_date_exists(query.day, query.month, query.year)?;
+    // The comparison uses the fast ephemeris for both calendars
+    EphemerisMode::Fast
+        .check_year(query.year)
+        .map_err(|err| err.to_string())?;
+
+    let comparison = amlich_core::variant::compare_vn_cn(query.day, query.month, query.year);
+    Ok(VariantComparisonDto::from(&comparison))
+}
+
+pub fn get_month_days(
+    month: i32,
+    year: i32,
+    timezone: Option<&str>,
+) -> Result<Vec<DayInfoDto>, String> {
+    get_month_days_with_hours(month, year, timezone, None, None)
+}
+
+/// Days of a month with hour branches placed by `hour_reckoning`
+///
+/// The solar reckonings use the longitude of `location`.
+pub fn get_month_days_with_hours(
+    month: i32,
+    year: i32,
+    timezone: Option<&str>,
+    location: Option<&LocationDto>,
+    hour_reckoning: Option<HourReckoningDto>,
+) -> Result<Vec<DayInfoDto>, String> {
+    if !(1..=12).contains(&month) {
+        return Err("month must be 1-12".to_string());
+    }
+
+    let mode = ephemeris_mode_for(year)?;
+    let policy = timezone_policy(timezone)?;
+    let reckoning = self::hour_reckoning(hour_reckoning, location)?;
+    let start = SolarDate {
+        day: 1,
+        month,
+        year,
+    };
+    let end = SolarDate {
+        day: days_in_month(month, year),
+        month,
+        year,
+    };
+    Ok(amlich_core::iter_days_with_policy(start, end, policy, mode)
+        .map(|mut info| {
+            let tz = policy.offset_for(info.solar.day, info.solar.month, info.solar.year);
+            apply_hour_reckoning(&mut info, tz, reckoning);
+            DayInfoDto::from(&info)
+        })
+        .collect())
+}
+
+/// Longest range `find_good_days` searches, about ten years
+const MAX_FIND_DAYS: i32 = 3660;
+
+/// Search a date range for days fit for an activity, best first
+///
+/// Days rejected by the activity's hard filters are always dropped; the
+/// query adds the hoàng đạo, taboo, person and weekday filters. Each pick
+/// carries its score breakdown, person relations, the hoàng đạo hours that
+/// suit every person and a flat list of reasons.
+pub fn find_good_days(query: &GoodDayQuery) -> Result<Vec<GoodDayDto>, String> {
+    let activity: Activity = query.activity.parse().map_err(|err| {
+        format!(
+            "{err}; valid activities are: {}",
+            Activity::ALL.map(|activity| activity.id()).join(", ")
+        )
+    })?;
+    check_date(&query.start)?;
+    check_date(&query.end)?;
+    let start_jd = jd_from_date(query.start.day, query.start.month, query.start.year);
+    let end_jd = jd_from_date(query.end.day, query.end.month, query.end.year);
+    if end_jd < start_jd {
+        return Err("end date must not be before start date".to_string());
+    }
+    if end_jd - start_jd >= MAX_FIND_DAYS {
+        return Err(format!("date range must be at most {MAX_FIND_DAYS} days"));
+    }
+    // One ephemeris for the whole range; Accurate covers Fast's years
+    let mode = match (
+        ephemeris_mode_for(query.start.year)?,
+        ephemeris_mode_for(query.end.year)?,
+    ) {
+        (EphemerisMode::Fast, EphemerisMode::Fast) => EphemerisMode::Fast,
+        _ => EphemerisMode::Accurate,
+    };
+
+    let policy = timezone_policy(query.timezone.as_deref())?;
+    let reckoning = hour_reckoning(query.hour_reckoning, query.location.as_ref())?;
+    let criteria = GoodDayCriteria {
+        activity,
+        hoang_dao_only: query.hoang_dao_only,
+        exclude_taboos: query.exclude_taboos,
+        persons: query
+            .persons
+            .iter()
+            .map(person_profile)
+            .collect::<Result<_, _>>()?,
+        weekdays: query.weekdays.clone(),
+        limit: query.limit,
+    };
+    if let Some(weekday) = criteria.weekdays.iter().find(|&&weekday| weekday > 6) {
+        return Err(format!("weekday {weekday} must be 0-6 (0 = Sunday)"));
+    }
+
+    let start = SolarDate {
+        day: query.start.day,
+        month: query.start.month,
+        year: query.start.year,
+    };
+    let end = SolarDate {
+        day: query.end.day,
+        month: query.end.month,
+        year: query.end.year,
+    };
+    let days = amlich_core::iter_days_with_policy(start, end, policy, mode).map(|mut info| {
+        let tz = policy.offset_for(info.solar.day, info.solar.month, info.solar.year);
+        apply_hour_reckoning(&mut info, tz, reckoning);
+        info
+    });
+    Ok(find_core_good_days(days, &criteria)
+        .iter()
+        .map(GoodDayDto::from)
+        .collect())
+}
+
+fn check_date(date: &DateDto) -> Result<(), String> {
+    if !(1..=12).contains(&date.month) {
+        return Err("month must be 1-12".to_string());
+    }
+    if !(1..=31).contains(&date.day) {
+        return Err("day must be 1-31".to_string());
+    }
+    check_date_exists(date.day, date.month, date.year)
+}
+
+fn person_profile(person: &PersonDto) -> Result<PersonProfile, String> {
+    let birth = DateDto {
+        day: person.birth_day,
+        month: person.birth_month,
+        year: person.birth_year,
+    };
+    check_date(&birth)?;
+    ephemeris_mode_for(birth.year)?;
+    let gender = match person.gender.to_lowercase().as_str() {
+        "male" => Gender::Male,
+        "female" => Gender::Female,
+        other => return Err(format!("invalid gender '{other}', expected male or female")),
+    };
+
+    let profile = PersonProfile::new(birth.day, birth.month, birth.year, gender);
+    match person.birth_hour {
+        None => Ok(profile),
+        Some(hour) if hour < 24 => Ok(profile.with_birth_clock_hour(hour)),
+        Some(_) => Err("birth hour must be 0-23".to_string()),
+    }
+}
+
+/// Core hour reckoning of a query; the solar ones need a location
+fn hour_reckoning(
+    reckoning: Option<HourReckoningDto>,
+    location: Option<&LocationDto>,
+) -> Result<HourReckoning, String> {
+    match (reckoning.unwrap_or_default(), location) {
+        (HourReckoningDto::Clock, _) => Ok(HourReckoning::Clock),
+        (_, None) => Err("solar hour reckoning needs a location".to_string()),
+        (HourReckoningDto::LocalMean, Some(location)) => Ok(HourReckoning::LocalMean {
+            longitude: location.longitude,
+        }),
+        (HourReckoningDto::Apparent, Some(location)) => Ok(HourReckoning::Apparent {
+            longitude: location.longitude,
+        }),
+    }
+}
+
+/// Re-place the hour branches of a day when they follow the sun
+fn apply_hour_reckoning(info: &mut amlich_core::DayInfo, time_zone: f64, reckoning: HourReckoning) {
+    if reckoning == HourReckoning::Clock {
+        return;
+    }
+    let starts = get_hour_starts(
+        info.solar.day,
+        info.solar.month,
+        info.solar.year,
+        time_zone,
+        reckoning,
+    );
+    info.gio_hoang_dao = get_gio_hoang_dao_at(info.canchi.day.can, info.canchi.day.chi, &starts);
+}
+
+/// Error for dates that do not exist, such as 2023-02-29 or the days
+/// 1582-10-05..14 skipped by the Gregorian reform
+fn check_date_exists(day: i32, month: i32, year: i32) -> Result<(), String> {
+    if is_valid_date(day, month, year) {
+        Ok(())
+    } else {
+        Err(format!(
+            "invalid date: {year}-{month:02}-{day:02} does not exist (dates before 1582-10-15 are Julian)"
+        ))
+    }
+}
+
+/// Fast ephemeris where it is supported, accurate for 1000-3000
+fn ephemeris_mode_for(year: i32) -> Result<EphemerisMode, String> {
+    EphemerisMode::for_year(year).map_err(|err| err.to_string())
+}
+
+/// Calendar time zone policy of a query; Vietnam (UTC+7) when none is given
+fn timezone_policy(timezone: Option<&str>) -> Result<TimezonePolicy, String> {
+    match timezone {
+        None => Ok(TimezonePolicy::Zone(TimeZone::VIETNAM)),
+        Some(HISTORICAL_VIETNAM_TIMEZONE) => Ok(TimezonePolicy::HistoricalVietnam),
+        Some(value) => value
+            .parse()
+            .map(TimezonePolicy::Zone)
+            .map_err(|err: amlich_core::timezone::ParseTimeZoneError| err.to_string()),
+    }
+}
+
+fn days_in_month(month: i32, year: i32) -> i32 {
+    let (next_month, next_year) = if month == 12 {
+        (1, year + 1)
+    } else {
+        (month + 1, year)
+    };
+    jd_from_date(1, next_month, next_year) - jd_from_date(1, month, year)
+}
+
 pub fn get_holidays(year: i32, major_only: bool) -> Vec<HolidayDto> {
     get_vietnamese_holidays(year)
         .iter()
@@ -65,7 +342,7 @@ pub fn get_day_insight(query: &DateQuery) -> Result<DayInsightDto, String> {
 
     let can_info = find_can(&day_info.canchi.day.can);
     let chi_info = find_chi(&day_info.canchi.day.chi);
-    let element_index: &HashMap<String, amlich_core::insight_data::ElementInfo> = all_elements();
+    let element_index: &BTreeMap<String, amlich_core::insight_data::ElementInfo> = all_elements();
 
     let canchi = match (can_info, chi_info) {
         (Some(can), Some(chi)) => {
@@ -101,5 +378,7 @@ pub fn get_day_insight_for_date(day: i32, month: i32, year: i32) -> Result<DayIn
         month,
         year,
         timezone: None,
+        location: None,
+        hour_reckoning: None,
     })
 }
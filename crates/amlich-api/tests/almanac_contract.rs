@@ -5,7 +5,9 @@ fn tet_2024_fortune() -> amlich_api::DayFortuneDto {
         day: 10,
         month: 2,
         year: 2024,
-        timezone: Some(7.0),
+        timezone: Some("+07:00".to_string()),
+        location: None,
+        hour_reckoning: None,
     })
     .expect("day info should be available");
     info.day_fortune.expect("day_fortune should exist")
@@ -75,7 +77,9 @@ fn day_fortune_applies_seeded_star_precedence_at_api_layer() {
         day: 10,
         month: 2,
         year: 2024,
-        timezone: Some(7.0),
+        timezone: Some("+07:00".to_string()),
+        location: None,
+        hour_reckoning: None,
     })
     .expect("day info should be available");
 
@@ -174,7 +178,9 @@ fn day_fortune_exposes_structured_taboos() {
         day: 14,
         month: 2,
         year: 2024,
-        timezone: Some(7.0),
+        timezone: Some("+07:00".to_string()),
+        location: None,
+        hour_reckoning: None,
     })
     .expect("day info should be available");
 
@@ -199,7 +205,9 @@ fn day_info_exposes_ruleset_metadata() {
         day: 10,
         month: 2,
         year: 2024,
-        timezone: Some(7.0),
+        timezone: Some("+07:00".to_string()),
+        location: None,
+        hour_reckoning: None,
     })
     .expect("day info should be available");
 
@@ -209,3 +217,332 @@ fn day_info_exposes_ruleset_metadata() {
     assert_eq!(fortune.ruleset_id, info.ruleset_id);
     assert_eq!(fortune.ruleset_version, info.ruleset_version);
 }
+
+#[test]
+fn month_days_match_single_day_queries() {
+    let days = amlich_api::get_month_days(2, 2024, None).expect("month should be valid");
+    assert_eq!(days.len(), 29);
+    for (i, info) in days.iter().enumerate() {
+        let single = amlich_api::get_day_info_for_date(i as i32 + 1, 2, 2024)
+            .expect("day info should be available");
+        assert_eq!(
+            serde_json::to_string(info).unwrap(),
+            serde_json::to_string(&single).unwrap()
+        );
+    }
+    assert!(amlich_api::get_month_days(13, 2024, None).is_err());
+}
+
+#[test]
+fn vn_cn_comparison_flags_tet_1985() {
+    let comparison = amlich_api::get_vn_cn_comparison(&DateQuery {
+        day: 21,
+        month: 1,
+        year: 1985,
+        timezone: None,
+        location: None,
+        hour_reckoning: None,
+    })
+    .expect("comparison should be available");
+    assert!(comparison.differs);
+    assert_eq!(comparison.vietnamese.variant, "vn");
+    assert_eq!(comparison.vietnamese.lunar.date_string, "1/1/1985");
+    assert_eq!(comparison.chinese.variant, "cn");
+    assert_eq!(comparison.chinese.time_zone, 8.0);
+    assert_eq!(comparison.chinese.zodiac, "Tý (Chuột)");
+}
+
+#[test]
+fn day_info_includes_sun_times_for_location() {
+    let mut query = DateQuery {
+        day: 21,
+        month: 6,
+        year: 2024,
+        timezone: Some("+07:00".to_string()),
+        location: None,
+        hour_reckoning: None,
+    };
+    let info = get_day_info(&query).expect("day info should be available");
+    assert!(info.sun_times.is_none());
+
+    query.location = Some(amlich_api::LocationDto {
+        latitude: 21.0285,
+        longitude: 105.8542,
+        elevation: 0.0,
+    });
+    let info = get_day_info(&query).expect("day info should be available");
+    let sun = info.sun_times.expect("sun_times should exist");
+    assert_eq!(sun.sunrise.as_deref(), Some("05:16"));
+    assert_eq!(sun.sunset.as_deref(), Some("18:41"));
+    assert_eq!(sun.location.latitude, 21.0285);
+
+    query.location = Some(amlich_api::LocationDto {
+        latitude: 95.0,
+        longitude: 0.0,
+        elevation: 0.0,
+    });
+    assert!(get_day_info(&query).is_err());
+}
+
+#[test]
+fn timezone_accepts_offsets_and_iana_names() {
+    // Older clients send the offset as a number of hours
+    let query: DateQuery =
+        serde_json::from_str(r#"{"day":8,"month":4,"year":2024,"timezone":7}"#).unwrap();
+    assert_eq!(query.timezone.as_deref(), Some("UTC+07:00"));
+    assert_eq!(get_day_info(&query).unwrap().lunar.date_string, "30/2/2024");
+
+    let mut query = DateQuery {
+        day: 8,
+        month: 4,
+        year: 2024,
+        timezone: Some("America/Los_Angeles".to_string()),
+        location: None,
+        hour_reckoning: None,
+    };
+    // The new moon of 8 April 18:21 UTC falls on the same day in California
+    assert_eq!(get_day_info(&query).unwrap().lunar.date_string, "1/3/2024");
+
+    query.timezone = Some("Mars/Olympus".to_string());
+    let err = get_day_info(&query).unwrap_err();
+    assert!(err.starts_with("unknown time zone"), "{err}");
+
+    let days = amlich_api::get_month_days(4, 2024, Some("America/Los_Angeles")).unwrap();
+    assert_eq!(days[7].lunar.date_string, "1/3/2024");
+}
+
+#[test]
+fn timezone_month_starts_follow_daylight_saving() {
+    // Sydney leaves DST on 1 April 2001; the new moon of 23 April 15:26 UTC
+    // is on 24 April at UTC+10
+    let days = amlich_api::get_month_days(4, 2001, Some("Australia/Sydney")).unwrap();
+    assert_eq!(days[22].lunar.date_string, "30/3/2001");
+    assert_eq!(days[23].lunar.date_string, "1/4/2001");
+    for pair in days.windows(2) {
+        let (prev, next) = (&pair[0].lunar, &pair[1].lunar);
+        assert!(
+            next.day == prev.day + 1 || (next.day == 1 && prev.day >= 29),
+            "{} after {}",
+            next.date_string,
+            prev.date_string
+        );
+    }
+
+    let query = DateQuery {
+        day: 23,
+        month: 4,
+        year: 2001,
+        timezone: Some("Australia/Sydney".to_string()),
+        location: None,
+        hour_reckoning: None,
+    };
+    assert_eq!(get_day_info(&query).unwrap().lunar.date_string, "30/3/2001");
+}
+
+#[test]
+fn historical_vietnam_timezone_uses_utc8_before_1967() {
+    // New moon of 1956-11-02 16:43 UTC: 2 Nov at UTC+7, 3 Nov at UTC+8
+    let mut query = DateQuery {
+        day: 3,
+        month: 11,
+        year: 1956,
+        timezone: Some(amlich_api::HISTORICAL_VIETNAM_TIMEZONE.to_string()),
+        location: None,
+        hour_reckoning: None,
+    };
+    assert_eq!(get_day_info(&query).unwrap().lunar.date_string, "1/10/1956");
+    query.timezone = None;
+    assert_eq!(get_day_info(&query).unwrap().lunar.date_string, "2/10/1956");
+
+    let days = amlich_api::get_month_days(11, 1956, Some("historical-vn")).unwrap();
+    assert_eq!(days[2].lunar.date_string, "1/10/1956");
+
+    let picks = amlich_api::find_good_days(&amlich_api::GoodDayQuery {
+        activity: "xuat_hanh".to_string(),
+        start: amlich_api::DateDto {
+            day: 1,
+            month: 11,
+            year: 1956,
+        },
+        end: amlich_api::DateDto {
+            day: 30,
+            month: 11,
+            year: 1956,
+        },
+        timezone: Some("historical-vn".to_string()),
+        location: None,
+        hour_reckoning: None,
+        hoang_dao_only: false,
+        exclude_taboos: false,
+        persons: Vec::new(),
+        weekdays: Vec::new(),
+        limit: Some(30),
+    })
+    .unwrap();
+    assert!(!picks.is_empty());
+    for pick in picks {
+        let day = &days[pick.solar.day as usize - 1];
+        assert_eq!(pick.lunar.date_string, day.lunar.date_string);
+    }
+}
+
+#[test]
+fn hour_reckoning_follows_local_solar_time() {
+    let mut query = DateQuery {
+        day: 3,
+        month: 11,
+        year: 2024,
+        timezone: None,
+        location: None,
+        hour_reckoning: Some(amlich_api::HourReckoningDto::Apparent),
+    };
+    let err = get_day_info(&query).unwrap_err();
+    assert!(err.contains("needs a location"), "{err}");
+
+    query.location = Some(amlich_api::LocationDto {
+        latitude: 21.0285,
+        longitude: 105.8542,
+        elevation: 0.0,
+    });
+    let info = get_day_info(&query).expect("day info should be available");
+    let ngo = &info.gio_hoang_dao.all_hours[6];
+    assert_eq!(ngo.time_range, "10:40-12:40");
+    assert_eq!(ngo.start_minute, 640);
+
+    query.hour_reckoning = Some(amlich_api::HourReckoningDto::LocalMean);
+    let info = get_day_info(&query).expect("day info should be available");
+    assert_eq!(info.gio_hoang_dao.all_hours[0].time_range, "22:57-00:57");
+
+    let json: DateQuery = serde_json::from_str(
+        r#"{"day":3,"month":11,"year":2024,"location":{"latitude":21.0,"longitude":105.85,"elevation":0.0},"hour_reckoning":"local_mean"}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        json.hour_reckoning,
+        Some(amlich_api::HourReckoningDto::LocalMean)
+    );
+
+    let days = amlich_api::get_month_days_with_hours(
+        11,
+        2024,
+        None,
+        query.location.as_ref(),
+        Some(amlich_api::HourReckoningDto::Apparent),
+    )
+    .unwrap();
+    assert_eq!(days[2].gio_hoang_dao.all_hours[6].time_range, "10:40-12:40");
+}
+
+#[test]
+fn day_info_reports_unsupported_and_nonexistent_dates() {
+    let query = |day, month, year| DateQuery {
+        day,
+        month,
+        year,
+        timezone: None,
+        location: None,
+        hour_reckoning: None,
+    };
+
+    // 15th-century dates use the accurate ephemeris and the Julian calendar
+    let info = get_day_info(&query(14, 1, 1450)).expect("1450 should be supported");
+    assert_eq!(info.lunar.date_string, "1/1/1450");
+
+    let err = get_day_info(&query(1, 1, 999)).unwrap_err();
+    assert_eq!(
+        err,
+        "year 999 is outside the supported range 1000-3000 (accurate ephemeris)"
+    );
+    assert!(get_day_info(&query(1, 1, 3001)).is_err());
+
+    let err = get_day_info(&query(10, 10, 1582)).unwrap_err();
+    assert!(err.starts_with("invalid date: 1582-10-10"), "{err}");
+    assert!(get_day_info(&query(29, 2, 1500)).is_ok());
+    assert!(get_day_info(&query(29, 2, 2023)).is_err());
+
+    assert_eq!(amlich_api::get_month_days(1, 1450, None).unwrap().len(), 31);
+    assert!(amlich_api::get_month_days(1, 999, None).is_err());
+    assert!(amlich_api::get_vn_cn_comparison(&query(1, 1, 1500)).is_err());
+}
+
+// --- good-day finder contract ---
+
+fn good_day_query(activity: &str) -> amlich_api::GoodDayQuery {
+    amlich_api::GoodDayQuery {
+        activity: activity.to_string(),
+        start: amlich_api::DateDto {
+            day: 1,
+            month: 3,
+            year: 2024,
+        },
+        end: amlich_api::DateDto {
+            day: 31,
+            month: 3,
+            year: 2024,
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn find_good_days_ranks_explained_picks() {
+    let mut query = good_day_query("cuoi_hoi");
+    query.hoang_dao_only = true;
+    query.weekdays = vec![0, 6];
+    query.limit = Some(5);
+    query.persons = vec![amlich_api::PersonDto {
+        birth_day: 1,
+        birth_month: 6,
+        birth_year: 1990,
+        gender: "male".to_string(),
+        birth_hour: Some(8),
+    }];
+
+    let picks = amlich_api::find_good_days(&query).expect("search should succeed");
+    assert!(!picks.is_empty() && picks.len() <= 5);
+    for pair in picks.windows(2) {
+        assert!(pair[0].score.score >= pair[1].score.score);
+    }
+    for pick in &picks {
+        assert!([0, 6].contains(&pick.solar.day_of_week));
+        assert_ne!(pick.score.verdict, "rejected");
+        assert_eq!(pick.score.activity, "cuoi_hoi");
+        assert!(!pick.reasons.is_empty());
+        assert_eq!(pick.persons[0].tuoi, "Canh Ngọ");
+        assert_ne!(pick.persons[0].verdict, "xung");
+        assert!(pick.good_hours.iter().all(|hour| hour.hour_chi != "Tý"));
+        let evidence = pick.score.evidence.as_ref().expect("evidence");
+        assert_eq!(evidence.method, "policy");
+    }
+}
+
+#[test]
+fn find_good_days_rejects_bad_queries() {
+    let err = amlich_api::find_good_days(&good_day_query("picnic")).unwrap_err();
+    assert!(err.starts_with("unknown activity: picnic"), "{err}");
+
+    let mut query = good_day_query("dong_tho");
+    query.end.year = 2023;
+    assert_eq!(
+        amlich_api::find_good_days(&query).unwrap_err(),
+        "end date must not be before start date"
+    );
+
+    let mut query = good_day_query("dong_tho");
+    query.end.year = 2040;
+    assert!(amlich_api::find_good_days(&query).is_err());
+
+    let mut query = good_day_query("dong_tho");
+    query.weekdays = vec![7];
+    assert!(amlich_api::find_good_days(&query).is_err());
+
+    let mut query = good_day_query("dong_tho");
+    query.persons = vec![amlich_api::PersonDto {
+        birth_day: 1,
+        birth_month: 6,
+        birth_year: 1990,
+        gender: "other".to_string(),
+        birth_hour: None,
+    }];
+    assert!(amlich_api::find_good_days(&query).is_err());
+}
@@ -44,7 +44,9 @@ fn golden_day_info_parity() {
             day: fixture.query.day,
             month: fixture.query.month,
             year: fixture.query.year,
-            timezone: Some(fixture.query.timezone),
+            timezone: Some(fixture.query.timezone.to_string()),
+            location: None,
+            hour_reckoning: None,
         })
         .expect("query should be valid");
 
@@ -0,0 +1,79 @@
+// Bakes the JSON files under data/ into `static` value trees
+// ($OUT_DIR/static_data.rs, included by src/static_data.rs) so the almanac,
+// insight and holiday features need no JSON parser and no std at runtime
+// (DEC-0014 in docs/almanac/decision-log.md).
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+/// (static name, data file, feature that reads it)
+const TABLES: [(&str, &str, &str); 5] = [
+    ("BASELINE", "data/almanac/baseline.json", "almanac"),
+    ("CANCHI", "data/canchi.json", "insight"),
+    ("TIET_KHI", "data/tiet-khi.json", "insight"),
+    (
+        "SOLAR_HOLIDAYS",
+        "data/holidays/solar-holidays.json",
+        "holidays",
+    ),
+    (
+        "LUNAR_FESTIVALS",
+        "data/holidays/lunar-festivals.json",
+        "holidays",
+    ),
+];
+
+fn main() {
+    let mut out = String::from("// @generated by build.rs from data/. Do not edit.\n");
+    for (name, path, feature) in TABLES {
+        println!("cargo:rerun-if-changed={path}");
+        let text =
+            fs::read_to_string(path).unwrap_or_else(|e| panic!("Failed to read {path}: {e}"));
+        let value: Value =
+            serde_json::from_str(&text).unwrap_or_else(|e| panic!("Failed to parse {path}: {e}"));
+        writeln!(out, "\n/// `{path}`").unwrap();
+        writeln!(out, "#[cfg(feature = \"{feature}\")]").unwrap();
+        write!(out, "pub(crate) static {name}: StaticValue = ").unwrap();
+        emit(&mut out, &value);
+        out.push_str(";\n");
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("static_data.rs");
+    fs::write(dest, out).expect("Failed to write static_data.rs");
+}
+
+fn emit(out: &mut String, value: &Value) {
+    match value {
+        Value::Null => panic!("null is not supported in data/; leave the field out instead"),
+        Value::Bool(b) => write!(out, "StaticValue::Bool({b})").unwrap(),
+        Value::Number(n) => {
+            let i = n
+                .as_i64()
+                .unwrap_or_else(|| panic!("only integers are supported in data/, found {n}"));
+            write!(out, "StaticValue::Int({i})").unwrap();
+        }
+        Value::String(s) => write!(out, "StaticValue::Str({s:?})").unwrap(),
+        Value::Array(items) => {
+            out.push_str("StaticValue::Array(&[");
+            for item in items {
+                emit(out, item);
+                out.push(',');
+            }
+            out.push_str("])");
+        }
+        Value::Object(fields) => {
+            out.push_str("StaticValue::Object(&[");
+            for (key, item) in fields {
+                write!(out, "({key:?}, ").unwrap();
+                emit(out, item);
+                out.push_str("),");
+            }
+            out.push_str("])");
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use alloc::format;
+/// Activity scoring — is this a good day for a wedding, groundbreaking, ...
+///
+/// Each activity has a versioned policy in the `activity_policies` family of
+/// the ruleset data. A policy is product policy, not a classical source, so
+/// its evidence uses the `policy` method.
+///
+/// Scoring runs in two stages over a `DayFortune`:
+///
+/// 1. **Hard filters:** listed taboos (e.g. Tam Nương for a wedding) and
+///    trực rule the day out. The verdict is `Rejected` whatever the score.
+/// 2. **Soft factors:** weighted points for hoàng/hắc đạo, trực quality,
+///    trực favored or avoided by the activity, every cát/sát tinh, named
+///    stars the activity cares about, remaining taboos and the nhị thập bát
+///    tú star. The score is their sum and the thresholds give the verdict.
+///
+/// Every factor carries its points and a reason so the score can be
+/// explained line by line.
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::DayInfo;
+
+use super::data::{default_ruleset, ActivityPolicy};
+use super::types::{
+    Activity, ActivityFactor, ActivityFilterHit, ActivityScore, ActivityVerdict,
+    DayDeityClassification, DayFortune, RuleEvidence, StarQuality,
+};
+
+/// Score a day for an activity.
+///
+/// # Arguments
+/// * `activity` - Activity to score for
+/// * `fortune` - Day fortune (e.g. `DayInfo::day_fortune`)
+pub fn score_day(activity: Activity, fortune: &DayFortune) -> ActivityScore {
+    let ruleset = default_ruleset();
+    let data = ruleset.data();
+    let policy = data
+        .activity_policies
+        .get(&activity)
+        .expect("activity policy should exist");
+    let purpose = policy.name.to_lowercase();
+
+    let hard_filters = hard_filter_hits(policy, fortune, &purpose);
+    let factors = soft_factors(policy, fortune, &purpose);
+    let score = factors.iter().map(|factor| factor.points).sum();
+
+    let verdict = if !hard_filters.is_empty() {
+        ActivityVerdict::Rejected
+    } else if score >= policy.thresholds.good {
+        ActivityVerdict::Good
+    } else if score >= policy.thresholds.fair {
+        ActivityVerdict::Fair
+    } else {
+        ActivityVerdict::Poor
+    };
+
+    ActivityScore {
+        activity,
+        activity_name: policy.name.clone(),
+        policy_version: policy.version.clone(),
+        score,
+        verdict,
+        hard_filters,
+        factors,
+        evidence: Some(RuleEvidence {
+            source_id: data.activity_policy_meta.source_id.clone(),
+            method: data.activity_policy_meta.method.clone(),
+            profile: data.profile.clone(),
+        }),
+    }
+}
+
+/// Score a `DayInfo` for an activity.
+pub fn score_day_info(activity: Activity, info: &DayInfo) -> ActivityScore {
+    score_day(activity, &info.day_fortune)
+}
+
+fn hard_filter_hits(
+    policy: &ActivityPolicy,
+    fortune: &DayFortune,
+    purpose: &str,
+) -> Vec<ActivityFilterHit> {
+    let mut hits: Vec<ActivityFilterHit> = fortune
+        .taboos
+        .iter()
+        .filter(|taboo| policy.hard_taboos.contains(&taboo.rule_id))
+        .map(|taboo| ActivityFilterHit {
+            filter_id: taboo.rule_id.clone(),
+            reason: format!("{}, kỵ {}", taboo.reason, purpose),
+            evidence: taboo.evidence.clone(),
+        })
+        .collect();
+
+    if policy.hard_truc.contains(&fortune.truc.name) {
+        hits.push(ActivityFilterHit {
+            filter_id: "truc".to_string(),
+            reason: format!("Trực {}, kỵ {}", fortune.truc.name, purpose),
+            evidence: fortune.truc.evidence.clone(),
+        });
+    }
+
+    hits
+}
+
+fn soft_factors(
+    policy: &ActivityPolicy,
+    fortune: &DayFortune,
+    purpose: &str,
+) -> Vec<ActivityFactor> {
+    let weights = &policy.weights;
+    let mut factors = Vec::new();
+    let mut push = |factor_id: &str, points: i32, reason: String| {
+        if points != 0 {
+            factors.push(ActivityFactor {
+                factor_id: factor_id.to_string(),
+                points,
+                reason,
+            });
+        }
+    };
+
+    if let Some(deity) = &fortune.day_deity {
+        match deity.classification {
+            DayDeityClassification::HoangDao => push(
+                "hoang_dao",
+                weights.hoang_dao,
+                format!("Ngày hoàng đạo ({})", deity.name),
+            ),
+            DayDeityClassification::HacDao => push(
+                "hac_dao",
+                weights.hac_dao,
+                format!("Ngày hắc đạo ({})", deity.name),
+            ),
+        }
+    }
+
+    let truc = &fortune.truc;
+    match truc.quality.as_str() {
+        "cat" => push(
+            "truc_cat",
+            weights.truc_cat,
+            format!("Trực {} là trực cát", truc.name),
+        ),
+        "hung" => push(
+            "truc_hung",
+            weights.truc_hung,
+            format!("Trực {} là trực hung", truc.name),
+        ),
+        _ => {}
+    }
+    if policy.favored_truc.contains(&truc.name) {
+        push(
+            "favored_truc",
+            weights.favored_truc,
+            format!("Trực {} hợp với {}", truc.name, purpose),
+        );
+    }
+    if policy.avoided_truc.contains(&truc.name) {
+        push(
+            "avoided_truc",
+            weights.avoided_truc,
+            format!("Trực {} không hợp với {}", truc.name, purpose),
+        );
+    }
+
+    for name in &fortune.stars.cat_tinh {
+        push("cat_tinh", weights.cat_tinh, format!("Cát tinh {}", name));
+    }
+    for name in &fortune.stars.sat_tinh {
+        push("sat_tinh", weights.sat_tinh, format!("Sát tinh {}", name));
+    }
+    for name in fortune.stars.cat_tinh.iter().chain(&fortune.stars.sat_tinh) {
+        if let Some(&points) = policy.star_points.get(name) {
+            let relation = if points > 0 { "hợp" } else { "không hợp" };
+            push(
+                "star_points",
+                points,
+                format!("Sao {} {} với {}", name, relation, purpose),
+            );
+        }
+    }
+
+    for taboo in &fortune.taboos {
+        if !policy.hard_taboos.contains(&taboo.rule_id) {
+            push("taboo", weights.taboo, taboo.reason.clone());
+        }
+    }
+
+    if let Some(star) = &fortune.stars.day_star {
+        match star.quality {
+            StarQuality::Cat => push(
+                "day_star_cat",
+                weights.day_star_cat,
+                format!("Sao {} (nhị thập bát tú) tốt", star.name),
+            ),
+            StarQuality::Hung => push(
+                "day_star_hung",
+                weights.day_star_hung,
+                format!("Sao {} (nhị thập bát tú) xấu", star.name),
+            ),
+            StarQuality::Binh => {}
+        }
+    }
+
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_day_info;
+
+    #[test]
+    fn tam_nuong_rejects_a_wedding_but_not_a_burial() {
+        // 2024-02-12 is lunar 3/1/2024, a Tam Nương day
+        let info = get_day_info(12, 2, 2024);
+        assert!(info
+            .day_fortune
+            .taboos
+            .iter()
+            .any(|taboo| taboo.rule_id == "tam_nuong"));
+
+        let wedding = score_day_info(Activity::CuoiHoi, &info);
+        assert_eq!(wedding.verdict, ActivityVerdict::Rejected);
+        assert_eq!(wedding.activity_name, "Cưới hỏi");
+        assert_eq!(wedding.policy_version, "v1");
+        let hit = &wedding.hard_filters[0];
+        assert_eq!(hit.filter_id, "tam_nuong");
+        assert!(hit.reason.ends_with(", kỵ cưới hỏi"), "{}", hit.reason);
+
+        let burial = score_day_info(Activity::AnTang, &info);
+        assert!(burial
+            .hard_filters
+            .iter()
+            .all(|hit| hit.filter_id != "tam_nuong"));
+        assert!(burial
+            .factors
+            .iter()
+            .any(|factor| factor.factor_id == "taboo" && factor.points == -3));
+    }
+
+    #[test]
+    fn score_is_the_sum_of_explained_factors() {
+        for day in 1..=28 {
+            let info = get_day_info(day, 3, 2024);
+            for activity in Activity::ALL {
+                let score = score_day_info(activity, &info);
+                let total: i32 = score.factors.iter().map(|factor| factor.points).sum();
+                assert_eq!(score.score, total);
+                assert!(score.factors.iter().all(|factor| factor.points != 0));
+                assert_eq!(
+                    score.verdict == ActivityVerdict::Rejected,
+                    !score.hard_filters.is_empty()
+                );
+                let evidence = score.evidence.expect("evidence");
+                assert_eq!(evidence.method, "policy");
+            }
+        }
+    }
+
+    #[test]
+    fn truc_and_stars_are_weighted() {
+        let info = get_day_info(10, 2, 2024);
+        let fortune = &info.day_fortune;
+        let score = score_day(Activity::KhaiTruong, fortune);
+
+        let cat = score
+            .factors
+            .iter()
+            .filter(|factor| factor.factor_id == "cat_tinh")
+            .count();
+        let sat = score
+            .factors
+            .iter()
+            .filter(|factor| factor.factor_id == "sat_tinh")
+            .count();
+        assert_eq!(cat, fortune.stars.cat_tinh.len());
+        assert_eq!(sat, fortune.stars.sat_tinh.len());
+
+        // 2024-02-10: trực Mãn is hung but favored for khai trương
+        let truc: Vec<(&str, i32)> = score
+            .factors
+            .iter()
+            .filter(|factor| factor.factor_id.contains("truc"))
+            .map(|factor| (factor.factor_id.as_str(), factor.points))
+            .collect();
+        assert_eq!(truc, vec![("truc_hung", -1), ("favored_truc", 2)]);
+        assert!(score
+            .factors
+            .iter()
+            .any(|factor| factor.reason == "Sao Thiên Quý hợp với khai trương"));
+    }
+}
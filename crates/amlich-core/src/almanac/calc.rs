@@ -1,4 +1,9 @@
-use crate::types::{CanChi, CHI, CON_GIAP};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use crate::tietkhi::SolarTermId;
+use crate::types::{Can, CanChi, Chi};
 
 use super::data::default_ruleset;
 use super::day_deity::resolve_day_deity;
@@ -19,8 +24,8 @@ pub fn calculate_day_fortune(
     day_canchi: &CanChi,
     lunar_day: i32,
     lunar_month: i32,
-    year_can: &str,
-    tiet_khi_name: &str,
+    year_can: Can,
+    tiet_khi: SolarTermId,
 ) -> DayFortune {
     let ruleset = default_ruleset();
     let data = ruleset.data();
@@ -30,36 +35,27 @@ pub fn calculate_day_fortune(
         .expect("conflict rule by chi should exist");
     let na_am = data
         .sexagenary_na_am
-        .get(&day_canchi.full)
+        .get(&(day_canchi.can, day_canchi.chi))
         .expect("na am entry should exist");
-
-    let opposing_idx = CHI
-        .iter()
-        .position(|chi| *chi == conflict_rule.opposing_chi)
-        .expect("opposing chi should exist");
-
-    let day_chi_idx = CHI
-        .iter()
-        .position(|chi| *chi == day_canchi.chi)
-        .expect("day chi should exist");
+    let opposing_chi = conflict_rule.opposing_chi;
 
     let day_star_index = jd.rem_euclid(28) as usize;
     let day_star_rule = &data.nhi_thap_bat_tu[day_star_index];
 
     let star_rules = get_day_star_rules(
-        &day_canchi.chi,
-        &day_canchi.full,
+        day_canchi.can,
+        day_canchi.chi,
         year_can,
         lunar_month,
-        tiet_khi_name,
+        tiet_khi,
     );
     let (cat_tinh, sat_tinh) = resolve_rules(&star_rules);
     let profile = data.profile.clone();
     let matched_rules = build_star_rule_evidence(&star_rules, &profile, data);
 
-    let mut travel = get_than_huong(&day_canchi.can);
+    let mut travel = get_than_huong(day_canchi.can);
     travel.evidence = Some(rule_evidence(&data.travel_meta, &profile));
-    let mut day_deity = resolve_day_deity(lunar_month, &day_canchi.chi);
+    let mut day_deity = resolve_day_deity(lunar_month, day_canchi.chi);
     day_deity.evidence = Some(rule_evidence(&data.day_deity_meta, &profile));
 
     DayFortune {
@@ -68,17 +64,17 @@ pub fn calculate_day_fortune(
         profile: profile.clone(),
         day_element: DayElement {
             na_am: na_am.na_am.clone(),
-            element: na_am.element.clone(),
-            can_element: day_canchi.ngu_hanh.can.clone(),
-            chi_element: day_canchi.ngu_hanh.chi.clone(),
+            element: na_am.element,
+            can_element: day_canchi.ngu_hanh.can,
+            chi_element: day_canchi.ngu_hanh.chi,
             evidence: Some(rule_evidence(&data.na_am_meta, &profile)),
         },
         conflict: DayConflict {
-            opposing_chi: conflict_rule.opposing_chi.clone(),
-            opposing_con_giap: CON_GIAP[opposing_idx].to_string(),
+            opposing_chi,
+            opposing_con_giap: opposing_chi.con_giap().to_string(),
             tuoi_xung: vec![
-                format!("{} {}", day_canchi.can, conflict_rule.opposing_chi),
-                CON_GIAP[opposing_idx].to_string(),
+                format!("{} {}", day_canchi.can, opposing_chi),
+                opposing_chi.con_giap().to_string(),
             ],
             sat_huong: conflict_rule.sat_huong.clone(),
             evidence: Some(rule_evidence(&data.conflict_meta, &profile)),
@@ -100,16 +96,16 @@ pub fn calculate_day_fortune(
         },
         day_deity: Some(day_deity),
         taboos: build_day_taboos(
-            &resolve_day_taboos(lunar_day, lunar_month, &day_canchi.chi),
+            &resolve_day_taboos(lunar_day, lunar_month, day_canchi.chi),
             &profile,
             data,
             lunar_day,
             lunar_month,
-            &day_canchi.chi,
+            day_canchi.chi,
         ),
-        xung_hop: get_xung_hop(day_chi_idx),
+        xung_hop: get_xung_hop(day_canchi.chi),
         truc: {
-            let mut truc = get_truc(day_chi_idx, lunar_month);
+            let mut truc = get_truc(day_canchi.chi.index(), lunar_month);
             truc.evidence = Some(RuleEvidence {
                 source_id: "formula".to_string(),
                 method: "table-lookup".to_string(),
@@ -120,7 +116,7 @@ pub fn calculate_day_fortune(
     }
 }
 
-fn rule_evidence(meta: &super::types::SourceMeta, profile: &str) -> RuleEvidence {
+pub(super) fn rule_evidence(meta: &super::types::SourceMeta, profile: &str) -> RuleEvidence {
     RuleEvidence {
         source_id: meta.source_id.clone(),
         method: meta.method.clone(),
@@ -184,7 +180,7 @@ fn build_day_taboos(
     data: &super::data::AlmanacData,
     lunar_day: i32,
     lunar_month: i32,
-    day_chi: &str,
+    day_chi: Chi,
 ) -> Vec<DayTaboo> {
     hits.iter()
         .map(|hit| DayTaboo {
@@ -213,7 +209,7 @@ fn taboo_meta_for_rule<'a>(
     }
 }
 
-fn taboo_reason(rule_id: &str, lunar_day: i32, lunar_month: i32, day_chi: &str) -> String {
+fn taboo_reason(rule_id: &str, lunar_day: i32, lunar_month: i32, day_chi: Chi) -> String {
     match rule_id {
         "tam_nuong" => format!("Ngày âm lịch {lunar_day} thuộc Tam Nương"),
         "nguyet_ky" => format!("Ngày âm lịch {lunar_day} thuộc Nguyệt Kỵ"),
@@ -225,7 +221,7 @@ fn taboo_reason(rule_id: &str, lunar_day: i32, lunar_month: i32, day_chi: &str)
     }
 }
 
-fn parse_star_quality(input: &str) -> StarQuality {
+pub(crate) fn parse_star_quality(input: &str) -> StarQuality {
     match input {
         "cat" => StarQuality::Cat,
         "hung" => StarQuality::Hung,
@@ -238,6 +234,7 @@ mod tests {
     use super::super::types::DayDeityClassification;
     use super::calculate_day_fortune;
     use crate::get_day_info;
+    use crate::types::Chi;
 
     #[test]
     fn computes_fortune_for_tet_2024() {
@@ -247,14 +244,14 @@ mod tests {
             &info.canchi.day,
             info.lunar.day,
             info.lunar.month,
-            &info.canchi.year.can,
-            &info.tiet_khi.name,
+            info.canchi.year.can,
+            info.tiet_khi.id,
         );
 
         assert_eq!(fortune.profile, "baseline");
         assert_eq!(fortune.ruleset_id, "vn_baseline_v1");
         assert_eq!(fortune.ruleset_version, "v1");
-        assert_eq!(fortune.conflict.opposing_chi, "Tuất");
+        assert_eq!(fortune.conflict.opposing_chi, Chi::Tuat);
         assert!(!fortune.travel.xuat_hanh_huong.is_empty());
         assert!(!fortune.stars.cat_tinh.is_empty());
         assert!(!fortune.stars.sat_tinh.is_empty());
@@ -268,8 +265,8 @@ mod tests {
             &info.canchi.day,
             info.lunar.day,
             info.lunar.month,
-            &info.canchi.year.can,
-            &info.tiet_khi.name,
+            info.canchi.year.can,
+            info.tiet_khi.id,
         );
         let day_star = fortune.stars.day_star.expect("day star");
         assert!(day_star.index < 28);
@@ -283,8 +280,8 @@ mod tests {
             &info.canchi.day,
             info.lunar.day,
             info.lunar.month,
-            &info.canchi.year.can,
-            &info.tiet_khi.name,
+            info.canchi.year.can,
+            info.tiet_khi.id,
         );
 
         assert!(
@@ -312,8 +309,8 @@ mod tests {
             &info.canchi.day,
             info.lunar.day,
             info.lunar.month,
-            &info.canchi.year.can,
-            &info.tiet_khi.name,
+            info.canchi.year.can,
+            info.tiet_khi.id,
         );
 
         let deity = fortune.day_deity.expect("day deity");
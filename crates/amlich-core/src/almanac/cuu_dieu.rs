@@ -0,0 +1,188 @@
+/// Cửu Diệu (九曜) yearly star and Hạn for a person
+///
+/// Both are counted on tuổi mụ (`AGE_CONVENTION_TUOI_MU`) and depend on gender.
+///
+/// **Cửu Diệu:** nine stars in a per-gender cycle, `index = (tuổi mụ - 1) % 9`.
+/// Men start at La Hầu (ages 1, 10, 19, ...), women at Kế Đô. Each star has a
+/// monthly ritual day (e.g. La Hầu on the 8th, Thái Dương on the 27th).
+///
+/// **Hạn:** eight palaces counted on the hand from age 10. Each decade moves
+/// one palace and each further year one more, forward for men (from Huỳnh
+/// Tuyền) and backward for women (from Toán Tận). No Hạn is given below 10.
+///
+/// Tables live in the `person_star_rule_sets` family of the ruleset data.
+use alloc::format;
+
+use super::calc::{parse_star_quality, rule_evidence};
+use super::data::{default_ruleset, AlmanacData, CountDirection, HanCount};
+use super::person::PersonProfile;
+use super::types::{CuuDieuInfo, Gender, PersonStarYear, YearlyHanInfo};
+
+/// Resolve the Cửu Diệu star and Hạn for a person in a lunar year.
+pub fn resolve_cuu_dieu(person: &PersonProfile, lunar_year: i32) -> PersonStarYear {
+    resolve_cuu_dieu_for_age(person.lunar_age(lunar_year), person.gender, lunar_year)
+}
+
+/// Resolve the Cửu Diệu star and Hạn from a tuổi mụ and gender.
+///
+/// # Arguments
+/// * `lunar_age` - Age in tuổi mụ
+/// * `gender` - Gender of the person
+/// * `lunar_year` - Lunar year the age refers to (reported back only)
+pub fn resolve_cuu_dieu_for_age(lunar_age: i32, gender: Gender, lunar_year: i32) -> PersonStarYear {
+    let ruleset = default_ruleset();
+    let data = ruleset.data();
+    let rules = &data.person_star_rules;
+
+    PersonStarYear {
+        rules_version: rules.version.clone(),
+        age_convention: rules.age_convention.clone(),
+        lunar_year,
+        lunar_age,
+        gender,
+        star: resolve_star(data, lunar_age, gender),
+        han: resolve_han(data, lunar_age, gender),
+    }
+}
+
+fn resolve_star(data: &AlmanacData, lunar_age: i32, gender: Gender) -> CuuDieuInfo {
+    let rule = &data.person_star_rules.cuu_dieu;
+    let cycle = match gender {
+        Gender::Male => &rule.male_cycle,
+        Gender::Female => &rule.female_cycle,
+    };
+    let star = &rule.stars[cycle[(lunar_age - 1).rem_euclid(cycle.len() as i32) as usize]];
+
+    CuuDieuInfo {
+        name: star.name.clone(),
+        quality: parse_star_quality(&star.quality),
+        ritual_day: star.ritual_day,
+        ritual_direction: star.ritual_direction.clone(),
+        reason: format!(
+            "{} {} tuổi mụ gặp sao {}, cúng ngày {} âm lịch hằng tháng, hướng {}",
+            gender_label(gender),
+            lunar_age,
+            star.name,
+            star.ritual_day,
+            star.ritual_direction
+        ),
+        evidence: Some(rule_evidence(
+            &data.person_star_rule_meta.cuu_dieu,
+            &data.profile,
+        )),
+    }
+}
+
+fn resolve_han(data: &AlmanacData, lunar_age: i32, gender: Gender) -> Option<YearlyHanInfo> {
+    let rule = &data.person_star_rules.han;
+    if lunar_age < rule.start_age {
+        return None;
+    }
+
+    let count = match gender {
+        Gender::Male => rule.male,
+        Gender::Female => rule.female,
+    };
+    let palace = &rule.palaces[han_index(count, lunar_age, rule.start_age, rule.palaces.len())];
+
+    Some(YearlyHanInfo {
+        name: palace.name.clone(),
+        description: palace.description.clone(),
+        reason: format!(
+            "{} {} tuổi mụ gặp hạn {}: {}",
+            gender_label(gender),
+            lunar_age,
+            palace.name,
+            palace.description
+        ),
+        evidence: Some(rule_evidence(
+            &data.person_star_rule_meta.han,
+            &data.profile,
+        )),
+    })
+}
+
+fn han_index(count: HanCount, lunar_age: i32, start_age: i32, palaces: usize) -> usize {
+    // As for Hoang Ốc, `start_age` is the first decade on the hand.
+    let steps = lunar_age / start_age - 1 + lunar_age % start_age;
+    let offset = match count.direction {
+        CountDirection::Forward => steps,
+        CountDirection::Backward => -steps,
+    };
+    (count.start as i32 + offset).rem_euclid(palaces as i32) as usize
+}
+
+fn gender_label(gender: Gender) -> &'static str {
+    match gender {
+        Gender::Male => "Nam",
+        Gender::Female => "Nữ",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::almanac::types::StarQuality;
+
+    fn star(age: i32, gender: Gender) -> String {
+        resolve_cuu_dieu_for_age(age, gender, 2024).star.name
+    }
+
+    fn han(age: i32, gender: Gender) -> Option<String> {
+        resolve_cuu_dieu_for_age(age, gender, 2024)
+            .han
+            .map(|han| han.name)
+    }
+
+    #[test]
+    fn cuu_dieu_cycles_by_gender() {
+        for age in [10, 19, 28, 37, 46, 55] {
+            assert_eq!(star(age, Gender::Male), "La Hầu");
+            assert_eq!(star(age, Gender::Female), "Kế Đô");
+        }
+        assert_eq!(star(14, Gender::Male), "Thái Dương");
+        assert_eq!(star(17, Gender::Male), "Thái Âm");
+        assert_eq!(star(18, Gender::Male), "Mộc Đức");
+        assert_eq!(star(13, Gender::Female), "Thái Âm");
+        assert_eq!(star(17, Gender::Female), "Thái Bạch");
+        assert_eq!(star(1, Gender::Male), "La Hầu");
+    }
+
+    #[test]
+    fn giap_ty_in_giap_thin() {
+        // Born 1984 (Giáp Tý): tuổi mụ 41 in 2024
+        let male = resolve_cuu_dieu(&PersonProfile::new(1, 5, 1984, Gender::Male), 2024);
+        assert_eq!(male.lunar_age, 41);
+        assert_eq!(male.star.name, "Thái Dương");
+        assert_eq!(male.star.quality, StarQuality::Cat);
+        assert_eq!(male.star.ritual_day, 27);
+        assert_eq!(
+            male.star.reason,
+            "Nam 41 tuổi mụ gặp sao Thái Dương, cúng ngày 27 âm lịch hằng tháng, hướng Đông"
+        );
+        assert_eq!(male.han.as_ref().map(|h| h.name.as_str()), Some("Toán Tận"));
+
+        let female = resolve_cuu_dieu(&PersonProfile::new(1, 5, 1984, Gender::Female), 2024);
+        assert_eq!(female.star.name, "Thổ Tú");
+        assert_eq!(female.star.quality, StarQuality::Binh);
+        assert_eq!(
+            female.han.as_ref().map(|h| h.name.as_str()),
+            Some("Huỳnh Tuyền")
+        );
+        let evidence = female.han.and_then(|h| h.evidence).expect("evidence");
+        assert_eq!(evidence.source_id, "ngoc-hap-chanh-tong");
+        assert_eq!(evidence.method, "bai-quyet");
+    }
+
+    #[test]
+    fn han_counts_on_the_hand() {
+        assert_eq!(han(9, Gender::Male), None);
+        assert_eq!(han(10, Gender::Male).as_deref(), Some("Huỳnh Tuyền"));
+        assert_eq!(han(11, Gender::Male).as_deref(), Some("Tam Kheo"));
+        assert_eq!(han(20, Gender::Male).as_deref(), Some("Tam Kheo"));
+        assert_eq!(han(35, Gender::Male).as_deref(), Some("Diêm Vương"));
+        assert_eq!(han(10, Gender::Female).as_deref(), Some("Toán Tận"));
+        assert_eq!(han(11, Gender::Female).as_deref(), Some("Thiên Tinh"));
+        assert_eq!(han(20, Gender::Female).as_deref(), Some("Thiên Tinh"));
+    }
+}
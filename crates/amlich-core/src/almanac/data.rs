@@ -1,17 +1,24 @@
-use std::collections::{HashMap, HashSet};
-use std::error::Error;
-use std::fmt;
-use std::sync::OnceLock;
-
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+
+use once_cell::race::OnceBox;
 use serde::Deserialize;
 
+use super::truc::TRUC_NAMES;
 use super::types::{
-    RuleSetDefaults, RuleSetDescriptor as RulesetDescriptorDoc, RuleSetSourceNote, SourceMeta,
+    Activity, RuleSetDefaults, RuleSetDescriptor as RulesetDescriptorDoc, RuleSetSourceNote,
+    SourceMeta,
 };
-use crate::tietkhi::TIET_KHI;
-use crate::types::{CAN, CHI};
+use crate::static_data::{self, BASELINE};
+use crate::tietkhi::{SolarTermId, TIET_KHI};
+use crate::types::{Can, Chi, Element, ParseNameError, CAN, CHI};
 
-const BASELINE_JSON: &str = include_str!("../../data/almanac/baseline.json");
 pub const DEFAULT_RULESET_ID: &str = "vn_baseline_v1";
 const BASELINE_RULESET_ALIAS: &str = "baseline";
 const DEFAULT_RULESET_VERSION: &str = "v1";
@@ -38,19 +45,27 @@ pub struct TravelRule {
 }
 
 #[derive(Debug, Clone, Deserialize)]
-pub struct ConflictRule {
+pub struct ConflictRuleRaw {
     pub opposing_chi: String,
     pub sat_huong: String,
     pub cat_tinh: Vec<String>,
     pub sat_tinh: Vec<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct ConflictRule {
+    pub opposing_chi: Chi,
+    pub sat_huong: String,
+    pub cat_tinh: Vec<String>,
+    pub sat_tinh: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct NaAmEntry {
-    pub can: String,
-    pub chi: String,
+    pub can: Can,
+    pub chi: Chi,
     pub na_am: String,
-    pub element: String,
+    pub element: Element,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -104,13 +119,13 @@ pub struct DayDeityRule {
 #[derive(Debug, Clone, Deserialize)]
 pub struct DayDeityRuleSetRaw {
     pub cycle: Vec<DayDeityRuleRaw>,
-    pub month_group_start_by_chi: HashMap<String, usize>,
+    pub month_group_start_by_chi: BTreeMap<String, usize>,
 }
 
 #[derive(Debug, Clone)]
 pub struct DayDeityRuleSet {
     pub cycle: Vec<DayDeityRule>,
-    pub month_group_start_by_chi: HashMap<String, usize>,
+    pub month_group_start_by_chi: BTreeMap<Chi, usize>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -142,7 +157,7 @@ pub struct TabooMonthChiRuleRaw {
     pub rule_id: String,
     pub name: String,
     pub severity: String,
-    pub by_lunar_month: HashMap<String, String>,
+    pub by_lunar_month: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone)]
@@ -150,7 +165,7 @@ pub struct TabooMonthChiRule {
     pub rule_id: String,
     pub name: String,
     pub severity: String,
-    pub by_lunar_month: HashMap<u8, String>,
+    pub by_lunar_month: BTreeMap<u8, Chi>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -169,12 +184,212 @@ pub struct TabooRuleSets {
     pub tho_tu: TabooMonthChiRule,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct PersonYearRuleMetaSet {
+    pub tam_tai: SourceMeta,
+    pub kim_lau: SourceMeta,
+    pub hoang_oc: SourceMeta,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TamTaiRuleRaw {
+    pub rule_id: String,
+    pub name: String,
+    pub severity: String,
+    pub by_birth_chi: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TamTaiRule {
+    pub rule_id: String,
+    pub name: String,
+    pub severity: String,
+    pub by_birth_chi: BTreeMap<Chi, Vec<Chi>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct KimLauRuleRaw {
+    pub rule_id: String,
+    pub name: String,
+    pub severity: String,
+    pub cycle: i32,
+    pub by_remainder: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct KimLauRule {
+    pub rule_id: String,
+    pub name: String,
+    pub severity: String,
+    pub cycle: i32,
+    pub by_remainder: BTreeMap<i32, String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HoangOcPalace {
+    pub name: String,
+    pub quality: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HoangOcRule {
+    pub rule_id: String,
+    pub name: String,
+    pub severity: String,
+    pub start_age: i32,
+    pub palaces: Vec<HoangOcPalace>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PersonYearRuleSetsRaw {
+    pub version: String,
+    pub age_convention: String,
+    pub tam_tai: TamTaiRuleRaw,
+    pub kim_lau: KimLauRuleRaw,
+    pub hoang_oc: HoangOcRule,
+}
+
+/// Yearly checks for a person (Tam Tai, Kim Lâu, Hoang Ốc), versioned
+/// separately from the day rules.
+#[derive(Debug, Clone)]
+pub struct PersonYearRuleSets {
+    pub version: String,
+    /// Age convention the rules are written against; always `tuoi_mu`
+    pub age_convention: String,
+    pub tam_tai: TamTaiRule,
+    pub kim_lau: KimLauRule,
+    pub hoang_oc: HoangOcRule,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PersonStarRuleMetaSet {
+    pub cuu_dieu: SourceMeta,
+    pub han: SourceMeta,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CuuDieuStarRule {
+    pub name: String,
+    pub quality: String,
+    /// Lunar day of the monthly ritual (cúng sao giải hạn)
+    pub ritual_day: u8,
+    pub ritual_direction: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CuuDieuRuleRaw {
+    pub rule_id: String,
+    pub name: String,
+    pub stars: Vec<CuuDieuStarRule>,
+    pub male_cycle: Vec<String>,
+    pub female_cycle: Vec<String>,
+}
+
+/// Cửu Diệu stars and the per-gender cycles, indexed by `(tuổi mụ - 1) % 9`.
+#[derive(Debug, Clone)]
+pub struct CuuDieuRule {
+    pub rule_id: String,
+    pub name: String,
+    pub stars: Vec<CuuDieuStarRule>,
+    pub male_cycle: Vec<usize>,
+    pub female_cycle: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HanPalace {
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CountDirection {
+    Forward,
+    Backward,
+}
+
+/// Starting palace at `start_age` and counting direction on the hand.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct HanCount {
+    pub start: usize,
+    pub direction: CountDirection,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HanRule {
+    pub rule_id: String,
+    pub name: String,
+    pub start_age: i32,
+    pub palaces: Vec<HanPalace>,
+    pub male: HanCount,
+    pub female: HanCount,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PersonStarRuleSetsRaw {
+    pub version: String,
+    pub age_convention: String,
+    pub cuu_dieu: CuuDieuRuleRaw,
+    pub han: HanRule,
+}
+
+/// Yearly Cửu Diệu star and Hạn for a person, versioned separately from the
+/// day rules.
+#[derive(Debug, Clone)]
+pub struct PersonStarRuleSets {
+    pub version: String,
+    /// Age convention the rules are written against; always `tuoi_mu`
+    pub age_convention: String,
+    pub cuu_dieu: CuuDieuRule,
+    pub han: HanRule,
+}
+
+/// Points per matched factor of an activity policy.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActivityWeights {
+    pub cat_tinh: i32,
+    pub sat_tinh: i32,
+    pub taboo: i32,
+    pub hoang_dao: i32,
+    pub hac_dao: i32,
+    pub truc_cat: i32,
+    pub truc_hung: i32,
+    pub favored_truc: i32,
+    pub avoided_truc: i32,
+    pub day_star_cat: i32,
+    pub day_star_hung: i32,
+}
+
+/// Minimum scores for the `good` and `fair` verdicts.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActivityThresholds {
+    pub good: i32,
+    pub fair: i32,
+}
+
+/// Versioned scoring policy for one activity.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActivityPolicy {
+    pub name: String,
+    pub version: String,
+    /// Taboo rule ids that rule the day out regardless of their severity
+    pub hard_taboos: Vec<String>,
+    /// Trực names that rule the day out
+    pub hard_truc: Vec<String>,
+    pub favored_truc: Vec<String>,
+    pub avoided_truc: Vec<String>,
+    /// Extra points for named stars among the day's cát/sát tinh
+    pub star_points: BTreeMap<String, i32>,
+    pub weights: ActivityWeights,
+    pub thresholds: ActivityThresholds,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct StarRuleSetsRaw {
-    pub fixed_by_canchi: HashMap<String, StarRuleBucketRaw>,
-    pub by_year_can: HashMap<String, StarRuleBucketRaw>,
-    pub by_lunar_month: HashMap<String, StarRuleBucketRaw>,
-    pub by_tiet_khi: HashMap<String, StarRuleBucketRaw>,
+    pub fixed_by_canchi: BTreeMap<String, StarRuleBucketRaw>,
+    pub by_year_can: BTreeMap<String, StarRuleBucketRaw>,
+    pub by_lunar_month: BTreeMap<String, StarRuleBucketRaw>,
+    pub by_tiet_khi: BTreeMap<String, StarRuleBucketRaw>,
 }
 
 #[derive(Debug, Clone)]
@@ -186,17 +401,23 @@ pub struct AlmanacData {
     pub star_meta: SourceMeta,
     pub day_deity_meta: SourceMeta,
     pub taboo_rule_meta: TabooRuleMetaSet,
-    pub travel_by_can: HashMap<String, TravelRule>,
-    pub conflict_by_chi: HashMap<String, ConflictRule>,
-    pub sexagenary_na_am: HashMap<String, NaAmEntry>,
+    pub travel_by_can: BTreeMap<Can, TravelRule>,
+    pub conflict_by_chi: BTreeMap<Chi, ConflictRule>,
+    pub sexagenary_na_am: BTreeMap<(Can, Chi), NaAmEntry>,
     pub nhi_thap_bat_tu: Vec<DayStarRule>,
     pub star_rule_meta: StarRuleMetaSet,
-    pub star_rules_fixed_by_canchi: HashMap<String, StarRuleBucket>,
-    pub star_rules_by_year_can: HashMap<String, StarRuleBucket>,
-    pub star_rules_by_lunar_month: HashMap<u8, StarRuleBucket>,
-    pub star_rules_by_tiet_khi: HashMap<String, StarRuleBucket>,
+    pub star_rules_fixed_by_canchi: BTreeMap<(Can, Chi), StarRuleBucket>,
+    pub star_rules_by_year_can: BTreeMap<Can, StarRuleBucket>,
+    pub star_rules_by_lunar_month: BTreeMap<u8, StarRuleBucket>,
+    pub star_rules_by_tiet_khi: BTreeMap<SolarTermId, StarRuleBucket>,
     pub day_deity_rule_set: DayDeityRuleSet,
     pub taboo_rules: TabooRuleSets,
+    pub person_year_rule_meta: PersonYearRuleMetaSet,
+    pub person_year_rules: PersonYearRuleSets,
+    pub person_star_rule_meta: PersonStarRuleMetaSet,
+    pub person_star_rules: PersonStarRuleSets,
+    pub activity_policy_meta: SourceMeta,
+    pub activity_policies: BTreeMap<Activity, ActivityPolicy>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -229,6 +450,16 @@ impl RulesetDescriptor {
                     source_id: "khcbppt".to_string(),
                     note: "Tam Nuong/Nguyet Ky/Sat Chu/Tho Tu frozen for v1".to_string(),
                 },
+                RuleSetSourceNote {
+                    family: "person_year_rules".to_string(),
+                    source_id: "ngoc-hap-chanh-tong".to_string(),
+                    note: "Tam Tai/Kim Lau/Hoang Oc frozen for v1 on tuoi mu".to_string(),
+                },
+                RuleSetSourceNote {
+                    family: "person_star_rules".to_string(),
+                    source_id: "ngoc-hap-chanh-tong".to_string(),
+                    note: "Cuu Dieu cycles and Han hand count frozen for v1 on tuoi mu".to_string(),
+                },
             ],
             schema_version: DEFAULT_RULESET_SCHEMA_VERSION.to_string(),
         }
@@ -276,17 +507,23 @@ struct RawAlmanacData {
     star_meta: SourceMeta,
     day_deity_meta: SourceMeta,
     taboo_rule_meta: TabooRuleMetaSet,
-    travel_by_can: HashMap<String, TravelRule>,
-    conflict_by_chi: HashMap<String, ConflictRule>,
+    travel_by_can: BTreeMap<String, TravelRule>,
+    conflict_by_chi: BTreeMap<String, ConflictRuleRaw>,
     na_am_pairs: Vec<String>,
     nhi_thap_bat_tu: Vec<DayStarRule>,
     star_rule_meta: StarRuleMetaSet,
     star_rule_sets: StarRuleSetsRaw,
     day_deity_rule_set: DayDeityRuleSetRaw,
     taboo_rule_sets: TabooRuleSetsRaw,
+    person_year_rule_meta: PersonYearRuleMetaSet,
+    person_year_rule_sets: PersonYearRuleSetsRaw,
+    person_star_rule_meta: PersonStarRuleMetaSet,
+    person_star_rule_sets: PersonStarRuleSetsRaw,
+    activity_policy_meta: SourceMeta,
+    activity_policies: BTreeMap<String, ActivityPolicy>,
 }
 
-static BASELINE_DATA: OnceLock<AlmanacData> = OnceLock::new();
+static BASELINE_DATA: OnceBox<AlmanacData> = OnceBox::new();
 
 static RULESET_REGISTRY: [RulesetRegistryEntry; 1] = [RulesetRegistryEntry {
     descriptor: RulesetDescriptor {
@@ -360,7 +597,7 @@ fn validate_ruleset_descriptor_doc(descriptor: &RulesetDescriptorDoc) {
 }
 
 fn validate_ruleset_source_notes(notes: &[RuleSetSourceNote]) {
-    let mut seen = HashSet::new();
+    let mut seen = BTreeSet::new();
     for note in notes {
         assert!(
             !note.family.trim().is_empty(),
@@ -384,12 +621,11 @@ fn validate_ruleset_source_notes(notes: &[RuleSetSourceNote]) {
 
 pub fn baseline_data() -> &'static AlmanacData {
     BASELINE_DATA.get_or_init(|| {
-        let raw: RawAlmanacData =
-            serde_json::from_str(BASELINE_JSON).expect("Failed to parse baseline almanac data");
+        let raw: RawAlmanacData = static_data::load(&BASELINE, "data/almanac/baseline.json");
 
         validate_raw_data(&raw);
 
-        AlmanacData {
+        Box::new(AlmanacData {
             profile: raw.profile,
             travel_meta: raw.travel_meta,
             conflict_meta: raw.conflict_meta,
@@ -397,8 +633,21 @@ pub fn baseline_data() -> &'static AlmanacData {
             star_meta: raw.star_meta,
             day_deity_meta: raw.day_deity_meta,
             taboo_rule_meta: raw.taboo_rule_meta,
-            travel_by_can: raw.travel_by_can,
-            conflict_by_chi: raw.conflict_by_chi,
+            travel_by_can: raw
+                .travel_by_can
+                .into_iter()
+                .map(|(k, v)| (parse_name(&k, "travel_by_can"), v))
+                .collect(),
+            conflict_by_chi: raw
+                .conflict_by_chi
+                .into_iter()
+                .map(|(k, v)| {
+                    (
+                        parse_name(&k, "conflict_by_chi"),
+                        normalize_conflict_rule(v),
+                    )
+                })
+                .collect(),
             sexagenary_na_am: expand_sexagenary_na_am(&raw.na_am_pairs),
             nhi_thap_bat_tu: raw.nhi_thap_bat_tu,
             star_rule_meta: raw.star_rule_meta,
@@ -406,13 +655,23 @@ pub fn baseline_data() -> &'static AlmanacData {
                 .star_rule_sets
                 .fixed_by_canchi
                 .into_iter()
-                .map(|(k, v)| (k, normalize_star_rule_bucket(v)))
+                .map(|(k, v)| {
+                    (
+                        parse_canchi_key(&k, "star_rule_sets.fixed_by_canchi"),
+                        normalize_star_rule_bucket(v),
+                    )
+                })
                 .collect(),
             star_rules_by_year_can: raw
                 .star_rule_sets
                 .by_year_can
                 .into_iter()
-                .map(|(k, v)| (k, normalize_star_rule_bucket(v)))
+                .map(|(k, v)| {
+                    (
+                        parse_name(&k, "star_rule_sets.by_year_can"),
+                        normalize_star_rule_bucket(v),
+                    )
+                })
                 .collect(),
             star_rules_by_lunar_month: parse_lunar_month_rule_map(
                 raw.star_rule_sets.by_lunar_month,
@@ -421,11 +680,26 @@ pub fn baseline_data() -> &'static AlmanacData {
                 .star_rule_sets
                 .by_tiet_khi
                 .into_iter()
-                .map(|(k, v)| (k, normalize_star_rule_bucket(v)))
+                .map(|(k, v)| {
+                    (
+                        parse_name(&k, "star_rule_sets.by_tiet_khi"),
+                        normalize_star_rule_bucket(v),
+                    )
+                })
                 .collect(),
             day_deity_rule_set: normalize_day_deity_rule_set(raw.day_deity_rule_set),
             taboo_rules: normalize_taboo_rule_sets(raw.taboo_rule_sets),
-        }
+            person_year_rule_meta: raw.person_year_rule_meta,
+            person_year_rules: normalize_person_year_rule_sets(raw.person_year_rule_sets),
+            person_star_rule_meta: raw.person_star_rule_meta,
+            person_star_rules: normalize_person_star_rule_sets(raw.person_star_rule_sets),
+            activity_policy_meta: raw.activity_policy_meta,
+            activity_policies: raw
+                .activity_policies
+                .into_iter()
+                .map(|(k, v)| (parse_name(&k, "activity_policies"), v))
+                .collect(),
+        })
     })
 }
 
@@ -439,6 +713,7 @@ fn validate_raw_data(raw: &RawAlmanacData) {
     validate_can_map(&raw.travel_by_can);
     validate_chi_map(&raw.conflict_by_chi);
     validate_directions(raw);
+    validate_conflict_opposing(&raw.conflict_by_chi);
     validate_conflict_stars(&raw.conflict_by_chi);
     validate_na_am_pairs(&raw.na_am_pairs);
     validate_nhi_thap_bat_tu(&raw.nhi_thap_bat_tu);
@@ -446,9 +721,19 @@ fn validate_raw_data(raw: &RawAlmanacData) {
     validate_star_rule_sets(&raw.star_rule_sets);
     validate_day_deity_rule_set(&raw.day_deity_rule_set);
     validate_taboo_rule_sets(&raw.taboo_rule_sets);
+    validate_person_year_rule_meta(&raw.person_year_rule_meta);
+    validate_person_year_rule_sets(&raw.person_year_rule_sets);
+    validate_person_star_rule_meta(&raw.person_star_rule_meta);
+    validate_person_star_rule_sets(&raw.person_star_rule_sets);
+    validate_source_meta(&raw.activity_policy_meta, "activity_policy_meta");
+    validate_activity_policies(&raw.activity_policies);
 }
 
-const VALID_METHODS: [&str; 3] = ["table-lookup", "bai-quyet", "jd-cycle"];
+/// Age convention of the person year rules: tuổi mụ, one at birth and one
+/// more at every Tết (lunar year - lunar birth year + 1).
+pub const AGE_CONVENTION_TUOI_MU: &str = "tuoi_mu";
+
+const VALID_METHODS: [&str; 4] = ["table-lookup", "bai-quyet", "jd-cycle", "policy"];
 
 pub fn is_valid_method(method: &str) -> bool {
     VALID_METHODS.contains(&method)
@@ -481,18 +766,29 @@ fn validate_taboo_rule_meta(meta: &TabooRuleMetaSet) {
     validate_source_meta(&meta.tho_tu, "taboo_rule_meta.tho_tu");
 }
 
-fn validate_can_map(map: &HashMap<String, TravelRule>) {
-    let expected: HashSet<&str> = CAN.iter().copied().collect();
-    let actual: HashSet<&str> = map.keys().map(String::as_str).collect();
+fn validate_person_year_rule_meta(meta: &PersonYearRuleMetaSet) {
+    validate_source_meta(&meta.tam_tai, "person_year_rule_meta.tam_tai");
+    validate_source_meta(&meta.kim_lau, "person_year_rule_meta.kim_lau");
+    validate_source_meta(&meta.hoang_oc, "person_year_rule_meta.hoang_oc");
+}
+
+fn validate_person_star_rule_meta(meta: &PersonStarRuleMetaSet) {
+    validate_source_meta(&meta.cuu_dieu, "person_star_rule_meta.cuu_dieu");
+    validate_source_meta(&meta.han, "person_star_rule_meta.han");
+}
+
+fn validate_can_map(map: &BTreeMap<String, TravelRule>) {
+    let expected: BTreeSet<&str> = CAN.iter().copied().collect();
+    let actual: BTreeSet<&str> = map.keys().map(String::as_str).collect();
     assert_eq!(
         actual, expected,
         "travel_by_can must contain exactly 10 can"
     );
 }
 
-fn validate_chi_map(map: &HashMap<String, ConflictRule>) {
-    let expected: HashSet<&str> = CHI.iter().copied().collect();
-    let actual: HashSet<&str> = map.keys().map(String::as_str).collect();
+fn validate_chi_map(map: &BTreeMap<String, ConflictRuleRaw>) {
+    let expected: BTreeSet<&str> = CHI.iter().copied().collect();
+    let actual: BTreeSet<&str> = map.keys().map(String::as_str).collect();
     assert_eq!(
         actual, expected,
         "conflict_by_chi must contain exactly 12 chi"
@@ -527,7 +823,18 @@ fn validate_directions(raw: &RawAlmanacData) {
     }
 }
 
-fn validate_conflict_stars(map: &HashMap<String, ConflictRule>) {
+fn validate_conflict_opposing(map: &BTreeMap<String, ConflictRuleRaw>) {
+    for (chi, rule) in map {
+        let expected = chi.parse::<Chi>().map(|chi| chi.opposing().name());
+        assert_eq!(
+            Ok(rule.opposing_chi.as_str()),
+            expected,
+            "conflict_by_chi[{chi}].opposing_chi must be the branch six steps away"
+        );
+    }
+}
+
+fn validate_conflict_stars(map: &BTreeMap<String, ConflictRuleRaw>) {
     for (chi, rule) in map {
         assert!(
             !rule.cat_tinh.is_empty(),
@@ -548,6 +855,10 @@ fn validate_na_am_pairs(values: &[String]) {
     );
     for value in values {
         assert!(!value.trim().is_empty(), "na_am_pairs cannot contain empty");
+        assert!(
+            na_am_element(value).is_ok(),
+            "na_am_pairs entry must end with an element name: {value}"
+        );
     }
 }
 
@@ -567,6 +878,29 @@ fn validate_nhi_thap_bat_tu(values: &[DayStarRule]) {
     }
 }
 
+fn normalize_conflict_rule(raw: ConflictRuleRaw) -> ConflictRule {
+    ConflictRule {
+        opposing_chi: parse_name(&raw.opposing_chi, "conflict_by_chi.opposing_chi"),
+        sat_huong: raw.sat_huong,
+        cat_tinh: raw.cat_tinh,
+        sat_tinh: raw.sat_tinh,
+    }
+}
+
+/// Parse a stem, branch or solar term key that validation has already checked
+fn parse_name<T: FromStr<Err = ParseNameError>>(value: &str, path: &str) -> T {
+    value
+        .parse()
+        .unwrap_or_else(|err| panic!("{path} contains invalid key: {err}"))
+}
+
+fn parse_canchi_key(key: &str, path: &str) -> (Can, Chi) {
+    let (can, chi) = key
+        .split_once(' ')
+        .unwrap_or_else(|| panic!("{path} contains invalid canchi key: {key}"));
+    (parse_name(can, path), parse_name(chi, path))
+}
+
 fn normalize_star_rule_bucket(raw: StarRuleBucketRaw) -> StarRuleBucket {
     StarRuleBucket {
         cat_tinh: raw.cat_tinh,
@@ -576,8 +910,8 @@ fn normalize_star_rule_bucket(raw: StarRuleBucketRaw) -> StarRuleBucket {
 }
 
 fn parse_lunar_month_rule_map(
-    raw: HashMap<String, StarRuleBucketRaw>,
-) -> HashMap<u8, StarRuleBucket> {
+    raw: BTreeMap<String, StarRuleBucketRaw>,
+) -> BTreeMap<u8, StarRuleBucket> {
     raw.into_iter()
         .map(|(month, bucket)| {
             let value = month
@@ -598,7 +932,16 @@ fn normalize_day_deity_rule_set(raw: DayDeityRuleSetRaw) -> DayDeityRuleSet {
                 classification: entry.classification,
             })
             .collect(),
-        month_group_start_by_chi: raw.month_group_start_by_chi,
+        month_group_start_by_chi: raw
+            .month_group_start_by_chi
+            .into_iter()
+            .map(|(chi, start)| {
+                (
+                    parse_name(&chi, "day_deity_rule_set.month_group_start_by_chi"),
+                    start,
+                )
+            })
+            .collect(),
     }
 }
 
@@ -631,13 +974,85 @@ fn normalize_taboo_rule_sets(raw: TabooRuleSetsRaw) -> TabooRuleSets {
     }
 }
 
-fn parse_taboo_month_chi_map(raw: HashMap<String, String>) -> HashMap<u8, String> {
+fn normalize_person_year_rule_sets(raw: PersonYearRuleSetsRaw) -> PersonYearRuleSets {
+    PersonYearRuleSets {
+        version: raw.version,
+        age_convention: raw.age_convention,
+        tam_tai: TamTaiRule {
+            rule_id: raw.tam_tai.rule_id,
+            name: raw.tam_tai.name,
+            severity: raw.tam_tai.severity,
+            by_birth_chi: raw
+                .tam_tai
+                .by_birth_chi
+                .into_iter()
+                .map(|(chi, years)| {
+                    (
+                        parse_name(&chi, "person_year_rule_sets.tam_tai.by_birth_chi"),
+                        years
+                            .iter()
+                            .map(|year| {
+                                parse_name(year, "person_year_rule_sets.tam_tai.by_birth_chi")
+                            })
+                            .collect(),
+                    )
+                })
+                .collect(),
+        },
+        kim_lau: KimLauRule {
+            rule_id: raw.kim_lau.rule_id,
+            name: raw.kim_lau.name,
+            severity: raw.kim_lau.severity,
+            cycle: raw.kim_lau.cycle,
+            by_remainder: raw
+                .kim_lau
+                .by_remainder
+                .into_iter()
+                .map(|(remainder, name)| {
+                    let value = remainder
+                        .parse::<i32>()
+                        .expect("kim_lau.by_remainder key must be a numeric string");
+                    (value, name)
+                })
+                .collect(),
+        },
+        hoang_oc: raw.hoang_oc,
+    }
+}
+
+fn normalize_person_star_rule_sets(raw: PersonStarRuleSetsRaw) -> PersonStarRuleSets {
+    let cuu_dieu = raw.cuu_dieu;
+    let star_index = |name: &String| {
+        cuu_dieu
+            .stars
+            .iter()
+            .position(|star| &star.name == name)
+            .expect("cuu_dieu cycle entries must name a star")
+    };
+    let male_cycle = cuu_dieu.male_cycle.iter().map(star_index).collect();
+    let female_cycle = cuu_dieu.female_cycle.iter().map(star_index).collect();
+
+    PersonStarRuleSets {
+        version: raw.version,
+        age_convention: raw.age_convention,
+        cuu_dieu: CuuDieuRule {
+            rule_id: cuu_dieu.rule_id,
+            name: cuu_dieu.name,
+            stars: cuu_dieu.stars,
+            male_cycle,
+            female_cycle,
+        },
+        han: raw.han,
+    }
+}
+
+fn parse_taboo_month_chi_map(raw: BTreeMap<String, String>) -> BTreeMap<u8, Chi> {
     raw.into_iter()
         .map(|(month, chi)| {
             let value = month
                 .parse::<u8>()
                 .expect("taboo by_lunar_month key must be a numeric month string");
-            (value, chi)
+            (value, parse_name(&chi, "taboo by_lunar_month"))
         })
         .collect()
 }
@@ -667,8 +1082,8 @@ fn validate_day_deity_rule_set(rule_set: &DayDeityRuleSetRaw) {
         );
     }
 
-    let expected: HashSet<&str> = CHI.iter().copied().collect();
-    let actual: HashSet<&str> = rule_set
+    let expected: BTreeSet<&str> = CHI.iter().copied().collect();
+    let actual: BTreeSet<&str> = rule_set
         .month_group_start_by_chi
         .keys()
         .map(String::as_str)
@@ -706,7 +1121,7 @@ fn validate_taboo_day_rule(rule: &TabooDayRuleRaw, path: &str, expected_rule_id:
         "{path}.lunar_days must not be empty"
     );
 
-    let mut seen = HashSet::new();
+    let mut seen = BTreeSet::new();
     for day in &rule.lunar_days {
         assert!(
             (1..=30).contains(day),
@@ -733,8 +1148,8 @@ fn validate_taboo_month_chi_rule(rule: &TabooMonthChiRuleRaw, path: &str, expect
         "{path}.by_lunar_month must contain exactly 12 months"
     );
 
-    let expected_months: HashSet<u8> = (1..=12).collect();
-    let mut actual_months = HashSet::new();
+    let expected_months: BTreeSet<u8> = (1..=12).collect();
+    let mut actual_months = BTreeSet::new();
 
     for (month, chi) in &rule.by_lunar_month {
         let month_num = month
@@ -757,6 +1172,241 @@ fn validate_taboo_month_chi_rule(rule: &TabooMonthChiRuleRaw, path: &str, expect
     );
 }
 
+fn validate_person_year_rule_sets(sets: &PersonYearRuleSetsRaw) {
+    assert!(
+        !sets.version.trim().is_empty(),
+        "person_year_rule_sets.version must not be empty"
+    );
+    assert_eq!(
+        sets.age_convention, AGE_CONVENTION_TUOI_MU,
+        "person_year_rule_sets.age_convention must be '{AGE_CONVENTION_TUOI_MU}'"
+    );
+    validate_tam_tai_rule(&sets.tam_tai);
+    validate_kim_lau_rule(&sets.kim_lau);
+    validate_hoang_oc_rule(&sets.hoang_oc);
+}
+
+fn validate_tam_tai_rule(rule: &TamTaiRuleRaw) {
+    let path = "person_year_rule_sets.tam_tai";
+    validate_taboo_common_fields(&rule.rule_id, &rule.name, &rule.severity, path, "tam_tai");
+
+    let expected: BTreeSet<&str> = CHI.iter().copied().collect();
+    let actual: BTreeSet<&str> = rule.by_birth_chi.keys().map(String::as_str).collect();
+    assert_eq!(
+        actual, expected,
+        "{path}.by_birth_chi must contain all 12 chi keys"
+    );
+
+    for (chi, years) in &rule.by_birth_chi {
+        assert_eq!(
+            years.len(),
+            3,
+            "{path}.by_birth_chi[{chi}] must list 3 years"
+        );
+        for year in years {
+            assert!(
+                CHI.contains(&year.as_str()),
+                "{path}.by_birth_chi[{chi}] contains invalid chi: {year}"
+            );
+        }
+        // Every member of a tam hợp triad shares the same Tam Tai years
+        let birth: Chi = parse_name(chi, path);
+        for partner in super::xung_hop::tam_hop(birth) {
+            assert_eq!(
+                rule.by_birth_chi.get(partner.name()),
+                Some(years),
+                "{path}.by_birth_chi[{chi}] must match its tam hop triad"
+            );
+        }
+    }
+}
+
+fn validate_kim_lau_rule(rule: &KimLauRuleRaw) {
+    let path = "person_year_rule_sets.kim_lau";
+    validate_taboo_common_fields(&rule.rule_id, &rule.name, &rule.severity, path, "kim_lau");
+    assert!(rule.cycle > 1, "{path}.cycle must be greater than 1");
+    assert!(
+        !rule.by_remainder.is_empty(),
+        "{path}.by_remainder must not be empty"
+    );
+    for (remainder, name) in &rule.by_remainder {
+        let value = remainder
+            .parse::<i32>()
+            .unwrap_or_else(|_| panic!("{path}.by_remainder key must be numeric: {remainder}"));
+        assert!(
+            (0..rule.cycle).contains(&value),
+            "{path}.by_remainder key must be in 0..{}",
+            rule.cycle
+        );
+        assert!(
+            !name.trim().is_empty(),
+            "{path}.by_remainder[{remainder}] must not be empty"
+        );
+    }
+}
+
+fn validate_hoang_oc_rule(rule: &HoangOcRule) {
+    let path = "person_year_rule_sets.hoang_oc";
+    validate_taboo_common_fields(&rule.rule_id, &rule.name, &rule.severity, path, "hoang_oc");
+    assert_eq!(
+        rule.start_age, 10,
+        "{path}.start_age must be 10, the hand count moves one palace per decade"
+    );
+    assert_eq!(
+        rule.palaces.len(),
+        6,
+        "{path}.palaces must contain 6 palaces"
+    );
+    for (idx, palace) in rule.palaces.iter().enumerate() {
+        assert!(
+            !palace.name.trim().is_empty(),
+            "{path}.palaces[{idx}].name must not be empty"
+        );
+        assert!(
+            matches!(palace.quality.as_str(), "cat" | "hung"),
+            "{path}.palaces[{idx}].quality must be cat|hung"
+        );
+    }
+}
+
+fn validate_person_star_rule_sets(sets: &PersonStarRuleSetsRaw) {
+    assert!(
+        !sets.version.trim().is_empty(),
+        "person_star_rule_sets.version must not be empty"
+    );
+    assert_eq!(
+        sets.age_convention, AGE_CONVENTION_TUOI_MU,
+        "person_star_rule_sets.age_convention must be '{AGE_CONVENTION_TUOI_MU}'"
+    );
+    validate_cuu_dieu_rule(&sets.cuu_dieu);
+    validate_han_rule(&sets.han);
+}
+
+fn validate_cuu_dieu_rule(rule: &CuuDieuRuleRaw) {
+    let path = "person_star_rule_sets.cuu_dieu";
+    assert_eq!(
+        rule.rule_id, "cuu_dieu",
+        "{path}.rule_id must be 'cuu_dieu'"
+    );
+    assert!(
+        !rule.name.trim().is_empty(),
+        "{path}.name must not be empty"
+    );
+    assert_eq!(rule.stars.len(), 9, "{path}.stars must contain 9 stars");
+    for (idx, star) in rule.stars.iter().enumerate() {
+        assert!(
+            !star.name.trim().is_empty(),
+            "{path}.stars[{idx}].name must not be empty"
+        );
+        assert!(
+            matches!(star.quality.as_str(), "cat" | "hung" | "binh"),
+            "{path}.stars[{idx}].quality must be cat|hung|binh"
+        );
+        assert!(
+            (1..=30).contains(&star.ritual_day),
+            "{path}.stars[{idx}].ritual_day must be a lunar day 1..30"
+        );
+        assert!(
+            is_valid_direction(&star.ritual_direction),
+            "invalid direction for {path}.stars[{idx}].ritual_direction: {}",
+            star.ritual_direction
+        );
+    }
+
+    let names: BTreeSet<&str> = rule.stars.iter().map(|star| star.name.as_str()).collect();
+    for (field, cycle) in [
+        ("male_cycle", &rule.male_cycle),
+        ("female_cycle", &rule.female_cycle),
+    ] {
+        let entries: BTreeSet<&str> = cycle.iter().map(String::as_str).collect();
+        assert_eq!(cycle.len(), 9, "{path}.{field} must contain 9 entries");
+        assert_eq!(
+            entries, names,
+            "{path}.{field} must list every star exactly once"
+        );
+    }
+}
+
+fn validate_han_rule(rule: &HanRule) {
+    let path = "person_star_rule_sets.han";
+    assert_eq!(rule.rule_id, "han", "{path}.rule_id must be 'han'");
+    assert!(
+        !rule.name.trim().is_empty(),
+        "{path}.name must not be empty"
+    );
+    assert_eq!(
+        rule.start_age, 10,
+        "{path}.start_age must be 10, the hand count moves one palace per decade"
+    );
+    assert_eq!(
+        rule.palaces.len(),
+        8,
+        "{path}.palaces must contain 8 palaces"
+    );
+    for (idx, palace) in rule.palaces.iter().enumerate() {
+        assert!(
+            !palace.name.trim().is_empty(),
+            "{path}.palaces[{idx}].name must not be empty"
+        );
+        assert!(
+            !palace.description.trim().is_empty(),
+            "{path}.palaces[{idx}].description must not be empty"
+        );
+    }
+    assert!(rule.male.start < 8, "{path}.male.start must be in 0..8");
+    assert!(rule.female.start < 8, "{path}.female.start must be in 0..8");
+}
+
+fn validate_activity_policies(policies: &BTreeMap<String, ActivityPolicy>) {
+    let expected: BTreeSet<&str> = Activity::ALL.iter().map(|activity| activity.id()).collect();
+    let actual: BTreeSet<&str> = policies.keys().map(String::as_str).collect();
+    assert_eq!(
+        actual, expected,
+        "activity_policies must contain exactly the supported activities"
+    );
+
+    let taboo_ids = ["tam_nuong", "nguyet_ky", "sat_chu", "tho_tu"];
+    for (id, policy) in policies {
+        let path = format!("activity_policies.{id}");
+        assert!(
+            !policy.name.trim().is_empty(),
+            "{path}.name must not be empty"
+        );
+        assert!(
+            !policy.version.trim().is_empty(),
+            "{path}.version must not be empty"
+        );
+        for taboo in &policy.hard_taboos {
+            assert!(
+                taboo_ids.contains(&taboo.as_str()),
+                "{path}.hard_taboos contains unknown taboo rule id: {taboo}"
+            );
+        }
+        for (field, truc) in [
+            ("hard_truc", &policy.hard_truc),
+            ("favored_truc", &policy.favored_truc),
+            ("avoided_truc", &policy.avoided_truc),
+        ] {
+            for name in truc {
+                assert!(
+                    TRUC_NAMES.contains(&name.as_str()),
+                    "{path}.{field} contains unknown truc: {name}"
+                );
+            }
+        }
+        for name in policy.star_points.keys() {
+            assert!(
+                !name.trim().is_empty(),
+                "{path}.star_points contains an empty star name"
+            );
+        }
+        assert!(
+            policy.thresholds.good > policy.thresholds.fair,
+            "{path}.thresholds.good must be greater than thresholds.fair"
+        );
+    }
+}
+
 fn validate_taboo_common_fields(
     rule_id: &str,
     name: &str,
@@ -775,7 +1425,7 @@ fn validate_taboo_common_fields(
     );
 }
 
-fn validate_fixed_by_canchi_map(map: &HashMap<String, StarRuleBucketRaw>) {
+fn validate_fixed_by_canchi_map(map: &BTreeMap<String, StarRuleBucketRaw>) {
     for (key, bucket) in map {
         assert!(
             is_valid_sexagenary_key(key),
@@ -785,7 +1435,7 @@ fn validate_fixed_by_canchi_map(map: &HashMap<String, StarRuleBucketRaw>) {
     }
 }
 
-fn validate_by_year_can_map(map: &HashMap<String, StarRuleBucketRaw>) {
+fn validate_by_year_can_map(map: &BTreeMap<String, StarRuleBucketRaw>) {
     for (key, bucket) in map {
         assert!(
             CAN.contains(&key.as_str()),
@@ -795,7 +1445,7 @@ fn validate_by_year_can_map(map: &HashMap<String, StarRuleBucketRaw>) {
     }
 }
 
-fn validate_by_lunar_month_map(map: &HashMap<String, StarRuleBucketRaw>) {
+fn validate_by_lunar_month_map(map: &BTreeMap<String, StarRuleBucketRaw>) {
     for (key, bucket) in map {
         let month = key
             .parse::<u8>()
@@ -808,7 +1458,7 @@ fn validate_by_lunar_month_map(map: &HashMap<String, StarRuleBucketRaw>) {
     }
 }
 
-fn validate_by_tiet_khi_map(map: &HashMap<String, StarRuleBucketRaw>) {
+fn validate_by_tiet_khi_map(map: &BTreeMap<String, StarRuleBucketRaw>) {
     for (key, bucket) in map {
         assert!(
             is_valid_tiet_khi_name(key),
@@ -823,7 +1473,7 @@ fn validate_star_rule_bucket(bucket: &StarRuleBucketRaw, path: &str) {
     validate_nonempty_star_names(&bucket.sat_tinh, &format!("{path}.sat_tinh"));
     validate_nonempty_star_names(&bucket.binh_tinh, &format!("{path}.binh_tinh"));
 
-    let mut seen = HashSet::new();
+    let mut seen = BTreeSet::new();
     for star in &bucket.cat_tinh {
         assert!(
             seen.insert(star),
@@ -869,18 +1519,22 @@ pub fn is_valid_direction(direction: &str) -> bool {
     VALID_DIRECTIONS.contains(&direction)
 }
 
-fn expand_sexagenary_na_am(na_am_pairs: &[String]) -> HashMap<String, NaAmEntry> {
-    let mut out = HashMap::with_capacity(60);
+fn na_am_element(na_am: &str) -> Result<Element, ParseNameError> {
+    na_am.split_whitespace().last().unwrap_or("").parse()
+}
+
+fn expand_sexagenary_na_am(na_am_pairs: &[String]) -> BTreeMap<(Can, Chi), NaAmEntry> {
+    let mut out = BTreeMap::new();
     for i in 0..60 {
-        let can = CAN[i % 10];
-        let chi = CHI[i % 12];
+        let can = Can::from_index(i);
+        let chi = Chi::from_index(i);
         let na_am = na_am_pairs[i / 2].clone();
-        let element = na_am.split_whitespace().last().unwrap_or("").to_string();
+        let element = na_am_element(&na_am).expect("na am entry should end with an element");
         out.insert(
-            format!("{can} {chi}"),
+            (can, chi),
             NaAmEntry {
-                can: can.to_string(),
-                chi: chi.to_string(),
+                can,
+                chi,
                 na_am,
                 element,
             },
@@ -892,6 +1546,7 @@ fn expand_sexagenary_na_am(na_am_pairs: &[String]) -> HashMap<String, NaAmEntry>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::CanChi;
 
     #[test]
     fn rejects_invalid_method_tokens() {
@@ -907,6 +1562,7 @@ mod tests {
         assert!(is_valid_method("table-lookup"));
         assert!(is_valid_method("bai-quyet"));
         assert!(is_valid_method("jd-cycle"));
+        assert!(is_valid_method("policy"));
     }
 
     #[test]
@@ -982,32 +1638,46 @@ mod tests {
         let data = baseline_data();
         assert_eq!(
             data.sexagenary_na_am
-                .get("Giáp Tý")
+                .get(&(Can::Giap, Chi::Ty))
                 .map(|v| v.na_am.as_str()),
             Some("Hải Trung Kim")
         );
         assert_eq!(
             data.sexagenary_na_am
-                .get("Ất Sửu")
+                .get(&(Can::At, Chi::Suu))
                 .map(|v| v.na_am.as_str()),
             Some("Hải Trung Kim")
         );
         assert_eq!(
             data.sexagenary_na_am
-                .get("Bính Dần")
+                .get(&(Can::Binh, Chi::Dan))
                 .map(|v| v.na_am.as_str()),
             Some("Lư Trung Hỏa")
         );
     }
 
+    #[test]
+    fn na_am_matches_static_table() {
+        // Giờ Hoàng Đạo reads the static table so that it works without the
+        // almanac feature; the ruleset must agree with it
+        let data = baseline_data();
+        for (&(can, chi), entry) in &data.sexagenary_na_am {
+            assert_eq!(CanChi::from_parts(can, chi).na_am(), entry.na_am);
+        }
+    }
+
     #[test]
     fn validates_star_rule_schema_loads() {
         let data = baseline_data();
         assert!(data.star_rule_meta.fixed_by_chi.source_id == "khcbppt");
-        assert!(data.star_rules_fixed_by_canchi.contains_key("Giáp Thìn"));
-        assert!(data.star_rules_by_year_can.contains_key("Giáp"));
+        assert!(data
+            .star_rules_fixed_by_canchi
+            .contains_key(&(Can::Giap, Chi::Thin)));
+        assert!(data.star_rules_by_year_can.contains_key(&Can::Giap));
         assert!(data.star_rules_by_lunar_month.contains_key(&1));
-        assert!(data.star_rules_by_tiet_khi.contains_key("Lập Xuân"));
+        assert!(data
+            .star_rules_by_tiet_khi
+            .contains_key(&SolarTermId::LapXuan));
     }
 
     #[test]
@@ -1019,19 +1689,53 @@ mod tests {
         assert_eq!(
             data.day_deity_rule_set
                 .month_group_start_by_chi
-                .get("Dần")
+                .get(&Chi::Dan)
                 .copied(),
             Some(0)
         );
         assert_eq!(
             data.day_deity_rule_set
                 .month_group_start_by_chi
-                .get("Tý")
+                .get(&Chi::Ty)
                 .copied(),
             Some(8)
         );
     }
 
+    #[test]
+    fn validates_person_year_rule_schema_loads() {
+        let data = baseline_data();
+        let rules = &data.person_year_rules;
+        assert_eq!(rules.version, "v1");
+        assert_eq!(rules.age_convention, AGE_CONVENTION_TUOI_MU);
+        assert_eq!(data.person_year_rule_meta.tam_tai.method, "table-lookup");
+        assert_eq!(
+            rules.tam_tai.by_birth_chi.get(&Chi::Ty),
+            Some(&vec![Chi::Dan, Chi::Mao, Chi::Thin])
+        );
+        assert_eq!(rules.kim_lau.cycle, 9);
+        assert_eq!(
+            rules.kim_lau.by_remainder.get(&3).map(String::as_str),
+            Some("Kim Lâu Thê")
+        );
+        assert_eq!(rules.hoang_oc.start_age, 10);
+        assert_eq!(rules.hoang_oc.palaces[5].name, "Lục Hoang Ốc");
+    }
+
+    #[test]
+    fn validates_person_star_rule_schema_loads() {
+        let data = baseline_data();
+        let rules = &data.person_star_rules;
+        assert_eq!(rules.version, "v1");
+        assert_eq!(rules.age_convention, AGE_CONVENTION_TUOI_MU);
+        assert_eq!(data.person_star_rule_meta.han.method, "bai-quyet");
+        let cuu_dieu = &rules.cuu_dieu;
+        assert_eq!(cuu_dieu.stars[cuu_dieu.male_cycle[0]].name, "La Hầu");
+        assert_eq!(cuu_dieu.stars[cuu_dieu.female_cycle[0]].name, "Kế Đô");
+        assert_eq!(rules.han.palaces[0].name, "Huỳnh Tuyền");
+        assert_eq!(rules.han.female.direction, CountDirection::Backward);
+    }
+
     #[test]
     fn resolves_default_ruleset_by_canonical_id() {
         let entry = get_ruleset(DEFAULT_RULESET_ID).expect("canonical ruleset lookup");
@@ -1123,20 +1827,12 @@ mod tests {
         assert_eq!(data.taboo_rules.tam_nuong.rule_id, "tam_nuong");
         assert_eq!(data.taboo_rules.nguyet_ky.lunar_days, vec![5, 14, 23]);
         assert_eq!(
-            data.taboo_rules
-                .sat_chu
-                .by_lunar_month
-                .get(&1)
-                .map(String::as_str),
-            Some("Tỵ")
+            data.taboo_rules.sat_chu.by_lunar_month.get(&1).copied(),
+            Some(Chi::Ti)
         );
         assert_eq!(
-            data.taboo_rules
-                .tho_tu
-                .by_lunar_month
-                .get(&12)
-                .map(String::as_str),
-            Some("Mùi")
+            data.taboo_rules.tho_tu.by_lunar_month.get(&12).copied(),
+            Some(Chi::Mui)
         );
     }
 
@@ -1157,7 +1853,7 @@ mod tests {
 
     #[test]
     fn rejects_invalid_taboo_month_chi_rule_values() {
-        let mut by_lunar_month = HashMap::new();
+        let mut by_lunar_month = BTreeMap::new();
         for month in 1..=11 {
             by_lunar_month.insert(month.to_string(), "Tý".to_string());
         }
@@ -0,0 +1,315 @@
+/// Good-day finder — search a date range for days fit for an activity
+///
+/// Every day of the range is scored with `activity::score_day` and then
+/// filtered:
+///
+/// - days rejected by the activity's hard filters are always dropped;
+/// - `hoang_dao_only` keeps hoàng đạo days;
+/// - `exclude_taboos` drops days with any taboo, not only the hard ones;
+/// - a day clashing (xung) with any of `persons` is dropped;
+/// - `weekdays`, when not empty, keeps only those days of the week.
+///
+/// The remaining days are ranked by score, then by how many persons the day
+/// harmonizes (hợp) with, then by date. Each pick lists the hoàng đạo hours
+/// that clash with none of the persons, and a flat list of reasons
+/// explaining the pick.
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use crate::gio_hoang_dao::HourInfo;
+use crate::lunar::SolarDate;
+use crate::{iter_days, DayInfo};
+
+use super::activity::score_day_info;
+use super::person::{evaluate_day_info, PersonProfile};
+use super::types::{
+    Activity, ActivityScore, ActivityVerdict, DayDeityClassification, PersonDayEvaluation,
+    PersonVerdict,
+};
+
+/// What to search for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoodDayCriteria {
+    pub activity: Activity,
+    /// Keep hoàng đạo days only
+    pub hoang_dao_only: bool,
+    /// Drop days with any taboo, not only the activity's hard taboos
+    pub exclude_taboos: bool,
+    /// Persons whose tuổi must not clash with the day or the hours
+    pub persons: Vec<PersonProfile>,
+    /// Days of the week to keep (0 = Sunday, as `SolarInfo::day_of_week`);
+    /// all days when empty
+    pub weekdays: Vec<usize>,
+    /// Maximum number of picks; all when `None`
+    pub limit: Option<usize>,
+}
+
+impl GoodDayCriteria {
+    pub fn new(activity: Activity) -> Self {
+        Self {
+            activity,
+            hoang_dao_only: false,
+            exclude_taboos: false,
+            persons: Vec::new(),
+            weekdays: Vec::new(),
+            limit: None,
+        }
+    }
+}
+
+/// An hour of a picked day that suits every person.
+#[derive(Debug, Clone)]
+pub struct GoodHour {
+    pub hour: HourInfo,
+    pub reasons: Vec<String>,
+}
+
+/// A day that passed the criteria, with its explanation.
+#[derive(Debug, Clone)]
+pub struct GoodDay {
+    pub info: DayInfo,
+    pub score: ActivityScore,
+    /// Evaluation for each person, in the order of `GoodDayCriteria::persons`
+    pub persons: Vec<PersonDayEvaluation>,
+    /// Hoàng đạo hours clashing with none of the persons
+    pub hours: Vec<GoodHour>,
+    /// Why the day was picked: the score factors, then the person relations
+    pub reasons: Vec<String>,
+}
+
+/// Find good days among `days`, ranked best first.
+///
+/// Takes any `DayInfo` sequence so callers can choose the time zone,
+/// ephemeris and hour reckoning (e.g. `iter_days_with_policy`).
+///
+/// # Arguments
+/// * `days` - Days to search
+/// * `criteria` - Activity and filters
+///
+/// # Returns
+/// Picks ordered by score, then persons in harmony, then date
+pub fn find_good_days<I>(days: I, criteria: &GoodDayCriteria) -> Vec<GoodDay>
+where
+    I: IntoIterator<Item = DayInfo>,
+{
+    let mut picks: Vec<(usize, GoodDay)> = days
+        .into_iter()
+        .filter_map(|info| evaluate_candidate(info, criteria))
+        .collect();
+
+    picks.sort_by(|(a_hop, a), (b_hop, b)| {
+        b.score
+            .score
+            .cmp(&a.score.score)
+            .then(b_hop.cmp(a_hop))
+            .then(a.info.jd.cmp(&b.info.jd))
+    });
+    if let Some(limit) = criteria.limit {
+        picks.truncate(limit);
+    }
+
+    picks.into_iter().map(|(_, pick)| pick).collect()
+}
+
+/// Find good days from `start` to `end` (inclusive) at a fixed time zone.
+///
+/// # Arguments
+/// * `start` - First solar date
+/// * `end` - Last solar date (inclusive)
+/// * `time_zone` - Timezone offset (e.g. VIETNAM_TIMEZONE)
+/// * `criteria` - Activity and filters
+pub fn find_good_days_in_range(
+    start: SolarDate,
+    end: SolarDate,
+    time_zone: f64,
+    criteria: &GoodDayCriteria,
+) -> Vec<GoodDay> {
+    find_good_days(iter_days(start, end, time_zone), criteria)
+}
+
+/// Score and filter one day; returns the pick with its hợp count.
+fn evaluate_candidate(info: DayInfo, criteria: &GoodDayCriteria) -> Option<(usize, GoodDay)> {
+    if !criteria.weekdays.is_empty() && !criteria.weekdays.contains(&info.solar.day_of_week) {
+        return None;
+    }
+    let fortune = &info.day_fortune;
+    let hoang_dao = fortune
+        .day_deity
+        .as_ref()
+        .is_some_and(|deity| deity.classification == DayDeityClassification::HoangDao);
+    if criteria.hoang_dao_only && !hoang_dao {
+        return None;
+    }
+    if criteria.exclude_taboos && !fortune.taboos.is_empty() {
+        return None;
+    }
+
+    let score = score_day_info(criteria.activity, &info);
+    if score.verdict == ActivityVerdict::Rejected {
+        return None;
+    }
+
+    let persons: Vec<PersonDayEvaluation> = criteria
+        .persons
+        .iter()
+        .map(|person| evaluate_day_info(person, &info))
+        .collect();
+    if persons
+        .iter()
+        .any(|evaluation| evaluation.day.verdict == PersonVerdict::Xung)
+    {
+        return None;
+    }
+    let hop = persons
+        .iter()
+        .filter(|evaluation| evaluation.day.verdict == PersonVerdict::Hop)
+        .count();
+
+    let hours = good_hours(&info, &persons);
+    let reasons = score
+        .factors
+        .iter()
+        .map(|factor| format!("{:+} {}", factor.points, factor.reason))
+        .chain(
+            persons
+                .iter()
+                .flat_map(|evaluation| evaluation.day.reasons.iter().cloned()),
+        )
+        .collect();
+
+    Some((
+        hop,
+        GoodDay {
+            info,
+            score,
+            persons,
+            hours,
+            reasons,
+        },
+    ))
+}
+
+fn good_hours(info: &DayInfo, persons: &[PersonDayEvaluation]) -> Vec<GoodHour> {
+    info.gio_hoang_dao
+        .good_hours
+        .iter()
+        .filter_map(|hour| {
+            let matches: Vec<_> = persons
+                .iter()
+                .filter_map(|evaluation| {
+                    evaluation
+                        .hours
+                        .iter()
+                        .find(|item| item.hour_index == hour.hour_index)
+                })
+                .collect();
+            if matches
+                .iter()
+                .any(|item| item.result.verdict == PersonVerdict::Xung)
+            {
+                return None;
+            }
+
+            let mut reasons = vec![format!(
+                "Giờ {} ({}) hoàng đạo, sao {}",
+                hour.hour_chi, hour.time_range, hour.star
+            )];
+            reasons.extend(
+                matches
+                    .iter()
+                    .flat_map(|item| item.result.reasons.iter().cloned()),
+            );
+            Some(GoodHour {
+                hour: hour.clone(),
+                reasons,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::almanac::types::Gender;
+    use crate::types::Chi;
+
+    fn february_2024() -> (SolarDate, SolarDate) {
+        (
+            SolarDate {
+                day: 1,
+                month: 2,
+                year: 2024,
+            },
+            SolarDate {
+                day: 29,
+                month: 2,
+                year: 2024,
+            },
+        )
+    }
+
+    #[test]
+    fn picks_are_ranked_and_never_rejected() {
+        let (start, end) = february_2024();
+        let picks =
+            find_good_days_in_range(start, end, 7.0, &GoodDayCriteria::new(Activity::CuoiHoi));
+
+        assert!(!picks.is_empty());
+        for pair in picks.windows(2) {
+            assert!(pair[0].score.score >= pair[1].score.score);
+        }
+        for pick in &picks {
+            assert_ne!(pick.score.verdict, ActivityVerdict::Rejected);
+            assert_eq!(pick.hours.len(), pick.info.gio_hoang_dao.good_hours.len());
+            assert_eq!(pick.reasons.len(), pick.score.factors.len());
+        }
+        // Tam Nương (lunar 3, 7, 13, 18, 22, 27) is a hard filter for weddings
+        assert!(picks
+            .iter()
+            .all(|pick| ![3, 7, 13, 18, 22, 27].contains(&pick.info.lunar.day)));
+    }
+
+    #[test]
+    fn filters_narrow_the_picks() {
+        let (start, end) = february_2024();
+        let mut criteria = GoodDayCriteria::new(Activity::KhaiTruong);
+        criteria.hoang_dao_only = true;
+        criteria.exclude_taboos = true;
+        criteria.weekdays = vec![0, 6];
+        criteria.limit = Some(3);
+
+        let picks = find_good_days_in_range(start, end, 7.0, &criteria);
+        assert!(picks.len() <= 3);
+        for pick in &picks {
+            let fortune = &pick.info.day_fortune;
+            assert!([0, 6].contains(&pick.info.solar.day_of_week));
+            assert!(fortune.taboos.is_empty());
+            assert_eq!(
+                fortune
+                    .day_deity
+                    .as_ref()
+                    .map(|deity| deity.classification.clone()),
+                Some(DayDeityClassification::HoangDao)
+            );
+        }
+    }
+
+    #[test]
+    fn days_and_hours_clashing_with_a_person_are_dropped() {
+        // Born 1990-06-01: tuổi Canh Ngọ, so Tý days and hours clash
+        let person = PersonProfile::new(1, 6, 1990, Gender::Male);
+        let (start, end) = february_2024();
+        let mut criteria = GoodDayCriteria::new(Activity::XuatHanh);
+        criteria.persons = vec![person];
+
+        let picks = find_good_days_in_range(start, end, 7.0, &criteria);
+        assert!(!picks.is_empty());
+        for pick in &picks {
+            assert_ne!(pick.info.canchi.day.chi, Chi::Ty);
+            assert_eq!(pick.persons.len(), 1);
+            assert_ne!(pick.persons[0].day.verdict, PersonVerdict::Xung);
+            assert!(pick.hours.iter().all(|hour| hour.hour.hour_chi != Chi::Ty));
+        }
+    }
+}
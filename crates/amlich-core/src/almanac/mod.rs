@@ -1,6 +1,11 @@
+pub mod activity;
 pub mod calc;
+pub mod cuu_dieu;
 pub mod data;
 pub mod day_deity;
+pub mod finder;
+pub mod person;
+pub mod person_year;
 pub mod profile;
 pub mod star;
 pub mod taboo;
@@ -0,0 +1,384 @@
+/// Person-aware evaluation — tuổi xung/hợp for a given person
+///
+/// A person is identified by their solar birth date, gender and optionally the
+/// branch of their birth hour. The lunar birth year (tuổi) follows the lunar
+/// calendar, so someone born in January before Tết belongs to the previous
+/// year's Can Chi. The lunar year is placed with the historical Vietnam
+/// policy, so births before 1967-08-08 use the UTC+8 calendar of the time.
+///
+/// A day or hour branch is compared with the birth-year branch (and the birth
+/// hour branch when known):
+///
+/// - **Lục xung:** the branches are opposite; if the stems also clash
+///   (Giáp-Canh, Ất-Tân, Bính-Nhâm, Đinh-Quý) it is thiên khắc địa xung.
+/// - **Tứ hành xung:** same four-clash square, three branches apart.
+/// - **Tam hợp / lục hợp:** same triad, or one of the six harmony pairs.
+///   Dần-Hợi and Tỵ-Thân are both lục hợp and in one square; they count as
+///   lục hợp.
+///
+/// The verdict is xung if any relation clashes, otherwise hợp if any relation
+/// harmonizes, otherwise bình.
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use serde::{Deserialize, Serialize};
+
+use crate::canchi::get_year_canchi;
+use crate::ephemeris::EphemerisMode;
+use crate::gio_hoang_dao::HourInfo;
+use crate::historical::{convert_solar_to_lunar_with_policy, TimezonePolicy};
+use crate::types::{Can, CanChi, Chi};
+use crate::DayInfo;
+
+use super::data::default_ruleset;
+use super::types::{
+    Gender, PersonBasis, PersonDayEvaluation, PersonHourMatch, PersonMatch, PersonRelation,
+    PersonRelationHit, PersonVerdict, RuleEvidence,
+};
+use super::xung_hop::{luc_hop, luc_xung, tam_hop, tu_hanh_xung};
+
+/// A person to evaluate days against.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonProfile {
+    /// Solar (Gregorian) birth date
+    pub birth_day: i32,
+    pub birth_month: i32,
+    pub birth_year: i32,
+    pub gender: Gender,
+    /// Branch of the birth hour, when known
+    #[serde(default)]
+    pub birth_hour: Option<Chi>,
+}
+
+impl PersonProfile {
+    pub fn new(birth_day: i32, birth_month: i32, birth_year: i32, gender: Gender) -> Self {
+        Self {
+            birth_day,
+            birth_month,
+            birth_year,
+            gender,
+            birth_hour: None,
+        }
+    }
+
+    pub fn with_birth_hour(mut self, hour_chi: Chi) -> Self {
+        self.birth_hour = Some(hour_chi);
+        self
+    }
+
+    /// Set the birth hour from a clock hour (0-23); 23:00-00:59 is giờ Tý.
+    pub fn with_birth_clock_hour(self, hour: u32) -> Self {
+        self.with_birth_hour(Chi::from_index((hour as usize % 24).div_ceil(2) % 12))
+    }
+
+    /// Lunar year the person was born in.
+    ///
+    /// Placed under `TimezonePolicy::HistoricalVietnam`, with the ephemeris
+    /// the API uses for the birth year (fast where supported).
+    pub fn lunar_birth_year(&self) -> i32 {
+        let mode = EphemerisMode::for_year(self.birth_year).unwrap_or(EphemerisMode::Fast);
+        convert_solar_to_lunar_with_policy(
+            self.birth_day,
+            self.birth_month,
+            self.birth_year,
+            TimezonePolicy::HistoricalVietnam,
+            mode,
+        )
+        .year
+    }
+
+    /// Age in tuổi mụ: one at birth and one more at every Tết.
+    pub fn lunar_age(&self, lunar_year: i32) -> i32 {
+        lunar_year - self.lunar_birth_year() + 1
+    }
+
+    /// Can Chi of the lunar birth year (the person's tuổi).
+    pub fn year_canchi(&self) -> CanChi {
+        get_year_canchi(self.lunar_birth_year())
+    }
+}
+
+/// Return the relation between a person's branch and a day or hour branch.
+///
+/// # Returns
+/// `None` for bình, including the same branch.
+pub fn branch_relation(person: Chi, other: Chi) -> Option<PersonRelation> {
+    if person == other {
+        None
+    } else if luc_xung(person) == other {
+        Some(PersonRelation::LucXung)
+    } else if luc_hop(person) == other {
+        Some(PersonRelation::LucHop)
+    } else if tam_hop(person).contains(&other) {
+        Some(PersonRelation::TamHop)
+    } else if tu_hanh_xung(person).contains(&other) {
+        Some(PersonRelation::TuHanhXung)
+    } else {
+        None
+    }
+}
+
+/// Whether two stems clash (Giáp-Canh, Ất-Tân, Bính-Nhâm, Đinh-Quý).
+pub fn can_clash(a: Can, b: Can) -> bool {
+    (a.index() as i32 - b.index() as i32).abs() == 6
+}
+
+/// Evaluate a day and each of its hours for a person.
+///
+/// # Arguments
+/// * `person` - Person to evaluate for
+/// * `day_canchi` - Can Chi of the day
+/// * `hours` - Hours of the day (e.g. `GioHoangDao::all_hours`)
+pub fn evaluate_day(
+    person: &PersonProfile,
+    day_canchi: &CanChi,
+    hours: &[HourInfo],
+) -> PersonDayEvaluation {
+    let year = person.year_canchi();
+    let evidence = person_evidence();
+    let evaluate = |subject: &str, canchi: &CanChi| {
+        evaluate_branch(subject, canchi, &year, person.birth_hour, &evidence)
+    };
+
+    PersonDayEvaluation {
+        birth_year_can: year.can,
+        birth_year_chi: year.chi,
+        day: evaluate("Ngày", day_canchi),
+        hours: hours
+            .iter()
+            .map(|hour| PersonHourMatch {
+                hour_index: hour.hour_index,
+                hour_chi: hour.hour_chi,
+                time_range: hour.time_range.clone(),
+                result: evaluate("Giờ", &hour.canchi),
+            })
+            .collect(),
+    }
+}
+
+/// Evaluate a `DayInfo` and all of its hours for a person.
+pub fn evaluate_day_info(person: &PersonProfile, info: &DayInfo) -> PersonDayEvaluation {
+    evaluate_day(person, &info.canchi.day, &info.gio_hoang_dao.all_hours)
+}
+
+fn evaluate_branch(
+    subject: &str,
+    target: &CanChi,
+    year: &CanChi,
+    birth_hour: Option<Chi>,
+    evidence: &RuleEvidence,
+) -> PersonMatch {
+    let mut relations = Vec::new();
+    let mut reasons = Vec::new();
+
+    let mut bases = vec![(
+        PersonBasis::BirthYear,
+        year.chi,
+        format!("tuổi {}", year.full),
+    )];
+    if let Some(hour_chi) = birth_hour {
+        bases.push((
+            PersonBasis::BirthHour,
+            hour_chi,
+            format!("giờ sinh {}", hour_chi),
+        ));
+    }
+
+    for (basis, chi, label) in &bases {
+        let Some(relation) = branch_relation(*chi, target.chi) else {
+            continue;
+        };
+        let thien_khac = relation == PersonRelation::LucXung
+            && *basis == PersonBasis::BirthYear
+            && can_clash(year.can, target.can);
+        reasons.push(relation_reason(
+            subject, target, relation, thien_khac, *chi, label,
+        ));
+        relations.push(PersonRelationHit {
+            relation,
+            basis: *basis,
+            thien_khac,
+        });
+    }
+
+    let verdict = if relations.iter().any(|hit| hit.relation.is_clash()) {
+        PersonVerdict::Xung
+    } else if relations.is_empty() {
+        reasons.push(format!(
+            "{} {} không xung, không hợp với tuổi {}",
+            subject, target.chi, year.full
+        ));
+        PersonVerdict::Binh
+    } else {
+        PersonVerdict::Hop
+    };
+
+    PersonMatch {
+        verdict,
+        relations,
+        reasons,
+        evidence: Some(evidence.clone()),
+    }
+}
+
+fn relation_reason(
+    subject: &str,
+    target: &CanChi,
+    relation: PersonRelation,
+    thien_khac: bool,
+    person_chi: Chi,
+    label: &str,
+) -> String {
+    match relation {
+        PersonRelation::LucXung if thien_khac => {
+            format!("{} {} thiên khắc địa xung {}", subject, target.full, label)
+        }
+        PersonRelation::LucXung => format!("{} {} lục xung {}", subject, target.chi, label),
+        PersonRelation::TuHanhXung => format!(
+            "{} {} thuộc tứ hành xung với {} ({})",
+            subject,
+            target.chi,
+            label,
+            join_branches(&tu_hanh_xung(person_chi))
+        ),
+        PersonRelation::TamHop => format!(
+            "{} {} tam hợp với {} ({})",
+            subject,
+            target.chi,
+            label,
+            join_branches(&tam_hop(person_chi))
+        ),
+        PersonRelation::LucHop => format!("{} {} lục hợp với {}", subject, target.chi, label),
+    }
+}
+
+fn join_branches(branches: &[Chi]) -> String {
+    branches
+        .iter()
+        .map(|chi| chi.name())
+        .collect::<Vec<_>>()
+        .join(" - ")
+}
+
+fn person_evidence() -> RuleEvidence {
+    RuleEvidence {
+        source_id: "formula".to_string(),
+        method: "branch-relation".to_string(),
+        profile: default_ruleset().data().profile.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_day_info;
+
+    #[test]
+    fn lunar_birth_year_follows_tet() {
+        // Tết 1990 fell on 27 Jan 1990
+        let before = PersonProfile::new(20, 1, 1990, Gender::Male);
+        let after = PersonProfile::new(27, 1, 1990, Gender::Male);
+        assert_eq!(before.lunar_birth_year(), 1989);
+        assert_eq!(before.year_canchi().full, "Kỷ Tỵ");
+        assert_eq!(after.year_canchi().full, "Canh Ngọ");
+    }
+
+    #[test]
+    fn lunar_birth_year_uses_pre_1967_calendar() {
+        // Tết 1935 was 4 Feb at UTC+8 (3 Feb at UTC+7)
+        let person = PersonProfile::new(3, 2, 1935, Gender::Female);
+        assert_eq!(person.lunar_birth_year(), 1934);
+        assert_eq!(person.year_canchi().full, "Giáp Tuất");
+        assert_eq!(
+            PersonProfile::new(4, 2, 1935, Gender::Female).lunar_birth_year(),
+            1935
+        );
+    }
+
+    #[test]
+    fn birth_clock_hour_maps_to_branch() {
+        let person = PersonProfile::new(1, 6, 1990, Gender::Female);
+        assert_eq!(
+            person.clone().with_birth_clock_hour(23).birth_hour,
+            Some(Chi::Ty)
+        );
+        assert_eq!(
+            person.clone().with_birth_clock_hour(0).birth_hour,
+            Some(Chi::Ty)
+        );
+        assert_eq!(
+            person.clone().with_birth_clock_hour(1).birth_hour,
+            Some(Chi::Suu)
+        );
+        assert_eq!(person.with_birth_clock_hour(12).birth_hour, Some(Chi::Ngo));
+    }
+
+    #[test]
+    fn branch_relations() {
+        assert_eq!(
+            branch_relation(Chi::Ty, Chi::Ngo),
+            Some(PersonRelation::LucXung)
+        );
+        assert_eq!(
+            branch_relation(Chi::Ty, Chi::Mao),
+            Some(PersonRelation::TuHanhXung)
+        );
+        assert_eq!(
+            branch_relation(Chi::Ty, Chi::Thin),
+            Some(PersonRelation::TamHop)
+        );
+        assert_eq!(
+            branch_relation(Chi::Ty, Chi::Suu),
+            Some(PersonRelation::LucHop)
+        );
+        assert_eq!(
+            branch_relation(Chi::Dan, Chi::Hoi),
+            Some(PersonRelation::LucHop)
+        );
+        assert_eq!(branch_relation(Chi::Ty, Chi::Ty), None);
+        assert_eq!(branch_relation(Chi::Ty, Chi::Dan), None);
+        for a in Chi::ALL {
+            for b in Chi::ALL {
+                assert_eq!(branch_relation(a, b), branch_relation(b, a));
+            }
+        }
+    }
+
+    #[test]
+    fn evaluates_day_and_hours_for_person() {
+        // Born 1984-05-01: Giáp Tý. 2024-02-10 (Tết) is a Giáp Thìn day.
+        let person = PersonProfile::new(1, 5, 1984, Gender::Male).with_birth_clock_hour(8);
+        let info = get_day_info(10, 2, 2024);
+        let eval = evaluate_day_info(&person, &info);
+
+        assert_eq!(eval.birth_year_chi, Chi::Ty);
+        assert_eq!(eval.day.verdict, PersonVerdict::Hop);
+        assert_eq!(
+            eval.day.reasons[0],
+            "Ngày Thìn tam hợp với tuổi Giáp Tý (Tý - Thìn - Thân)"
+        );
+        assert_eq!(eval.hours.len(), 12);
+
+        // Giờ Ngọ of a Giáp day is Canh Ngọ: thiên khắc địa xung with Giáp Tý
+        let ngo = &eval.hours[6];
+        assert_eq!(ngo.hour_chi, Chi::Ngo);
+        assert_eq!(ngo.result.verdict, PersonVerdict::Xung);
+        assert!(ngo.result.relations[0].thien_khac);
+        assert_eq!(
+            ngo.result.reasons[0],
+            "Giờ Canh Ngọ thiên khắc địa xung tuổi Giáp Tý"
+        );
+
+        // Giờ Tuất clashes with the Thìn birth hour only
+        let tuat = &eval.hours[10];
+        assert_eq!(tuat.result.verdict, PersonVerdict::Xung);
+        assert_eq!(tuat.result.relations[0].basis, PersonBasis::BirthHour);
+        assert_eq!(tuat.result.reasons[0], "Giờ Tuất lục xung giờ sinh Thìn");
+
+        // Giờ Dần has no relation with either branch
+        let dan = &eval.hours[2];
+        assert_eq!(dan.result.verdict, PersonVerdict::Binh);
+        assert!(dan.result.relations.is_empty());
+    }
+}
@@ -0,0 +1,256 @@
+use alloc::format;
+/// Person year rules — Tam Tai, Kim Lâu, Hoang Ốc
+///
+/// All three checks take the person's lunar birth year and a lunar year, and
+/// count age in tuổi mụ (`AGE_CONVENTION_TUOI_MU`): one at birth and one more
+/// at every Tết.
+///
+/// - **Tam Tai:** each tam hợp triad of birth branches has three consecutive
+///   unlucky years (e.g. Thân · Tý · Thìn → Dần, Mão, Thìn).
+/// - **Kim Lâu:** tuổi mụ modulo 9 equal to 1, 3, 6 or 8 is Kim Lâu Thân,
+///   Thê, Tử or Lục Súc.
+/// - **Hoang Ốc:** six palaces counted on the hand; age 10 starts at Nhất Cát,
+///   each decade moves one palace and each further year one more. Địa Sát,
+///   Thọ Tử and Hoang Ốc are unlucky.
+///
+/// Tables and severities live in the `person_year_rule_sets` family of the
+/// ruleset data, versioned separately from the day rules.
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use crate::canchi::get_year_canchi;
+use crate::types::Chi;
+
+use super::calc::rule_evidence;
+use super::data::{default_ruleset, AlmanacData};
+use super::person::PersonProfile;
+use super::types::{PersonYearCheck, PersonYearChecks};
+
+const TAM_TAI_ORDINALS: [&str; 3] = ["năm đầu", "năm giữa", "năm cuối"];
+
+/// Resolve Tam Tai, Kim Lâu and Hoang Ốc for a person in a lunar year.
+pub fn resolve_person_year(person: &PersonProfile, lunar_year: i32) -> PersonYearChecks {
+    resolve_person_year_rules(person.lunar_birth_year(), lunar_year)
+}
+
+/// Resolve Tam Tai, Kim Lâu and Hoang Ốc for a lunar birth year.
+///
+/// # Arguments
+/// * `birth_lunar_year` - Lunar year of birth
+/// * `lunar_year` - Lunar year to check
+pub fn resolve_person_year_rules(birth_lunar_year: i32, lunar_year: i32) -> PersonYearChecks {
+    let ruleset = default_ruleset();
+    let data = ruleset.data();
+    let rules = &data.person_year_rules;
+    let lunar_age = lunar_year - birth_lunar_year + 1;
+    let birth_chi = get_year_canchi(birth_lunar_year).chi;
+    let year_chi = get_year_canchi(lunar_year).chi;
+
+    PersonYearChecks {
+        rules_version: rules.version.clone(),
+        age_convention: rules.age_convention.clone(),
+        lunar_year,
+        lunar_age,
+        tam_tai: check_tam_tai(data, birth_chi, year_chi),
+        kim_lau: check_kim_lau(data, lunar_age),
+        hoang_oc: check_hoang_oc(data, lunar_age),
+    }
+}
+
+fn check_tam_tai(data: &AlmanacData, birth_chi: Chi, year_chi: Chi) -> PersonYearCheck {
+    let rule = &data.person_year_rules.tam_tai;
+    let years = rule
+        .by_birth_chi
+        .get(&birth_chi)
+        .expect("tam tai years by chi should exist");
+    let listed = years
+        .iter()
+        .map(|chi| chi.name())
+        .collect::<Vec<_>>()
+        .join(" - ");
+    let position = years.iter().position(|&chi| chi == year_chi);
+
+    PersonYearCheck {
+        rule_id: rule.rule_id.clone(),
+        name: rule.name.clone(),
+        severity: rule.severity.clone(),
+        hit: position.is_some(),
+        detail: position.map(|idx| TAM_TAI_ORDINALS[idx].to_string()),
+        reason: match position {
+            Some(_) => format!(
+                "Tuổi {} gặp Tam Tai năm {} (các năm {})",
+                birth_chi, year_chi, listed
+            ),
+            None => format!(
+                "Tuổi {} không gặp Tam Tai năm {} (Tam Tai các năm {})",
+                birth_chi, year_chi, listed
+            ),
+        },
+        evidence: Some(rule_evidence(
+            &data.person_year_rule_meta.tam_tai,
+            &data.profile,
+        )),
+    }
+}
+
+fn check_kim_lau(data: &AlmanacData, lunar_age: i32) -> PersonYearCheck {
+    let rule = &data.person_year_rules.kim_lau;
+    let remainder = lunar_age.rem_euclid(rule.cycle);
+    let matched = rule.by_remainder.get(&remainder);
+
+    PersonYearCheck {
+        rule_id: rule.rule_id.clone(),
+        name: rule.name.clone(),
+        severity: rule.severity.clone(),
+        hit: matched.is_some(),
+        detail: matched.cloned(),
+        reason: match matched {
+            Some(name) => format!(
+                "Tuổi mụ {} chia {} dư {}: phạm {}",
+                lunar_age, rule.cycle, remainder, name
+            ),
+            None => format!(
+                "Tuổi mụ {} chia {} dư {}: không phạm Kim Lâu",
+                lunar_age, rule.cycle, remainder
+            ),
+        },
+        evidence: Some(rule_evidence(
+            &data.person_year_rule_meta.kim_lau,
+            &data.profile,
+        )),
+    }
+}
+
+fn check_hoang_oc(data: &AlmanacData, lunar_age: i32) -> PersonYearCheck {
+    let rule = &data.person_year_rules.hoang_oc;
+    let evidence = Some(rule_evidence(
+        &data.person_year_rule_meta.hoang_oc,
+        &data.profile,
+    ));
+
+    if lunar_age < rule.start_age {
+        return PersonYearCheck {
+            rule_id: rule.rule_id.clone(),
+            name: rule.name.clone(),
+            severity: rule.severity.clone(),
+            hit: false,
+            detail: None,
+            reason: format!(
+                "Tuổi mụ {} chưa đến {}, chưa xét Hoang Ốc",
+                lunar_age, rule.start_age
+            ),
+            evidence,
+        };
+    }
+
+    // `start_age` is the first decade on the hand, so tens and units count
+    // as one palace each.
+    let step = lunar_age / rule.start_age - 1 + lunar_age % rule.start_age;
+    let palace = &rule.palaces[step as usize % rule.palaces.len()];
+    let hit = palace.quality == "hung";
+
+    PersonYearCheck {
+        rule_id: rule.rule_id.clone(),
+        name: rule.name.clone(),
+        severity: rule.severity.clone(),
+        hit,
+        detail: Some(palace.name.clone()),
+        reason: format!(
+            "Tuổi mụ {} vào cung {} ({})",
+            lunar_age,
+            palace.name,
+            if hit { "xấu" } else { "tốt" }
+        ),
+        evidence,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::almanac::types::Gender;
+
+    #[test]
+    fn canh_ngo_in_giap_thin() {
+        let person = PersonProfile::new(1, 6, 1990, Gender::Female);
+        let checks = resolve_person_year(&person, 2024);
+
+        assert_eq!(checks.rules_version, "v1");
+        assert_eq!(checks.age_convention, "tuoi_mu");
+        assert_eq!(checks.lunar_age, 35);
+        assert!(!checks.tam_tai.hit);
+        assert_eq!(
+            checks.tam_tai.reason,
+            "Tuổi Ngọ không gặp Tam Tai năm Thìn (Tam Tai các năm Thân - Dậu - Tuất)"
+        );
+        assert!(checks.kim_lau.hit);
+        assert_eq!(checks.kim_lau.detail.as_deref(), Some("Kim Lâu Lục Súc"));
+        assert_eq!(
+            checks.kim_lau.reason,
+            "Tuổi mụ 35 chia 9 dư 8: phạm Kim Lâu Lục Súc"
+        );
+        assert!(!checks.hoang_oc.hit);
+        assert_eq!(checks.hoang_oc.detail.as_deref(), Some("Nhì Nghi"));
+
+        let evidence = checks.kim_lau.evidence.expect("evidence");
+        assert_eq!(evidence.source_id, "ngoc-hap-chanh-tong");
+        assert_eq!(evidence.method, "bai-quyet");
+        assert_eq!(evidence.profile, "baseline");
+    }
+
+    #[test]
+    fn tam_tai_runs_three_years() {
+        // Canh Ngọ (1990): Thân, Dậu, Tuất years
+        let hits: Vec<_> = (2026..=2031)
+            .map(|year| resolve_person_year_rules(1990, year).tam_tai)
+            .map(|check| check.detail)
+            .collect();
+        assert_eq!(
+            hits,
+            vec![
+                None,
+                None,
+                Some("năm đầu".to_string()),
+                Some("năm giữa".to_string()),
+                Some("năm cuối".to_string()),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn kim_lau_ages() {
+        let ages: Vec<i32> = (10..=40)
+            .filter(|&age| resolve_person_year_rules(2000, 2000 + age - 1).kim_lau.hit)
+            .collect();
+        assert_eq!(
+            ages,
+            vec![10, 12, 15, 17, 19, 21, 24, 26, 28, 30, 33, 35, 37, 39]
+        );
+    }
+
+    #[test]
+    fn hoang_oc_palaces() {
+        let palace = |age: i32| {
+            resolve_person_year_rules(2000, 2000 + age - 1)
+                .hoang_oc
+                .detail
+                .unwrap_or_default()
+        };
+        assert_eq!(palace(10), "Nhất Cát");
+        assert_eq!(palace(20), "Nhì Nghi");
+        assert_eq!(palace(32), "Ngũ Thọ Tử");
+        assert_eq!(palace(40), "Tứ Tấn Tài");
+        assert_eq!(palace(60), "Lục Hoang Ốc");
+        assert_eq!(palace(70), "Nhất Cát");
+
+        let bad: Vec<i32> = (10..=33)
+            .filter(|&age| resolve_person_year_rules(2000, 2000 + age - 1).hoang_oc.hit)
+            .collect();
+        assert_eq!(bad, vec![12, 14, 15, 18, 21, 23, 24, 27, 29, 30, 32, 33]);
+
+        let young = resolve_person_year_rules(2020, 2024).hoang_oc;
+        assert!(!young.hit);
+        assert_eq!(young.detail, None);
+    }
+}
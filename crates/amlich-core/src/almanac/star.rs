@@ -12,7 +12,10 @@
 /// 3. `FixedByCanChi` — full sexagenary pair keyed
 /// 4. `FixedByChi`  — day's earthly-branch keyed (base rules)
 /// 5. `JdCycle`     — Julian Day modular cycle (nhị thập bát tú)
-use std::collections::HashMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use alloc::collections::BTreeMap;
 
 /// Determines which source a star rule comes from.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -71,7 +74,7 @@ pub fn category_priority(cat: &StarCategory) -> u8 {
 /// Both output lists are sorted for deterministic output.
 pub fn resolve_rules(rules: &[StarRule]) -> (Vec<String>, Vec<String>) {
     // For each star name, keep the rule with the lowest priority number.
-    let mut by_name: HashMap<&str, &StarRule> = HashMap::new();
+    let mut by_name: BTreeMap<&str, &StarRule> = BTreeMap::new();
     for rule in rules {
         let entry = by_name.entry(rule.name.as_str()).or_insert(rule);
         if category_priority(&rule.category) < category_priority(&entry.category) {
@@ -1,4 +1,8 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
 use super::data::baseline_data;
+use crate::types::Chi;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TabooSeverity {
@@ -30,7 +34,7 @@ pub struct TabooHit {
     pub severity: TabooSeverity,
 }
 
-pub fn resolve_day_taboos(lunar_day: i32, lunar_month: i32, day_chi: &str) -> Vec<TabooHit> {
+pub fn resolve_day_taboos(lunar_day: i32, lunar_month: i32, day_chi: Chi) -> Vec<TabooHit> {
     let data = baseline_data();
     let mut hits = Vec::new();
 
@@ -68,7 +72,7 @@ pub fn resolve_day_taboos(lunar_day: i32, lunar_month: i32, day_chi: &str) -> Ve
             .sat_chu
             .by_lunar_month
             .get(&lunar_month_u8)
-            .is_some_and(|chi| chi == day_chi)
+            .is_some_and(|chi| *chi == day_chi)
         {
             hits.push(TabooHit {
                 rule_id: data.taboo_rules.sat_chu.rule_id.clone(),
@@ -82,7 +86,7 @@ pub fn resolve_day_taboos(lunar_day: i32, lunar_month: i32, day_chi: &str) -> Ve
             .tho_tu
             .by_lunar_month
             .get(&lunar_month_u8)
-            .is_some_and(|chi| chi == day_chi)
+            .is_some_and(|chi| *chi == day_chi)
         {
             hits.push(TabooHit {
                 rule_id: data.taboo_rules.tho_tu.rule_id.clone(),
@@ -98,34 +102,35 @@ pub fn resolve_day_taboos(lunar_day: i32, lunar_month: i32, day_chi: &str) -> Ve
 #[cfg(test)]
 mod tests {
     use super::{resolve_day_taboos, TabooSeverity};
+    use crate::types::Chi;
 
     #[test]
     fn matches_tam_nuong_by_lunar_day() {
-        let hits = resolve_day_taboos(3, 6, "Dậu");
+        let hits = resolve_day_taboos(3, 6, Chi::Dau);
         assert!(hits.iter().any(|hit| hit.rule_id == "tam_nuong"));
     }
 
     #[test]
     fn matches_nguyet_ky_by_lunar_day() {
-        let hits = resolve_day_taboos(14, 4, "Tý");
+        let hits = resolve_day_taboos(14, 4, Chi::Ty);
         assert!(hits.iter().any(|hit| hit.rule_id == "nguyet_ky"));
     }
 
     #[test]
     fn matches_sat_chu_by_lunar_month_and_day_chi() {
-        let hits = resolve_day_taboos(1, 1, "Tỵ");
+        let hits = resolve_day_taboos(1, 1, Chi::Ti);
         assert!(hits.iter().any(|hit| hit.rule_id == "sat_chu"));
     }
 
     #[test]
     fn matches_tho_tu_by_lunar_month_and_day_chi() {
-        let hits = resolve_day_taboos(12, 12, "Mùi");
+        let hits = resolve_day_taboos(12, 12, Chi::Mui);
         assert!(hits.iter().any(|hit| hit.rule_id == "tho_tu"));
     }
 
     #[test]
     fn emits_expected_severity_per_rule() {
-        let hits = resolve_day_taboos(5, 2, "Tý");
+        let hits = resolve_day_taboos(5, 2, Chi::Ty);
         assert!(hits
             .iter()
             .any(|hit| hit.rule_id == "nguyet_ky" && hit.severity == TabooSeverity::Hard));
@@ -133,7 +138,7 @@ mod tests {
             .iter()
             .any(|hit| hit.rule_id == "sat_chu" && hit.severity == TabooSeverity::Hard));
 
-        let soft = resolve_day_taboos(1, 12, "Mùi");
+        let soft = resolve_day_taboos(1, 12, Chi::Mui);
         assert!(soft
             .iter()
             .any(|hit| hit.rule_id == "tho_tu" && hit.severity == TabooSeverity::Soft));
@@ -141,13 +146,13 @@ mod tests {
 
     #[test]
     fn no_hits_for_non_matching_day_context() {
-        let hits = resolve_day_taboos(2, 2, "Dần");
+        let hits = resolve_day_taboos(2, 2, Chi::Dan);
         assert!(hits.is_empty());
     }
 
     #[test]
     fn emits_deterministic_family_order() {
-        let hits = resolve_day_taboos(5, 2, "Tý");
+        let hits = resolve_day_taboos(5, 2, Chi::Ty);
         let ids: Vec<_> = hits.iter().map(|hit| hit.rule_id.as_str()).collect();
         assert_eq!(ids, vec!["nguyet_ky", "sat_chu"]);
     }
@@ -12,16 +12,16 @@
 /// Source tag already applied via `AlmanacData::travel_meta` (Batch 1).
 use super::data::baseline_data;
 use super::types::TravelDirection;
+use crate::types::Can;
 
-/// Return the directional-deity information for the given Heavenly Stem name.
+/// Return the directional-deity information for the given Heavenly Stem.
 ///
-/// Panics if `can` is not one of the 10 canonical stems — that would indicate
-/// a programming error, not a runtime condition.
-pub fn get_than_huong(can: &str) -> TravelDirection {
+/// The ruleset is validated at load to cover all 10 stems.
+pub fn get_than_huong(can: Can) -> TravelDirection {
     let rule = baseline_data()
         .travel_by_can
-        .get(can)
-        .unwrap_or_else(|| panic!("than_huong: unknown can '{can}'"));
+        .get(&can)
+        .unwrap_or_else(|| panic!("than_huong: no rule for can '{can}'"));
 
     TravelDirection {
         xuat_hanh_huong: rule.xuat_hanh_huong.clone(),
@@ -36,7 +36,7 @@ mod tests {
     use super::*;
 
     // Helper: assert three direction fields at once
-    fn check(can: &str, xuat: &str, tai: &str, hy: &str) {
+    fn check(can: Can, xuat: &str, tai: &str, hy: &str) {
         let r = get_than_huong(can);
         assert_eq!(r.xuat_hanh_huong, xuat, "{can}: xuat_hanh_huong");
         assert_eq!(r.tai_than, tai, "{can}: tai_than");
@@ -49,61 +49,58 @@ mod tests {
 
     #[test]
     fn giap_directions() {
-        check("Giáp", "Đông Nam", "Đông Bắc", "Đông Bắc");
+        check(Can::Giap, "Đông Nam", "Đông Bắc", "Đông Bắc");
     }
 
     #[test]
     fn at_directions() {
-        check("Ất", "Đông", "Tây Nam", "Tây Bắc");
+        check(Can::At, "Đông", "Tây Nam", "Tây Bắc");
     }
 
     #[test]
     fn binh_directions() {
-        check("Bính", "Nam", "Tây", "Tây Nam");
+        check(Can::Binh, "Nam", "Tây", "Tây Nam");
     }
 
     #[test]
     fn dinh_directions() {
-        check("Đinh", "Nam", "Tây", "Nam");
+        check(Can::Dinh, "Nam", "Tây", "Nam");
     }
 
     #[test]
     fn mau_directions() {
-        check("Mậu", "Đông Bắc", "Bắc", "Đông Nam");
+        check(Can::Mau, "Đông Bắc", "Bắc", "Đông Nam");
     }
 
     #[test]
     fn ky_directions() {
-        check("Kỷ", "Tây Nam", "Bắc", "Đông Bắc");
+        check(Can::Ky, "Tây Nam", "Bắc", "Đông Bắc");
     }
 
     #[test]
     fn canh_directions() {
-        check("Canh", "Tây Bắc", "Đông", "Tây Bắc");
+        check(Can::Canh, "Tây Bắc", "Đông", "Tây Bắc");
     }
 
     #[test]
     fn tan_directions() {
-        check("Tân", "Tây", "Đông", "Tây Nam");
+        check(Can::Tan, "Tây", "Đông", "Tây Nam");
     }
 
     #[test]
     fn nham_directions() {
-        check("Nhâm", "Bắc", "Nam", "Nam");
+        check(Can::Nham, "Bắc", "Nam", "Nam");
     }
 
     #[test]
     fn quy_directions() {
-        check("Quý", "Tây", "Nam", "Đông Nam");
+        check(Can::Quy, "Tây", "Nam", "Đông Nam");
     }
 
     #[test]
     fn all_10_stems_covered() {
         // Every canonical stem must return valid directions without panic
-        let stems = [
-            "Giáp", "Ất", "Bính", "Đinh", "Mậu", "Kỷ", "Canh", "Tân", "Nhâm", "Quý",
-        ];
-        for can in stems {
+        for can in Can::ALL {
             let r = get_than_huong(can);
             assert!(
                 !r.xuat_hanh_huong.is_empty(),
@@ -1,17 +1,22 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
 use super::data::{baseline_data, StarRuleBucket};
 use super::star::{StarCategory, StarQualityTag, StarRule};
+use crate::tietkhi::SolarTermId;
+use crate::types::{Can, Chi};
 
 pub fn get_day_star_rules(
-    day_chi: &str,
-    day_canchi_full: &str,
-    year_can: &str,
+    day_can: Can,
+    day_chi: Chi,
+    year_can: Can,
     lunar_month: i32,
-    tiet_khi_name: &str,
+    tiet_khi: SolarTermId,
 ) -> Vec<StarRule> {
     let data = baseline_data();
     let mut rules = Vec::new();
 
-    if let Some(conflict) = data.conflict_by_chi.get(day_chi) {
+    if let Some(conflict) = data.conflict_by_chi.get(&day_chi) {
         let source = data.star_rule_meta.fixed_by_chi.source_id.clone();
         for name in &conflict.cat_tinh {
             rules.push(StarRule {
@@ -31,7 +36,7 @@ pub fn get_day_star_rules(
         }
     }
 
-    if let Some(bucket) = data.star_rules_fixed_by_canchi.get(day_canchi_full) {
+    if let Some(bucket) = data.star_rules_fixed_by_canchi.get(&(day_can, day_chi)) {
         append_bucket_rules(
             &mut rules,
             bucket,
@@ -40,7 +45,7 @@ pub fn get_day_star_rules(
         );
     }
 
-    if let Some(bucket) = data.star_rules_by_year_can.get(year_can) {
+    if let Some(bucket) = data.star_rules_by_year_can.get(&year_can) {
         append_bucket_rules(
             &mut rules,
             bucket,
@@ -60,7 +65,7 @@ pub fn get_day_star_rules(
         }
     }
 
-    if let Some(bucket) = data.star_rules_by_tiet_khi.get(tiet_khi_name) {
+    if let Some(bucket) = data.star_rules_by_tiet_khi.get(&tiet_khi) {
         append_bucket_rules(
             &mut rules,
             bucket,
@@ -113,7 +118,7 @@ mod tests {
 
     #[test]
     fn fixed_by_chi_rules_for_ty() {
-        let rules = get_day_star_rules("Tý", "Giáp Tý", "Giáp", 1, "Lập Xuân");
+        let rules = get_day_star_rules(Can::Giap, Chi::Ty, Can::Giap, 1, SolarTermId::LapXuan);
         let fixed: Vec<_> = rules
             .iter()
             .filter(|r| r.category == StarCategory::FixedByChi)
@@ -135,11 +140,8 @@ mod tests {
 
     #[test]
     fn all_12_chi_produce_nonempty_rules() {
-        let chi_list = [
-            "Tý", "Sửu", "Dần", "Mão", "Thìn", "Tỵ", "Ngọ", "Mùi", "Thân", "Dậu", "Tuất", "Hợi",
-        ];
-        for chi in chi_list {
-            let rules = get_day_star_rules(chi, "Giáp Tý", "Giáp", 1, "Lập Xuân");
+        for chi in Chi::ALL {
+            let rules = get_day_star_rules(Can::Giap, chi, Can::Giap, 1, SolarTermId::LapXuan);
             assert!(!rules.is_empty(), "{chi}: must produce at least one rule");
             assert!(rules.iter().any(|r| r.quality == StarQualityTag::Cat));
             assert!(rules.iter().any(|r| r.quality == StarQualityTag::Hung));
@@ -148,7 +150,7 @@ mod tests {
 
     #[test]
     fn source_id_is_populated() {
-        let rules = get_day_star_rules("Tý", "Giáp Tý", "Giáp", 1, "Lập Xuân");
+        let rules = get_day_star_rules(Can::Giap, Chi::Ty, Can::Giap, 1, SolarTermId::LapXuan);
         for rule in &rules {
             assert!(!rule.source_id.is_empty(), "source_id must not be empty");
         }
@@ -254,7 +256,7 @@ mod tests {
 
     #[test]
     fn resolve_ty_day_rules() {
-        let rules = get_day_star_rules("Tý", "Giáp Tý", "Giáp", 1, "Lập Xuân");
+        let rules = get_day_star_rules(Can::Giap, Chi::Ty, Can::Giap, 1, SolarTermId::LapXuan);
         let (cat, hung) = resolve_rules(&rules);
         assert!(cat.contains(&"Thiên Đức".to_string()));
         assert!(cat.contains(&"Nguyệt Đức".to_string()));
@@ -264,14 +266,15 @@ mod tests {
 
     #[test]
     fn unknown_context_keys_do_not_crash_or_remove_fixed_by_chi() {
-        let rules = get_day_star_rules("Tý", "X Y", "Unknown", 99, "Unknown");
+        // Only Giáp Thìn, Giáp years, month 1 and Lập Xuân carry extra rules
+        let rules = get_day_star_rules(Can::At, Chi::Ty, Can::At, 99, SolarTermId::DongChi);
         assert!(!rules.is_empty());
         assert!(rules.iter().all(|r| r.category == StarCategory::FixedByChi));
     }
 
     #[test]
     fn emits_rules_from_all_context_categories_when_data_matches() {
-        let rules = get_day_star_rules("Thìn", "Giáp Thìn", "Giáp", 1, "Lập Xuân");
+        let rules = get_day_star_rules(Can::Giap, Chi::Thin, Can::Giap, 1, SolarTermId::LapXuan);
         assert!(rules.iter().any(|r| r.category == StarCategory::FixedByChi));
         assert!(rules
             .iter()
@@ -283,7 +286,7 @@ mod tests {
 
     #[test]
     fn real_data_precedence_prefers_tiet_khi_then_month_then_year_then_canchi() {
-        let rules = get_day_star_rules("Thìn", "Giáp Thìn", "Giáp", 1, "Lập Xuân");
+        let rules = get_day_star_rules(Can::Giap, Chi::Thin, Can::Giap, 1, SolarTermId::LapXuan);
         let (cat, hung) = resolve_rules(&rules);
 
         // Bạch Hổ appears as Hung in FixedByChi, Cat in ByYear, Hung in ByMonth, Cat in ByTietKhi.
@@ -16,6 +16,8 @@
 /// `truc_index = (day_chi_index − month_chi_index + 12) % 12`
 ///
 /// **Source:** Khâm Định Hiệp Kỷ Biện Phương Thư (欽定協紀辨方書), method: formula.
+use alloc::string::ToString;
+
 use super::types::TrucInfo;
 
 pub const TRUC_NAMES: [&str; 12] = [
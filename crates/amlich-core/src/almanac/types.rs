@@ -1,5 +1,13 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
+use crate::types::{Can, Chi, Element, ParseNameError};
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RuleSetDefaults {
     pub tz_offset: f64,
@@ -50,15 +58,15 @@ pub enum StarQuality {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DayElement {
     pub na_am: String,
-    pub element: String,
-    pub can_element: String,
-    pub chi_element: String,
+    pub element: Element,
+    pub can_element: Element,
+    pub chi_element: Element,
     pub evidence: Option<RuleEvidence>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DayConflict {
-    pub opposing_chi: String,
+    pub opposing_chi: Chi,
     pub opposing_con_giap: String,
     pub tuoi_xung: Vec<String>,
     pub sat_huong: String,
@@ -147,11 +155,11 @@ pub struct TrucInfo {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct XungHopResult {
     /// Directly opposing branch (lục xung).
-    pub luc_xung: String,
+    pub luc_xung: Chi,
     /// Three-harmony triad (tam hợp).
-    pub tam_hop: Vec<String>,
+    pub tam_hop: Vec<Chi>,
     /// Four-clash square (tứ hành xung).
-    pub tu_hanh_xung: Vec<String>,
+    pub tu_hanh_xung: Vec<Chi>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -169,6 +177,251 @@ pub struct DayFortune {
     pub truc: TrucInfo,
 }
 
+/// Gender recorded on a person profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Gender {
+    Male,
+    Female,
+}
+
+/// Branch relation between a person and a day or hour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PersonRelation {
+    /// Directly opposing branches (lục xung).
+    LucXung,
+    /// Same four-clash square, three branches apart (tứ hành xung).
+    TuHanhXung,
+    /// Same three-harmony triad (tam hợp).
+    TamHop,
+    /// Six-harmony pair (lục hợp).
+    LucHop,
+}
+
+impl PersonRelation {
+    pub fn is_clash(self) -> bool {
+        matches!(self, PersonRelation::LucXung | PersonRelation::TuHanhXung)
+    }
+}
+
+/// Which part of the person's birth data a relation was found against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PersonBasis {
+    /// Branch of the lunar birth year (tuổi).
+    BirthYear,
+    /// Branch of the birth hour.
+    BirthHour,
+}
+
+/// Overall verdict of a day or hour for a person.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PersonVerdict {
+    /// At least one clash (xung).
+    Xung,
+    /// No clash and at least one harmony (hợp).
+    Hop,
+    /// Neither (bình).
+    Binh,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonRelationHit {
+    pub relation: PersonRelation,
+    pub basis: PersonBasis,
+    /// Stems clash as well (thiên khắc địa xung); only set on lục xung.
+    pub thien_khac: bool,
+}
+
+/// How one day or hour relates to a person.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonMatch {
+    pub verdict: PersonVerdict,
+    pub relations: Vec<PersonRelationHit>,
+    pub reasons: Vec<String>,
+    pub evidence: Option<RuleEvidence>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonHourMatch {
+    pub hour_index: usize,
+    pub hour_chi: Chi,
+    pub time_range: String,
+    #[serde(flatten)]
+    pub result: PersonMatch,
+}
+
+/// Person-aware evaluation of a day and each of its hours.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonDayEvaluation {
+    pub birth_year_can: Can,
+    pub birth_year_chi: Chi,
+    pub day: PersonMatch,
+    pub hours: Vec<PersonHourMatch>,
+}
+
+/// Result of one yearly rule (Tam Tai, Kim Lâu, Hoang Ốc) for a person.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonYearCheck {
+    pub rule_id: String,
+    pub name: String,
+    pub severity: String,
+    /// Whether the person falls under the rule this year
+    pub hit: bool,
+    /// Matched variant, e.g. "Kim Lâu Thê" or "Tứ Tấn Tài"
+    pub detail: Option<String>,
+    pub reason: String,
+    pub evidence: Option<RuleEvidence>,
+}
+
+/// Yearly checks for a person in one lunar year.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonYearChecks {
+    pub rules_version: String,
+    pub age_convention: String,
+    pub lunar_year: i32,
+    /// Age in `age_convention` (tuổi mụ)
+    pub lunar_age: i32,
+    pub tam_tai: PersonYearCheck,
+    pub kim_lau: PersonYearCheck,
+    pub hoang_oc: PersonYearCheck,
+}
+
+/// Cửu Diệu star of the year for a person.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CuuDieuInfo {
+    pub name: String,
+    pub quality: StarQuality,
+    /// Lunar day of the monthly ritual (cúng sao giải hạn)
+    pub ritual_day: u8,
+    /// Direction to face during the ritual
+    pub ritual_direction: String,
+    pub reason: String,
+    pub evidence: Option<RuleEvidence>,
+}
+
+/// Yearly Hạn for a person.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct YearlyHanInfo {
+    pub name: String,
+    pub description: String,
+    pub reason: String,
+    pub evidence: Option<RuleEvidence>,
+}
+
+/// Cửu Diệu star and Hạn for a person in one lunar year.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonStarYear {
+    pub rules_version: String,
+    pub age_convention: String,
+    pub lunar_year: i32,
+    /// Age in `age_convention` (tuổi mụ)
+    pub lunar_age: i32,
+    pub gender: Gender,
+    pub star: CuuDieuInfo,
+    /// `None` below the age at which Hạn is counted
+    pub han: Option<YearlyHanInfo>,
+}
+
+/// Activity a day is scored for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Activity {
+    /// Cưới hỏi (wedding, engagement)
+    CuoiHoi,
+    /// Động thổ (groundbreaking)
+    DongTho,
+    /// Khai trương (opening a shop or business)
+    KhaiTruong,
+    /// Xuất hành (setting out on a journey)
+    XuatHanh,
+    /// An táng (burial)
+    AnTang,
+}
+
+impl Activity {
+    pub const ALL: [Activity; 5] = [
+        Activity::CuoiHoi,
+        Activity::DongTho,
+        Activity::KhaiTruong,
+        Activity::XuatHanh,
+        Activity::AnTang,
+    ];
+
+    /// Policy id in the ruleset data (e.g. "cuoi_hoi").
+    pub fn id(self) -> &'static str {
+        match self {
+            Activity::CuoiHoi => "cuoi_hoi",
+            Activity::DongTho => "dong_tho",
+            Activity::KhaiTruong => "khai_truong",
+            Activity::XuatHanh => "xuat_hanh",
+            Activity::AnTang => "an_tang",
+        }
+    }
+}
+
+impl fmt::Display for Activity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+impl FromStr for Activity {
+    type Err = ParseNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Activity::ALL
+            .into_iter()
+            .find(|activity| activity.id() == s)
+            .ok_or_else(|| ParseNameError::new("activity", s))
+    }
+}
+
+/// Overall verdict of a day for an activity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityVerdict {
+    Good,
+    Fair,
+    Poor,
+    /// A hard filter matched; the score is not a recommendation
+    Rejected,
+}
+
+/// Hard filter that rules a day out for an activity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityFilterHit {
+    /// Taboo rule id or "truc"
+    pub filter_id: String,
+    pub reason: String,
+    pub evidence: Option<RuleEvidence>,
+}
+
+/// One weighted factor of an activity score.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityFactor {
+    /// Weight key in the policy (e.g. "cat_tinh", "favored_truc")
+    pub factor_id: String,
+    pub points: i32,
+    pub reason: String,
+}
+
+/// Explained score of a day for an activity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityScore {
+    pub activity: Activity,
+    pub activity_name: String,
+    pub policy_version: String,
+    /// Sum of `factors[].points`
+    pub score: i32,
+    pub verdict: ActivityVerdict,
+    pub hard_filters: Vec<ActivityFilterHit>,
+    pub factors: Vec<ActivityFactor>,
+    pub evidence: Option<RuleEvidence>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -220,13 +473,13 @@ mod tests {
             profile: "baseline".to_string(),
             day_element: DayElement {
                 na_am: "Hải Trung Kim".to_string(),
-                element: "Kim".to_string(),
-                can_element: "Mộc".to_string(),
-                chi_element: "Thổ".to_string(),
+                element: Element::Kim,
+                can_element: Element::Moc,
+                chi_element: Element::Tho,
                 evidence: None,
             },
             conflict: DayConflict {
-                opposing_chi: "Tuất".to_string(),
+                opposing_chi: Chi::Tuat,
                 opposing_con_giap: "Tuất (Chó)".to_string(),
                 tuoi_xung: vec!["Nhâm Tuất".to_string()],
                 sat_huong: "Nam".to_string(),
@@ -265,14 +518,9 @@ mod tests {
                 evidence: None,
             }],
             xung_hop: XungHopResult {
-                luc_xung: "Tuất".to_string(),
-                tam_hop: vec!["Dần".to_string(), "Ngọ".to_string(), "Tuất".to_string()],
-                tu_hanh_xung: vec![
-                    "Tý".to_string(),
-                    "Mão".to_string(),
-                    "Ngọ".to_string(),
-                    "Dậu".to_string(),
-                ],
+                luc_xung: Chi::Tuat,
+                tam_hop: vec![Chi::Dan, Chi::Ngo, Chi::Tuat],
+                tu_hanh_xung: vec![Chi::Ty, Chi::Mao, Chi::Ngo, Chi::Dau],
             },
             truc: TrucInfo {
                 index: 2,
@@ -283,9 +531,11 @@ mod tests {
         };
 
         let encoded = serde_json::to_string(&value).expect("serialize");
+        assert!(encoded.contains(r#""element":"Kim""#));
+        assert!(encoded.contains(r#""luc_xung":"Tuất""#));
         let decoded: DayFortune = serde_json::from_str(&encoded).expect("deserialize");
         assert_eq!(decoded.profile, "baseline");
         assert_eq!(decoded.ruleset_id, "vn_baseline_v1");
-        assert_eq!(decoded.day_element.element, "Kim");
+        assert_eq!(decoded.day_element.element, Element::Kim);
     }
 }
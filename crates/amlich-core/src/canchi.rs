@@ -11,7 +11,8 @@
  * - Month Can Chi: Fixed branch by lunar month, stem by year
  * - Year Can Chi: Standard formula from lunar year
  */
-use crate::types::{normalize_index, CanChi};
+use crate::types::{normalize_index, Can, CanChi, Chi};
+use alloc::format;
 
 /// Get Can Chi for a given day
 ///
@@ -72,7 +73,7 @@ pub fn get_month_canchi(lunar_month: i32, lunar_year: i32, is_leap_month: bool)
     let first_month_can = first_month_can_table[year_can_index];
 
     // Calculate current month stem (offset from month 1)
-    let can_index = normalize_index(first_month_can as i32 + (lunar_month - 1), 10);
+    let can_index = normalize_index(first_month_can + (lunar_month - 1), 10);
 
     let mut result = CanChi::new(can_index, chi_index);
 
@@ -107,6 +108,25 @@ pub fn get_year_canchi(lunar_year: i32) -> CanChi {
     CanChi::new(can_index, chi_index)
 }
 
+/// Get Can Chi for a two-hour period (giờ) of a day
+///
+/// The stem depends only on the day stem and the hour branch: the stem of
+/// the Tý hour follows the day stem (Ngũ Thử Độn): Giáp/Kỷ → Giáp Tý,
+/// Ất/Canh → Bính Tý, Bính/Tân → Mậu Tý, Đinh/Nhâm → Canh Tý, Mậu/Quý → Nhâm
+/// Tý. Where each branch sits on the clock is decided by
+/// [`crate::gio_hoang_dao::get_hour_starts`].
+///
+/// # Arguments
+/// * `day_can` - Day stem
+/// * `hour_chi` - Hour branch
+///
+/// # Returns
+/// Hour Can Chi information
+pub fn get_hour_canchi(day_can: Can, hour_chi: Chi) -> CanChi {
+    let first_hour_can = Can::from_index((day_can.index() % 5) * 2);
+    CanChi::from_parts(first_hour_can + hour_chi.index() as i32, hour_chi)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,8 +135,8 @@ mod tests {
     fn test_day_canchi_tet_2024() {
         // Tết 2024: February 10, 2024 (JD 2460351) = Giáp Thìn
         let cc = get_day_canchi(2460351);
-        assert_eq!(cc.can, "Giáp");
-        assert_eq!(cc.chi, "Thìn");
+        assert_eq!(cc.can, Can::Giap);
+        assert_eq!(cc.chi, Chi::Thin);
         assert_eq!(cc.full, "Giáp Thìn");
     }
 
@@ -124,16 +144,16 @@ mod tests {
     fn test_day_canchi_tet_2025() {
         // Tết 2025: January 29, 2025 (JD 2460705) = Mậu Tuất
         let cc = get_day_canchi(2460705);
-        assert_eq!(cc.can, "Mậu");
-        assert_eq!(cc.chi, "Tuất");
+        assert_eq!(cc.can, Can::Mau);
+        assert_eq!(cc.chi, Chi::Tuat);
     }
 
     #[test]
     fn test_year_canchi_2024() {
         // 2024 lunar year = Giáp Thìn (Year of the Dragon)
         let cc = get_year_canchi(2024);
-        assert_eq!(cc.can, "Giáp");
-        assert_eq!(cc.chi, "Thìn");
+        assert_eq!(cc.can, Can::Giap);
+        assert_eq!(cc.chi, Chi::Thin);
         assert_eq!(cc.con_giap, "Thìn (Rồng)");
     }
 
@@ -141,8 +161,8 @@ mod tests {
     fn test_year_canchi_2025() {
         // 2025 lunar year = Ất Tỵ (Year of the Snake)
         let cc = get_year_canchi(2025);
-        assert_eq!(cc.can, "Ất");
-        assert_eq!(cc.chi, "Tỵ");
+        assert_eq!(cc.can, Can::At);
+        assert_eq!(cc.chi, Chi::Ti);
         assert_eq!(cc.con_giap, "Tỵ (Rắn)");
     }
 
@@ -150,8 +170,8 @@ mod tests {
     fn test_year_canchi_2023() {
         // 2023 lunar year = Quý Mão (Year of the Cat)
         let cc = get_year_canchi(2023);
-        assert_eq!(cc.can, "Quý");
-        assert_eq!(cc.chi, "Mão");
+        assert_eq!(cc.can, Can::Quy);
+        assert_eq!(cc.chi, Chi::Mao);
         assert_eq!(cc.con_giap, "Mão (Mèo)");
     }
 
@@ -161,8 +181,8 @@ mod tests {
         // Giáp year → Month 1 starts with Bính (index 2)
         // Month 1 branch = Dần (index 2)
         let cc = get_month_canchi(1, 2024, false);
-        assert_eq!(cc.can, "Bính");
-        assert_eq!(cc.chi, "Dần");
+        assert_eq!(cc.can, Can::Binh);
+        assert_eq!(cc.chi, Chi::Dan);
     }
 
     #[test]
@@ -179,8 +199,22 @@ mod tests {
         let month2 = get_month_canchi(2, 2024, false);
 
         // Stems should progress by 1
-        assert_eq!((month1.can_index + 1) % 10, month2.can_index);
+        assert_eq!(month1.can + 1, month2.can);
         // Branches should progress by 1
-        assert_eq!((month1.chi_index + 1) % 12, month2.chi_index);
+        assert_eq!(month1.chi + 1, month2.chi);
+    }
+
+    #[test]
+    fn test_hour_canchi_ngu_thu_don() {
+        // Giáp day: Tý hour is Giáp Tý, Hợi hour is Ất Hợi
+        assert_eq!(get_hour_canchi(Can::Giap, Chi::Ty).full, "Giáp Tý");
+        assert_eq!(get_hour_canchi(Can::Giap, Chi::Hoi).full, "Ất Hợi");
+        // Kỷ day repeats the Giáp cycle
+        assert_eq!(get_hour_canchi(Can::Ky, Chi::Ty).full, "Giáp Tý");
+        // Mậu day: Tý hour is Nhâm Tý, Ngọ hour is Mậu Ngọ
+        assert_eq!(get_hour_canchi(Can::Mau, Chi::Ty).full, "Nhâm Tý");
+        assert_eq!(get_hour_canchi(Can::Mau, Chi::Ngo).full, "Mậu Ngọ");
+        // Quý day: Hợi hour closes the 60-hour cycle at Quý Hợi
+        assert_eq!(get_hour_canchi(Can::Quy, Chi::Hoi).full, "Quý Hợi");
     }
 }
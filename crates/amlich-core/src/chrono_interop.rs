@@ -0,0 +1,136 @@
+/**
+ * chrono Interop
+ *
+ * Conversions between `chrono::NaiveDate` and `SolarDate`/`LunarDate`, behind
+ * the `chrono` feature.
+ *
+ * `NaiveDate` is proleptic Gregorian while `SolarDate` follows the Julian
+ * calendar before 1582-10-15, so conversions go through the Julian day
+ * number instead of copying day, month and year.
+ */
+use crate::julian::{jd_from_date, jd_to_date};
+use crate::lunar::{convert_lunar_to_solar, convert_solar_to_lunar, LunarDate, SolarDate};
+use crate::types::VIETNAM_TIMEZONE;
+use chrono::{Datelike, NaiveDate};
+
+/// Julian day number of 0000-12-31 (proleptic Gregorian), chrono's day 0
+const CE_EPOCH_JD: i32 = 1721425;
+
+/// Julian day number of a `NaiveDate`
+pub fn jd_from_naive_date(date: NaiveDate) -> i32 {
+    date.num_days_from_ce() + CE_EPOCH_JD
+}
+
+/// `NaiveDate` of a Julian day number, `None` outside chrono's range
+pub fn naive_date_from_jd(jd: i32) -> Option<NaiveDate> {
+    NaiveDate::from_num_days_from_ce_opt(jd - CE_EPOCH_JD)
+}
+
+impl From<NaiveDate> for SolarDate {
+    fn from(date: NaiveDate) -> Self {
+        let (day, month, year) = jd_to_date(jd_from_naive_date(date));
+        SolarDate { day, month, year }
+    }
+}
+
+impl SolarDate {
+    /// Same day as a `NaiveDate`, `None` outside chrono's range
+    pub fn to_naive_date(&self) -> Option<NaiveDate> {
+        naive_date_from_jd(jd_from_date(self.day, self.month, self.year))
+    }
+}
+
+impl LunarDate {
+    /// Lunar date of a solar date, in Vietnam time (UTC+7)
+    ///
+    /// # Example
+    /// ```
+    /// use amlich_core::lunar::LunarDate;
+    /// use chrono::NaiveDate;
+    ///
+    /// let tet = LunarDate::from_solar(NaiveDate::from_ymd_opt(2024, 2, 10).unwrap());
+    /// assert_eq!(tet.to_string(), "1/1/2024");
+    /// ```
+    pub fn from_solar(date: NaiveDate) -> Self {
+        let solar = SolarDate::from(date);
+        convert_solar_to_lunar(solar.day, solar.month, solar.year, VIETNAM_TIMEZONE)
+    }
+
+    /// Solar date of this lunar date, in Vietnam time (UTC+7)
+    ///
+    /// `None` when the date does not exist (no such leap month, day 30 of a
+    /// 29-day month) or is outside the supported range; use
+    /// `convert_lunar_to_solar` to learn which.
+    pub fn to_solar(&self) -> Option<NaiveDate> {
+        convert_lunar_to_solar(
+            self.day,
+            self.month,
+            self.year,
+            self.is_leap,
+            VIETNAM_TIMEZONE,
+        )
+        .ok()?
+        .to_naive_date()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_jd_round_trip() {
+        assert_eq!(jd_from_naive_date(ymd(2000, 1, 1)), 2451545);
+        assert_eq!(jd_from_naive_date(ymd(2024, 2, 10)), 2460351);
+        assert_eq!(naive_date_from_jd(2460351), Some(ymd(2024, 2, 10)));
+    }
+
+    #[test]
+    fn test_solar_date_uses_julian_calendar_before_1582() {
+        // The day before the Gregorian reform is 4 October 1582 (Julian)
+        let solar = SolarDate::from(ymd(1582, 10, 14));
+        assert_eq!(
+            solar,
+            SolarDate {
+                day: 4,
+                month: 10,
+                year: 1582
+            }
+        );
+        assert_eq!(solar.to_naive_date(), Some(ymd(1582, 10, 14)));
+        assert_eq!(
+            SolarDate::from(ymd(2024, 2, 10)).to_naive_date(),
+            Some(ymd(2024, 2, 10))
+        );
+    }
+
+    #[test]
+    fn test_lunar_date_from_and_to_solar() {
+        let tet = LunarDate::from_solar(ymd(2025, 1, 29));
+        assert_eq!(
+            tet,
+            LunarDate {
+                day: 1,
+                month: 1,
+                year: 2025,
+                is_leap: false
+            }
+        );
+        assert_eq!(tet.to_solar(), Some(ymd(2025, 1, 29)));
+
+        // 2025 has a leap sixth month starting on 25 July
+        let leap = LunarDate::from_solar(ymd(2025, 7, 25));
+        assert_eq!(leap.to_string(), "1/6/2025 (nhuận)");
+        assert_eq!(leap.to_solar(), Some(ymd(2025, 7, 25)));
+
+        let missing_leap = LunarDate {
+            is_leap: true,
+            ..tet
+        };
+        assert_eq!(missing_leap.to_solar(), None);
+    }
+}
@@ -0,0 +1,637 @@
+use alloc::format;
+/**
+ * Day Information
+ *
+ * Everything shown for one solar date: lunar date, Can Chi, solar term,
+ * Giờ Hoàng Đạo and the almanac day fortune. Needs the `almanac` feature,
+ * since the day fortune comes from the JSON rulesets.
+ */
+use alloc::string::{String, ToString};
+
+use crate::almanac::calc::calculate_day_fortune;
+use crate::almanac::types::DayFortune;
+use crate::canchi::{get_day_canchi, get_month_canchi, get_year_canchi};
+use crate::ephemeris::{EphemerisMode, YearOutOfRange};
+use crate::gio_hoang_dao::{get_gio_hoang_dao, GioHoangDao};
+use crate::historical::{convert_solar_to_lunar_with_policy, PolicyMonths, TimezonePolicy};
+use crate::julian::{jd_from_date, jd_to_date};
+use crate::location::Location;
+use crate::lunar::{convert_solar_to_lunar_with_mode, LunarCache, LunarDate, SolarDate};
+use crate::sun_times::{get_sun_times, SunTimes};
+use crate::tietkhi::{get_tiet_khi_with_mode, SolarTerm};
+use crate::types::{CanChi, THU, VIETNAM_TIMEZONE};
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+/// Solar date information
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolarInfo {
+    pub day: i32,
+    pub month: i32,
+    pub year: i32,
+    pub day_of_week: usize,
+    pub day_of_week_name: String,
+    pub date_string: String,
+}
+
+/// Lunar date information
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LunarInfo {
+    pub day: i32,
+    pub month: i32,
+    pub year: i32,
+    pub is_leap_month: bool,
+    pub date_string: String,
+}
+
+/// Can Chi information for day, month, and year
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CanChiInfo {
+    pub day: CanChi,
+    pub month: CanChi,
+    pub year: CanChi,
+    pub full: String,
+}
+
+/// Complete information about a day
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DayInfo {
+    pub ruleset_id: String,
+    pub ruleset_version: String,
+    pub solar: SolarInfo,
+    pub lunar: LunarInfo,
+    pub jd: i32,
+    pub canchi: CanChiInfo,
+    pub tiet_khi: SolarTerm,
+    pub gio_hoang_dao: GioHoangDao,
+    pub day_fortune: DayFortune,
+    /// Sunrise, sunset and twilight, when a location was supplied
+    pub sun_times: Option<SunTimes>,
+}
+
+/// Get comprehensive information for a given solar date
+///
+/// # Arguments
+/// * `day` - Day (1-31)
+/// * `month` - Month (1-12)
+/// * `year` - Year
+///
+/// # Returns
+/// Complete day information including solar, lunar, Can Chi, solar terms, and auspicious hours
+///
+/// # Example
+/// ```
+/// use amlich_core::get_day_info;
+///
+/// // Get info for Tết 2024 (February 10, 2024)
+/// let info = get_day_info(10, 2, 2024);
+/// println!("Lunar date: {}/{}/{}", info.lunar.day, info.lunar.month, info.lunar.year);
+/// println!("Day Can Chi: {}", info.canchi.day.full);
+/// ```
+pub fn get_day_info(day: i32, month: i32, year: i32) -> DayInfo {
+    get_day_info_with_timezone(day, month, year, VIETNAM_TIMEZONE)
+}
+
+/// Get comprehensive information for a `chrono::NaiveDate`
+///
+/// Same as `get_day_info` (Vietnam, UTC+7); dates before 1582-10-15 are
+/// mapped to the Julian calendar `get_day_info` uses there.
+///
+/// # Example
+/// ```
+/// use amlich_core::get_day_info_for;
+/// use chrono::NaiveDate;
+///
+/// let info = get_day_info_for(NaiveDate::from_ymd_opt(2024, 2, 10).unwrap());
+/// assert_eq!(info.lunar.date_string, "1/1/2024");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn get_day_info_for(date: NaiveDate) -> DayInfo {
+    let solar = SolarDate::from(date);
+    get_day_info(solar.day, solar.month, solar.year)
+}
+
+/// Get comprehensive information for a given solar date with custom timezone
+///
+/// # Arguments
+/// * `day` - Day (1-31)
+/// * `month` - Month (1-12)
+/// * `year` - Year
+/// * `time_zone` - Timezone offset (default: VIETNAM_TIMEZONE for Vietnam UTC+7)
+///
+/// # Returns
+/// Complete day information
+pub fn get_day_info_with_timezone(day: i32, month: i32, year: i32, time_zone: f64) -> DayInfo {
+    get_day_info_with_mode(day, month, year, time_zone, EphemerisMode::Fast)
+}
+
+/// Get comprehensive information for a given solar date with a selectable ephemeris
+///
+/// `EphemerisMode::Fast` reproduces `get_day_info_with_timezone`;
+/// `EphemerisMode::Accurate` uses VSOP87 and modern ΔT for the lunar month
+/// boundaries and solar terms (see `ephemeris`).
+///
+/// # Arguments
+/// * `day` - Day (1-31)
+/// * `month` - Month (1-12)
+/// * `year` - Year
+/// * `time_zone` - Timezone offset (default: VIETNAM_TIMEZONE for Vietnam UTC+7)
+/// * `mode` - Ephemeris mode
+///
+/// # Returns
+/// Complete day information
+pub fn get_day_info_with_mode(
+    day: i32,
+    month: i32,
+    year: i32,
+    time_zone: f64,
+    mode: EphemerisMode,
+) -> DayInfo {
+    let lunar_date = convert_solar_to_lunar_with_mode(day, month, year, time_zone, mode);
+    build_day_info(day, month, year, time_zone, mode, lunar_date)
+}
+
+/// Get comprehensive information, rejecting years outside the mode's range
+///
+/// Same as `get_day_info_with_mode` within `mode.supported_years()`; use
+/// `EphemerisMode::for_year` to pick the mode for a year.
+///
+/// # Arguments
+/// * `day` - Day (1-31)
+/// * `month` - Month (1-12)
+/// * `year` - Year (Julian calendar before 1582-10-15)
+/// * `time_zone` - Timezone offset (default: VIETNAM_TIMEZONE for Vietnam UTC+7)
+/// * `mode` - Ephemeris mode
+///
+/// # Returns
+/// Complete day information, or the supported range the year falls outside of
+pub fn get_day_info_checked(
+    day: i32,
+    month: i32,
+    year: i32,
+    time_zone: f64,
+    mode: EphemerisMode,
+) -> Result<DayInfo, YearOutOfRange> {
+    mode.check_year(year)?;
+    Ok(get_day_info_with_mode(day, month, year, time_zone, mode))
+}
+
+/// Get comprehensive information for a given solar date under a time zone policy
+///
+/// With `TimezonePolicy::HistoricalVietnam` the lunar date follows the
+/// calendar officially in use on that date (UTC+8 before 1967-08-08 and
+/// published month starts, see `historical`).
+///
+/// # Arguments
+/// * `day` - Day (1-31)
+/// * `month` - Month (1-12)
+/// * `year` - Year
+/// * `policy` - Time zone policy
+/// * `mode` - Ephemeris mode
+///
+/// # Returns
+/// Complete day information
+pub fn get_day_info_with_policy(
+    day: i32,
+    month: i32,
+    year: i32,
+    policy: TimezonePolicy,
+    mode: EphemerisMode,
+) -> DayInfo {
+    let time_zone = policy.offset_for(day, month, year);
+    let lunar_date = convert_solar_to_lunar_with_policy(day, month, year, policy, mode);
+    build_day_info(day, month, year, time_zone, mode, lunar_date)
+}
+
+/// Get comprehensive information for a given solar date at a location
+///
+/// Same as `get_day_info_with_timezone`, with `sun_times` filled in for the
+/// observer's location.
+///
+/// # Arguments
+/// * `day` - Day (1-31)
+/// * `month` - Month (1-12)
+/// * `year` - Year
+/// * `time_zone` - Timezone offset (default: VIETNAM_TIMEZONE for Vietnam UTC+7)
+/// * `location` - Observer location
+///
+/// # Returns
+/// Complete day information including sun times
+pub fn get_day_info_with_location(
+    day: i32,
+    month: i32,
+    year: i32,
+    time_zone: f64,
+    location: &Location,
+) -> DayInfo {
+    let mut info = get_day_info_with_timezone(day, month, year, time_zone);
+    info.sun_times = Some(get_sun_times(day, month, year, time_zone, location));
+    info
+}
+
+/// Iterate over every solar date from `start` to `end` (inclusive)
+///
+/// Yields the same `DayInfo` as calling `get_day_info_with_timezone` for
+/// each date, but shares new moon and month-11 computations across the
+/// range, which makes month and year views much cheaper.
+///
+/// # Arguments
+/// * `start` - First solar date
+/// * `end` - Last solar date (inclusive)
+/// * `time_zone` - Timezone offset (default: VIETNAM_TIMEZONE for Vietnam UTC+7)
+///
+/// # Example
+/// ```
+/// use amlich_core::{iter_days, lunar::SolarDate, VIETNAM_TIMEZONE};
+///
+/// let start = SolarDate { day: 1, month: 2, year: 2024 };
+/// let end = SolarDate { day: 29, month: 2, year: 2024 };
+/// let tet = iter_days(start, end, VIETNAM_TIMEZONE)
+///     .find(|info| info.lunar.day == 1 && info.lunar.month == 1)
+///     .unwrap();
+/// assert_eq!(tet.solar.day, 10);
+/// ```
+pub fn iter_days(start: SolarDate, end: SolarDate, time_zone: f64) -> DayIter {
+    iter_days_with_mode(start, end, time_zone, EphemerisMode::Fast)
+}
+
+/// Iterate over a range of solar dates with a selectable ephemeris
+///
+/// See `iter_days`; each item equals `get_day_info_with_mode` for that date.
+pub fn iter_days_with_mode(
+    start: SolarDate,
+    end: SolarDate,
+    time_zone: f64,
+    mode: EphemerisMode,
+) -> DayIter {
+    iter_days_with_policy(start, end, TimezonePolicy::Fixed(time_zone), mode)
+}
+
+/// Iterate over a range of solar dates under a time zone policy
+///
+/// See `iter_days`; each item equals `get_day_info_with_policy` for that
+/// date. With `TimezonePolicy::Zone` each month starts on the local day of
+/// its new moon under the offset in effect at that new moon.
+pub fn iter_days_with_policy(
+    start: SolarDate,
+    end: SolarDate,
+    policy: TimezonePolicy,
+    mode: EphemerisMode,
+) -> DayIter {
+    DayIter {
+        next_jd: jd_from_date(start.day, start.month, start.year),
+        end_jd: jd_from_date(end.day, end.month, end.year),
+        policy,
+        mode,
+        cache: LunarCache::with_boundaries(PolicyMonths { policy, mode }),
+    }
+}
+
+/// Iterator returned by `iter_days`
+pub struct DayIter {
+    next_jd: i32,
+    end_jd: i32,
+    policy: TimezonePolicy,
+    mode: EphemerisMode,
+    cache: LunarCache<PolicyMonths>,
+}
+
+impl Iterator for DayIter {
+    type Item = DayInfo;
+
+    fn next(&mut self) -> Option<DayInfo> {
+        if self.next_jd > self.end_jd {
+            return None;
+        }
+        let (day, month, year) = jd_to_date(self.next_jd);
+        self.next_jd += 1;
+
+        let time_zone = self.policy.offset_for(day, month, year);
+        let lunar_date = match self.policy {
+            TimezonePolicy::HistoricalVietnam => {
+                convert_solar_to_lunar_with_policy(day, month, year, self.policy, self.mode)
+            }
+            TimezonePolicy::Fixed(_) | TimezonePolicy::Zone(_) => {
+                self.cache.solar_to_lunar(day, month, year)
+            }
+        };
+        Some(build_day_info(
+            day, month, year, time_zone, self.mode, lunar_date,
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.end_jd - self.next_jd + 1).max(0) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for DayIter {}
+
+fn build_day_info(
+    day: i32,
+    month: i32,
+    year: i32,
+    time_zone: f64,
+    mode: EphemerisMode,
+    lunar_date: LunarDate,
+) -> DayInfo {
+    // Calculate Julian Day Number
+    let jd = jd_from_date(day, month, year);
+
+    // Calculate day of week (JD + 1 because JD 0 was Monday)
+    let day_of_week = ((jd + 1) % 7) as usize;
+
+    // Calculate Can Chi for day, month, year
+    let day_canchi = get_day_canchi(jd);
+    let month_canchi = get_month_canchi(lunar_date.month, lunar_date.year, lunar_date.is_leap);
+    let year_canchi = get_year_canchi(lunar_date.year);
+
+    // Calculate Solar Term (Tiết Khí)
+    let tiet_khi = get_tiet_khi_with_mode(jd, time_zone, mode);
+
+    // Calculate Auspicious Hours (Giờ Hoàng Đạo)
+    let gio_hoang_dao = get_gio_hoang_dao(day_canchi.can, day_canchi.chi);
+    let day_fortune = calculate_day_fortune(
+        jd,
+        &day_canchi,
+        lunar_date.day,
+        lunar_date.month,
+        year_canchi.can,
+        tiet_khi.id,
+    );
+
+    // Build solar info
+    let solar = SolarInfo {
+        day,
+        month,
+        year,
+        day_of_week,
+        day_of_week_name: THU[day_of_week].to_string(),
+        date_string: format!("{}-{:02}-{:02}", year, month, day),
+    };
+
+    // Build lunar info
+    let lunar = LunarInfo {
+        day: lunar_date.day,
+        month: lunar_date.month,
+        year: lunar_date.year,
+        is_leap_month: lunar_date.is_leap,
+        date_string: lunar_date.to_string(),
+    };
+
+    // Build Can Chi info
+    let canchi = CanChiInfo {
+        day: day_canchi.clone(),
+        month: month_canchi.clone(),
+        year: year_canchi.clone(),
+        full: format!(
+            "{}, tháng {}, năm {}",
+            day_canchi.full, month_canchi.full, year_canchi.full
+        ),
+    };
+
+    DayInfo {
+        ruleset_id: day_fortune.ruleset_id.clone(),
+        ruleset_version: day_fortune.ruleset_version.clone(),
+        solar,
+        lunar,
+        jd,
+        canchi,
+        tiet_khi,
+        gio_hoang_dao,
+        day_fortune,
+        sun_times: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::location;
+
+    #[test]
+    fn test_get_day_info_tet_2024() {
+        // Tết 2024: February 10, 2024
+        let info = get_day_info(10, 2, 2024);
+
+        // Check solar date
+        assert_eq!(info.solar.day, 10);
+        assert_eq!(info.solar.month, 2);
+        assert_eq!(info.solar.year, 2024);
+
+        // Check lunar date (should be 1/1/2024)
+        assert_eq!(info.lunar.day, 1);
+        assert_eq!(info.lunar.month, 1);
+        assert_eq!(info.lunar.year, 2024);
+        assert!(!info.lunar.is_leap_month);
+
+        // Check Can Chi
+        assert_eq!(info.canchi.day.full, "Giáp Thìn");
+        assert_eq!(info.canchi.year.full, "Giáp Thìn");
+        assert_eq!(info.ruleset_id, "vn_baseline_v1");
+        assert_eq!(info.ruleset_version, "v1");
+    }
+
+    #[test]
+    fn test_get_day_info_tet_2025() {
+        // Tết 2025: January 29, 2025
+        let info = get_day_info(29, 1, 2025);
+
+        // Check lunar date (should be 1/1/2025)
+        assert_eq!(info.lunar.day, 1);
+        assert_eq!(info.lunar.month, 1);
+        assert_eq!(info.lunar.year, 2025);
+
+        // Check Can Chi
+        assert_eq!(info.canchi.day.full, "Mậu Tuất");
+        assert_eq!(info.canchi.year.full, "Ất Tỵ");
+    }
+
+    #[test]
+    fn test_day_of_week() {
+        // Test a known day: January 1, 2000 was a Saturday (index 6)
+        let info = get_day_info(1, 1, 2000);
+        assert_eq!(info.solar.day_of_week, 6);
+        assert_eq!(info.solar.day_of_week_name, "Thứ Bảy");
+    }
+
+    #[test]
+    fn test_gio_hoang_dao_present() {
+        let info = get_day_info(10, 2, 2024);
+
+        // Should have 6 good hours
+        assert_eq!(info.gio_hoang_dao.good_hour_count, 6);
+        assert_eq!(info.gio_hoang_dao.good_hours.len(), 6);
+    }
+
+    #[test]
+    fn test_custom_timezone() {
+        // Test with different timezone (should work but give potentially different results)
+        let info = get_day_info_with_timezone(10, 2, 2024, 8.0);
+
+        // Should still work
+        assert_eq!(info.solar.day, 10);
+        assert_eq!(info.solar.month, 2);
+        assert_eq!(info.solar.year, 2024);
+    }
+
+    #[test]
+    fn test_accurate_mode_day_info() {
+        let fast = get_day_info_with_timezone(10, 2, 2024, VIETNAM_TIMEZONE);
+        let accurate =
+            get_day_info_with_mode(10, 2, 2024, VIETNAM_TIMEZONE, EphemerisMode::Accurate);
+
+        assert_eq!(accurate.lunar.date_string, fast.lunar.date_string);
+        assert_eq!(accurate.canchi.full, fast.canchi.full);
+        assert_eq!(accurate.tiet_khi.index, fast.tiet_khi.index);
+    }
+
+    #[test]
+    fn test_iter_days_matches_per_day_calls() {
+        let start = SolarDate {
+            day: 1,
+            month: 12,
+            year: 2024,
+        };
+        let end = SolarDate {
+            day: 28,
+            month: 2,
+            year: 2026,
+        };
+        let days: Vec<DayInfo> = iter_days(start, end, VIETNAM_TIMEZONE).collect();
+        assert_eq!(days.len(), 455);
+        for info in &days {
+            let single = get_day_info_with_timezone(
+                info.solar.day,
+                info.solar.month,
+                info.solar.year,
+                VIETNAM_TIMEZONE,
+            );
+            assert_eq!(format!("{info:?}"), format!("{single:?}"));
+        }
+    }
+
+    #[test]
+    fn test_iter_days_with_mode_and_empty_range() {
+        let start = SolarDate {
+            day: 20,
+            month: 1,
+            year: 2025,
+        };
+        let end = SolarDate {
+            day: 10,
+            month: 2,
+            year: 2025,
+        };
+        for info in iter_days_with_mode(start, end, 8.0, EphemerisMode::Accurate) {
+            let single = get_day_info_with_mode(
+                info.solar.day,
+                info.solar.month,
+                info.solar.year,
+                8.0,
+                EphemerisMode::Accurate,
+            );
+            assert_eq!(format!("{info:?}"), format!("{single:?}"));
+        }
+        assert_eq!(iter_days(end, start, VIETNAM_TIMEZONE).count(), 0);
+    }
+
+    #[test]
+    fn test_day_info_with_historical_policy() {
+        let info = get_day_info_with_policy(
+            3,
+            11,
+            1956,
+            TimezonePolicy::HistoricalVietnam,
+            EphemerisMode::Fast,
+        );
+        assert_eq!(info.lunar.date_string, "1/10/1956");
+
+        let fixed = get_day_info_with_policy(
+            10,
+            2,
+            2024,
+            TimezonePolicy::Fixed(VIETNAM_TIMEZONE),
+            EphemerisMode::Fast,
+        );
+        assert_eq!(
+            format!("{fixed:?}"),
+            format!("{:?}", get_day_info(10, 2, 2024))
+        );
+    }
+
+    #[test]
+    fn test_day_info_checked_range() {
+        let err = get_day_info_checked(1, 1, 1450, VIETNAM_TIMEZONE, EphemerisMode::Fast)
+            .expect_err("fast mode starts in 1800");
+        assert_eq!(err.min(), 1800);
+
+        // Tết of Canh Ngọ is 1450-01-14 (Julian), 1450-01-23 proleptic Gregorian
+        let mode = EphemerisMode::for_year(1450).unwrap();
+        let info = get_day_info_checked(14, 1, 1450, VIETNAM_TIMEZONE, mode).unwrap();
+        assert_eq!(info.lunar.date_string, "1/1/1450");
+        assert_eq!(info.canchi.year.full, "Canh Ngọ");
+    }
+
+    #[test]
+    fn test_day_info_with_location() {
+        assert!(get_day_info(21, 6, 2024).sun_times.is_none());
+
+        let info = get_day_info_with_location(21, 6, 2024, VIETNAM_TIMEZONE, &location::HANOI);
+        let sun = info.sun_times.expect("sun times");
+        assert_eq!(sun.sunrise.unwrap().hour, 5);
+        assert_eq!(sun.sunset.unwrap().hour, 18);
+        assert_eq!(info.lunar.date_string, "16/5/2024");
+    }
+
+    #[test]
+    #[cfg(feature = "tz")]
+    fn test_iter_days_with_policy_across_daylight_saving() {
+        // Los Angeles starts daylight saving time on 2024-03-10
+        let los_angeles = TimezonePolicy::Zone("America/Los_Angeles".parse().unwrap());
+        let start = SolarDate {
+            day: 1,
+            month: 3,
+            year: 2024,
+        };
+        let end = SolarDate {
+            day: 15,
+            month: 4,
+            year: 2024,
+        };
+        let mut count = 0;
+        for info in iter_days_with_policy(start, end, los_angeles, EphemerisMode::Fast) {
+            let single = get_day_info_with_policy(
+                info.solar.day,
+                info.solar.month,
+                info.solar.year,
+                los_angeles,
+                EphemerisMode::Fast,
+            );
+            assert_eq!(format!("{info:?}"), format!("{single:?}"));
+            count += 1;
+        }
+        assert_eq!(count, 46);
+
+        // The new moon of 2024-04-08 18:21 UTC is still 8 April in
+        // California but already 9 April in Vietnam
+        let info = get_day_info_with_policy(8, 4, 2024, los_angeles, EphemerisMode::Fast);
+        assert_eq!(info.lunar.date_string, "1/3/2024");
+        assert_eq!(get_day_info(9, 4, 2024).lunar.date_string, "1/3/2024");
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn test_day_info_for_naive_date() {
+        let date = NaiveDate::from_ymd_opt(2025, 1, 29).unwrap();
+        assert_eq!(
+            format!("{:?}", get_day_info_for(date)),
+            format!("{:?}", get_day_info(29, 1, 2025))
+        );
+    }
+}
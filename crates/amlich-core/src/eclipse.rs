@@ -0,0 +1,346 @@
+/**
+ * Solar and Lunar Eclipses
+ *
+ * Eclipse prediction from "Astronomical Algorithms" by Jean Meeus, 1998
+ * (chapter 54). A new or full moon gives an eclipse when the moon is close
+ * to a node of its orbit, i.e. when the argument of latitude F is near 0° or
+ * 180°. The instant of maximum eclipse is good to a few minutes; the type
+ * and magnitude are geocentric, so a listed solar eclipse is not
+ * necessarily visible from Vietnam.
+ */
+use crate::ephemeris::delta_t_days;
+use crate::julian::{jd_from_date, jd_to_datetime, JulianDateTime};
+use crate::lunar::{convert_solar_to_lunar, LunarDate};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::moon_phase::{PHASE_EPOCH, SYNODIC_MONTH};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+/// Whether the sun or the moon is eclipsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EclipseKind {
+    /// Nhật thực, at new moon
+    Solar,
+    /// Nguyệt thực, at full moon
+    Lunar,
+}
+
+/// Type of an eclipse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EclipseType {
+    Total,
+    /// Solar only: the moon is too far to cover the whole sun
+    Annular,
+    /// Solar only: annular along part of the track, total elsewhere
+    Hybrid,
+    Partial,
+    /// Lunar only: the moon enters the earth's penumbra but not the umbra
+    Penumbral,
+}
+
+impl EclipseType {
+    /// Stable identifier
+    pub fn id(self) -> &'static str {
+        match self {
+            EclipseType::Total => "total",
+            EclipseType::Annular => "annular",
+            EclipseType::Hybrid => "hybrid",
+            EclipseType::Partial => "partial",
+            EclipseType::Penumbral => "penumbral",
+        }
+    }
+}
+
+/// One eclipse
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Eclipse {
+    pub kind: EclipseKind,
+    pub eclipse_type: EclipseType,
+    /// Vietnamese name, e.g. "Nhật thực toàn phần"
+    pub name: String,
+    /// English description
+    pub description: String,
+    /// Julian day (fractional, UT) of maximum eclipse
+    pub jd: f64,
+    pub utc: JulianDateTime,
+    pub local: JulianDateTime,
+    /// Lunar date of the local day of maximum eclipse
+    pub lunar_date: LunarDate,
+    /// Magnitude: fraction of the sun's or moon's diameter covered (umbral
+    /// magnitude for lunar eclipses, penumbral for penumbral ones)
+    pub magnitude: f64,
+    /// Least distance from the axis of the moon's shadow (solar) or the
+    /// earth's shadow (lunar) to the centre of the earth or moon, in earth
+    /// radii
+    pub gamma: f64,
+}
+
+/// Geocentric parameters of a possible eclipse at phase index `k`
+struct EclipseCandidate {
+    jde: f64,
+    gamma: f64,
+    u: f64,
+}
+
+/// Evaluate the eclipse at new moon (integer `k`) or full moon (`k` + 0.5),
+/// or `None` if the moon is too far from a node
+fn eclipse_candidate(k: f64) -> Option<EclipseCandidate> {
+    let dr = PI / 180.0;
+    let t = k / 1236.85;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+
+    let f =
+        (160.7108 + 390.67050284 * k - 0.0016118 * t2 - 0.00000227 * t3 + 0.000000011 * t4) * dr;
+    if f.sin().abs() > 0.36 {
+        return None;
+    }
+
+    let mut jde =
+        PHASE_EPOCH + SYNODIC_MONTH * k + 0.00015437 * t2 - 0.000000150 * t3 + 0.00000000073 * t4;
+    let m = (2.5534 + 29.10535670 * k - 0.0000014 * t2 - 0.00000011 * t3) * dr;
+    let mpr =
+        (201.5643 + 385.81693528 * k + 0.0107582 * t2 + 0.00001238 * t3 - 0.000000058 * t4) * dr;
+    let omega = (124.7746 - 1.56375588 * k + 0.0020672 * t2 + 0.00000215 * t3) * dr;
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t2;
+
+    let f1 = f - 0.02665 * dr * omega.sin();
+    let a1 = (299.77 + 0.107408 * k - 0.009173 * t2) * dr;
+
+    let is_solar = k.fract() == 0.0;
+    jde += if is_solar {
+        -0.4075 * mpr.sin() + 0.1721 * e * m.sin()
+    } else {
+        -0.4065 * mpr.sin() + 0.1727 * e * m.sin()
+    };
+    jde += 0.0161 * (2.0 * mpr).sin() - 0.0097 * (2.0 * f1).sin() + 0.0073 * e * (mpr - m).sin()
+        - 0.0050 * e * (mpr + m).sin()
+        - 0.0023 * (mpr - 2.0 * f1).sin()
+        + 0.0021 * e * (2.0 * m).sin()
+        + 0.0012 * (mpr + 2.0 * f1).sin()
+        + 0.0006 * e * (2.0 * mpr + m).sin()
+        - 0.0004 * (3.0 * mpr).sin()
+        - 0.0003 * e * (m + 2.0 * f1).sin()
+        + 0.0003 * a1.sin()
+        - 0.0002 * e * (m - 2.0 * f1).sin()
+        - 0.0002 * e * (2.0 * mpr - m).sin()
+        - 0.0002 * omega.sin();
+
+    let p = 0.2070 * e * m.sin() + 0.0024 * e * (2.0 * m).sin() - 0.0392 * mpr.sin()
+        + 0.0116 * (2.0 * mpr).sin()
+        - 0.0073 * e * (mpr + m).sin()
+        + 0.0067 * e * (mpr - m).sin()
+        + 0.0118 * (2.0 * f1).sin();
+    let q = 5.2207 - 0.0048 * e * m.cos() + 0.0020 * e * (2.0 * m).cos()
+        - 0.3299 * mpr.cos()
+        - 0.0060 * e * (mpr + m).cos()
+        + 0.0041 * e * (mpr - m).cos();
+    let w = f1.cos().abs();
+    let gamma = (p * f1.cos() + q * f1.sin()) * (1.0 - 0.0048 * w);
+    let u = 0.0059 + 0.0046 * e * m.cos() - 0.0182 * mpr.cos() + 0.0004 * (2.0 * mpr).cos()
+        - 0.0005 * (m + mpr).cos();
+
+    Some(EclipseCandidate { jde, gamma, u })
+}
+
+/// Classify a solar eclipse, returning its type and magnitude
+fn classify_solar(gamma: f64, u: f64) -> Option<(EclipseType, f64)> {
+    let g = gamma.abs();
+    if g > 1.5433 + u {
+        return None;
+    }
+    if g > 0.9972 + u.abs() {
+        let magnitude = (1.5433 + u - g) / (0.5461 + 2.0 * u);
+        return Some((EclipseType::Partial, magnitude));
+    }
+
+    // Central eclipse, or non-central total/annular grazing the poles
+    let eclipse_type = if u < 0.0 {
+        EclipseType::Total
+    } else if u > 0.0047 {
+        EclipseType::Annular
+    } else if g < 0.9972 && u < 0.00464 * (1.0 - g * g).sqrt() {
+        EclipseType::Hybrid
+    } else {
+        EclipseType::Annular
+    };
+    Some((eclipse_type, 1.0))
+}
+
+/// Classify a lunar eclipse, returning its type and magnitude
+fn classify_lunar(gamma: f64, u: f64) -> Option<(EclipseType, f64)> {
+    let g = gamma.abs();
+    let penumbral = (1.5573 + u - g) / 0.5450;
+    let umbral = (1.0128 - u - g) / 0.5450;
+    if umbral >= 1.0 {
+        Some((EclipseType::Total, umbral))
+    } else if umbral > 0.0 {
+        Some((EclipseType::Partial, umbral))
+    } else if penumbral > 0.0 {
+        Some((EclipseType::Penumbral, penumbral))
+    } else {
+        None
+    }
+}
+
+fn eclipse_names(kind: EclipseKind, eclipse_type: EclipseType) -> (&'static str, &'static str) {
+    match (kind, eclipse_type) {
+        (EclipseKind::Solar, EclipseType::Total) => ("Nhật thực toàn phần", "Total solar eclipse"),
+        (EclipseKind::Solar, EclipseType::Annular) => {
+            ("Nhật thực hình khuyên", "Annular solar eclipse")
+        }
+        (EclipseKind::Solar, EclipseType::Hybrid) => ("Nhật thực lai", "Hybrid solar eclipse"),
+        (EclipseKind::Solar, _) => ("Nhật thực một phần", "Partial solar eclipse"),
+        (EclipseKind::Lunar, EclipseType::Total) => {
+            ("Nguyệt thực toàn phần", "Total lunar eclipse")
+        }
+        (EclipseKind::Lunar, EclipseType::Penumbral) => {
+            ("Nguyệt thực nửa tối", "Penumbral lunar eclipse")
+        }
+        (EclipseKind::Lunar, _) => ("Nguyệt thực một phần", "Partial lunar eclipse"),
+    }
+}
+
+/// Find the eclipse at a lunation, if any
+///
+/// # Arguments
+/// * `lunation` - Lunation index (0 = new moon of 2000 January 6)
+/// * `kind` - Solar (at the new moon) or lunar (at the following full moon)
+/// * `time_zone` - Timezone offset for `local` and the lunar date
+///
+/// # Returns
+/// The eclipse, or `None` if there is none at that syzygy
+pub fn get_eclipse(lunation: i32, kind: EclipseKind, time_zone: f64) -> Option<Eclipse> {
+    let k = match kind {
+        EclipseKind::Solar => lunation as f64,
+        EclipseKind::Lunar => lunation as f64 + 0.5,
+    };
+    let candidate = eclipse_candidate(k)?;
+    let (eclipse_type, magnitude) = match kind {
+        EclipseKind::Solar => classify_solar(candidate.gamma, candidate.u)?,
+        EclipseKind::Lunar => classify_lunar(candidate.gamma, candidate.u)?,
+    };
+
+    let jd = candidate.jde - delta_t_days(candidate.jde);
+    let local = jd_to_datetime(jd, time_zone);
+    let (name, description) = eclipse_names(kind, eclipse_type);
+
+    Some(Eclipse {
+        kind,
+        eclipse_type,
+        name: name.to_string(),
+        description: description.to_string(),
+        jd,
+        utc: jd_to_datetime(jd, 0.0),
+        local,
+        lunar_date: convert_solar_to_lunar(local.day, local.month, local.year, time_zone),
+        magnitude,
+        gamma: candidate.gamma,
+    })
+}
+
+/// Get every solar and lunar eclipse whose local date falls in a solar year
+///
+/// # Arguments
+/// * `year` - Solar year
+/// * `time_zone` - Timezone offset (7.0 for UTC+7:00)
+///
+/// # Returns
+/// Eclipses in chronological order
+pub fn get_eclipses_for_year(year: i32, time_zone: f64) -> Vec<Eclipse> {
+    let start = jd_from_date(1, 1, year) as f64 - 0.5 - time_zone / 24.0;
+    let end = jd_from_date(1, 1, year + 1) as f64 - 0.5 - time_zone / 24.0;
+    let first = ((start - PHASE_EPOCH) / SYNODIC_MONTH).floor() as i32 - 1;
+    let last = ((end - PHASE_EPOCH) / SYNODIC_MONTH).ceil() as i32 + 1;
+
+    (first..=last)
+        .flat_map(|lunation| {
+            [EclipseKind::Solar, EclipseKind::Lunar]
+                .into_iter()
+                .filter_map(move |kind| get_eclipse(lunation, kind, time_zone))
+        })
+        .filter(|eclipse| eclipse.jd >= start && eclipse.jd < end)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meeus_partial_solar_eclipse_1993() {
+        // Meeus example 54.a: 1993 May 21, JDE 2449129.0979, γ = 1.1348,
+        // u = 0.0097, magnitude 0.740
+        let candidate = eclipse_candidate(-82.0).unwrap();
+        assert!((candidate.jde - 2449129.0979).abs() < 0.0005);
+        assert!((candidate.gamma - 1.1348).abs() < 0.0005);
+        assert!((candidate.u - 0.0097).abs() < 0.0005);
+
+        let eclipse = get_eclipse(-82, EclipseKind::Solar, 7.0).unwrap();
+        assert_eq!(eclipse.eclipse_type, EclipseType::Partial);
+        assert!((eclipse.magnitude - 0.740).abs() < 0.002);
+    }
+
+    #[test]
+    fn test_eclipses_2024() {
+        let eclipses = get_eclipses_for_year(2024, 7.0);
+        let summary: Vec<(EclipseKind, EclipseType, i32, i32)> = eclipses
+            .iter()
+            .map(|e| (e.kind, e.eclipse_type, e.utc.day, e.utc.month))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (EclipseKind::Lunar, EclipseType::Penumbral, 25, 3),
+                (EclipseKind::Solar, EclipseType::Total, 8, 4),
+                (EclipseKind::Lunar, EclipseType::Partial, 18, 9),
+                (EclipseKind::Solar, EclipseType::Annular, 2, 10),
+            ]
+        );
+
+        // Total solar eclipse of April 8, maximum 18:17 UTC
+        let total = &eclipses[1];
+        let minutes = total.utc.hour * 60 + total.utc.minute;
+        assert!((minutes - (18 * 60 + 17)).abs() <= 5, "{:?}", total.utc);
+        assert_eq!(total.name, "Nhật thực toàn phần");
+        // 01:17 on April 9 in Vietnam, mùng 1 tháng 3
+        assert_eq!((total.local.day, total.local.month), (9, 4));
+        assert_eq!((total.lunar_date.day, total.lunar_date.month), (1, 3));
+    }
+
+    #[test]
+    fn test_lunar_eclipses_fall_on_ram() {
+        for year in 2023..=2026 {
+            for eclipse in get_eclipses_for_year(year, 7.0) {
+                let day = eclipse.lunar_date.day;
+                match eclipse.kind {
+                    EclipseKind::Lunar => assert!((14..=17).contains(&day), "{eclipse:?}"),
+                    EclipseKind::Solar => assert!(day <= 2 || day >= 29, "{eclipse:?}"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_hybrid_and_total_lunar() {
+        // 2023-04-20 hybrid solar eclipse
+        let eclipses = get_eclipses_for_year(2023, 7.0);
+        assert_eq!(eclipses[0].eclipse_type, EclipseType::Hybrid);
+        assert_eq!((eclipses[0].utc.day, eclipses[0].utc.month), (20, 4));
+
+        // 2025-09-07 total lunar eclipse, maximum 18:12 UTC
+        let total = get_eclipses_for_year(2025, 7.0)
+            .into_iter()
+            .find(|e| e.kind == EclipseKind::Lunar && e.utc.month == 9)
+            .unwrap();
+        assert_eq!(total.eclipse_type, EclipseType::Total);
+        assert!(total.magnitude > 1.3);
+        assert_eq!(total.name, "Nguyệt thực toàn phần");
+    }
+}
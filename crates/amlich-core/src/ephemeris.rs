@@ -0,0 +1,713 @@
+/**
+ * Ephemeris Modes
+ *
+ * The calendar can be evaluated with two ephemerides:
+ * - Fast: the truncated Meeus series in `sun` and `lunar` with a 1900-era ΔT
+ *   polynomial. This is the historical behaviour and stays the default.
+ * - Accurate: VSOP87 (Meeus appendix III truncation) for the sun with
+ *   nutation and aberration, the complete lunar phase series of Meeus
+ *   chapter 49 for new moons (the one `moon_phase` uses, not a separate lunar
+ *   theory), and the Espenak-Meeus ΔT model anchored to observed values
+ *   since 2005.
+ *
+ * Each mode has an explicit supported range (`EphemerisMode::supported_years`):
+ * Fast reproduces the published Hồ Ngọc Đức tables for 1800-2199 but its
+ * series and ΔT drift by up to an hour further out, while Accurate covers
+ * 1000-3000. Dates before 1582-10-15 are Julian calendar dates, as in
+ * `julian::jd_from_date`.
+ *
+ * References:
+ * - "Astronomical Algorithms" by Jean Meeus, 1998 (chapters 22, 25, 32, 49)
+ * - Espenak & Meeus, "Five Millennium Canon of Solar Eclipses", 2006
+ */
+use crate::julian::{jd_from_date, jd_to_date};
+use crate::lunar::{
+    convert_solar_to_lunar_with_mode, new_moon, LunarDate, MAX_SUPPORTED_YEAR, MIN_SUPPORTED_YEAR,
+};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::moon_phase::{moon_phase_instant, MoonPhase};
+use crate::sun::sun_longitude;
+use crate::tietkhi::get_tiet_khi_with_mode;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::f64::consts::PI;
+use core::fmt;
+use core::ops::RangeInclusive;
+
+/// Which ephemeris the astronomical calculations use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EphemerisMode {
+    /// Truncated Meeus series (historical behaviour)
+    #[default]
+    Fast,
+    /// VSOP87 sun, Meeus chapter 49 new moons and modern ΔT
+    Accurate,
+}
+
+impl EphemerisMode {
+    /// Stable identifier used in reports and serialized output
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EphemerisMode::Fast => "fast",
+            EphemerisMode::Accurate => "accurate",
+        }
+    }
+
+    /// Solar and lunar years the mode gives correct calendars for
+    pub fn supported_years(self) -> RangeInclusive<i32> {
+        match self {
+            EphemerisMode::Fast => MIN_SUPPORTED_YEAR..=MAX_SUPPORTED_YEAR,
+            EphemerisMode::Accurate => 1000..=3000,
+        }
+    }
+
+    /// Check that a year is within `supported_years`
+    pub fn check_year(self, year: i32) -> Result<(), YearOutOfRange> {
+        if self.supported_years().contains(&year) {
+            Ok(())
+        } else {
+            Err(YearOutOfRange { year, mode: self })
+        }
+    }
+
+    /// Fast where it is supported, Accurate further out
+    ///
+    /// # Returns
+    /// The mode to use for a year, or an error outside every mode's range
+    pub fn for_year(year: i32) -> Result<EphemerisMode, YearOutOfRange> {
+        EphemerisMode::Fast
+            .check_year(year)
+            .map(|()| EphemerisMode::Fast)
+            .or_else(|_| {
+                EphemerisMode::Accurate
+                    .check_year(year)
+                    .map(|()| EphemerisMode::Accurate)
+            })
+    }
+}
+
+/// A year outside the supported range of an ephemeris mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct YearOutOfRange {
+    pub year: i32,
+    pub mode: EphemerisMode,
+}
+
+impl YearOutOfRange {
+    /// First supported year of the mode
+    pub fn min(&self) -> i32 {
+        *self.mode.supported_years().start()
+    }
+
+    /// Last supported year of the mode
+    pub fn max(&self) -> i32 {
+        *self.mode.supported_years().end()
+    }
+}
+
+impl fmt::Display for YearOutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "year {} is outside the supported range {}-{} ({} ephemeris)",
+            self.year,
+            self.min(),
+            self.max(),
+            self.mode.as_str()
+        )
+    }
+}
+
+impl Error for YearOutOfRange {}
+
+/// Observed ΔT on January 1 of 2005..=2025, in seconds (IERS)
+const OBSERVED_DELTA_T: [f64; 21] = [
+    64.69, 64.85, 65.15, 65.46, 65.78, 66.07, 66.32, 66.60, 66.91, 67.28, 67.64, 68.10, 68.59,
+    68.97, 69.22, 69.36, 69.36, 69.29, 69.20, 69.18, 69.14,
+];
+
+/// ΔT (terrestrial time minus universal time) in seconds
+///
+/// Polynomial expressions from Espenak & Meeus (2006), valid from -1999 to
+/// 3000 and extrapolated parabolically outside that range. From 2005 to 2025
+/// the observed values are interpolated instead; the Earth's rotation has
+/// sped up since and the 2005-2050 polynomial overestimates ΔT by about 5 s,
+/// so the difference is phased out by 2050.
+///
+/// # Arguments
+/// * `year` - Decimal year (e.g. 2024.5 for mid-2024)
+pub fn delta_t_seconds(year: f64) -> f64 {
+    let y = year;
+
+    if !(-500.0..2150.0).contains(&y) {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    } else if y < 500.0 {
+        let u = y / 100.0;
+        10583.6 - 1014.41 * u + 33.78311 * u.powi(2) - 5.952053 * u.powi(3) - 0.1798452 * u.powi(4)
+            + 0.022174192 * u.powi(5)
+            + 0.0090316521 * u.powi(6)
+    } else if y < 1600.0 {
+        let u = (y - 1000.0) / 100.0;
+        1574.2 - 556.01 * u + 71.23472 * u.powi(2) + 0.319781 * u.powi(3)
+            - 0.8503463 * u.powi(4)
+            - 0.005050998 * u.powi(5)
+            + 0.0083572073 * u.powi(6)
+    } else if y < 1700.0 {
+        let t = y - 1600.0;
+        120.0 - 0.9808 * t - 0.01532 * t.powi(2) + t.powi(3) / 7129.0
+    } else if y < 1800.0 {
+        let t = y - 1700.0;
+        8.83 + 0.1603 * t - 0.0059285 * t.powi(2) + 0.00013336 * t.powi(3) - t.powi(4) / 1174000.0
+    } else if y < 1860.0 {
+        let t = y - 1800.0;
+        13.72 - 0.332447 * t + 0.0068612 * t.powi(2) + 0.0041116 * t.powi(3)
+            - 0.00037436 * t.powi(4)
+            + 0.0000121272 * t.powi(5)
+            - 0.0000001699 * t.powi(6)
+            + 0.000000000875 * t.powi(7)
+    } else if y < 1900.0 {
+        let t = y - 1860.0;
+        7.62 + 0.5737 * t - 0.251754 * t.powi(2) + 0.01680668 * t.powi(3) - 0.0004473624 * t.powi(4)
+            + t.powi(5) / 233174.0
+    } else if y < 1920.0 {
+        let t = y - 1900.0;
+        -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3) - 0.000197 * t.powi(4)
+    } else if y < 1941.0 {
+        let t = y - 1920.0;
+        21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+    } else if y < 1961.0 {
+        let t = y - 1950.0;
+        29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+    } else if y < 1986.0 {
+        let t = y - 1975.0;
+        45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+    } else if y < 2005.0 {
+        let t = y - 2000.0;
+        63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+            + 0.0017275 * t.powi(3)
+            + 0.000651814 * t.powi(4)
+            + 0.00002373599 * t.powi(5)
+    } else if y < 2025.0 {
+        let index = ((y - 2005.0).floor() as usize).min(OBSERVED_DELTA_T.len() - 2);
+        let fraction = y - 2005.0 - index as f64;
+        OBSERVED_DELTA_T[index] + fraction * (OBSERVED_DELTA_T[index + 1] - OBSERVED_DELTA_T[index])
+    } else if y < 2050.0 {
+        let polynomial = |y: f64| {
+            let t = y - 2000.0;
+            62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+        };
+        let excess = polynomial(2025.0) - OBSERVED_DELTA_T[OBSERVED_DELTA_T.len() - 1];
+        polynomial(y) - excess * (2050.0 - y) / 25.0
+    } else {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u - 0.5628 * (2150.0 - y)
+    }
+}
+
+/// ΔT in days at a Julian day
+pub(crate) fn delta_t_days(jd: f64) -> f64 {
+    let year = 2000.0 + (jd - 2451545.0) / 365.25;
+    delta_t_seconds(year) / 86400.0
+}
+
+// VSOP87 Earth series truncated as in Meeus appendix III: (A, B, C) terms of
+// A * cos(B + C * tau), with tau in Julian millennia from J2000.0 and A in
+// units of 1e-8 radian (longitude) or 1e-8 AU (radius vector). Phases such as
+// 3.142 are the published coefficients, not approximations of π.
+
+const EARTH_L0: [(f64, f64, f64); 64] = [
+    (175347046.0, 0.0, 0.0),
+    (3341656.0, 4.6692568, 6283.0758500),
+    (34894.0, 4.62610, 12566.15170),
+    (3497.0, 2.7441, 5753.3849),
+    (3418.0, 2.8289, 3.5231),
+    (3136.0, 3.6277, 77713.7715),
+    (2676.0, 4.4181, 7860.4194),
+    (2343.0, 6.1352, 3930.2097),
+    (1324.0, 0.7425, 11506.7698),
+    (1273.0, 2.0371, 529.6910),
+    (1199.0, 1.1096, 1577.3435),
+    (990.0, 5.233, 5884.927),
+    (902.0, 2.045, 26.298),
+    (857.0, 3.508, 398.149),
+    (780.0, 1.179, 5223.694),
+    (753.0, 2.533, 5507.553),
+    (505.0, 4.583, 18849.228),
+    (492.0, 4.205, 775.523),
+    (357.0, 2.920, 0.067),
+    (317.0, 5.849, 11790.629),
+    (284.0, 1.899, 796.298),
+    (271.0, 0.315, 10977.079),
+    (243.0, 0.345, 5486.778),
+    (206.0, 4.806, 2544.314),
+    (205.0, 1.869, 5573.143),
+    (202.0, 2.458, 6069.777),
+    (156.0, 0.833, 213.299),
+    (132.0, 3.411, 2942.463),
+    (126.0, 1.083, 20.775),
+    (115.0, 0.645, 0.980),
+    (103.0, 0.636, 4694.003),
+    (102.0, 0.976, 15720.839),
+    (102.0, 4.267, 7.114),
+    (99.0, 6.21, 2146.17),
+    (98.0, 0.68, 155.42),
+    (86.0, 5.98, 161000.69),
+    (85.0, 1.30, 6275.96),
+    (85.0, 3.67, 71430.70),
+    (80.0, 1.81, 17260.15),
+    (79.0, 3.04, 12036.46),
+    (75.0, 1.76, 5088.63),
+    (74.0, 3.50, 3154.69),
+    (74.0, 4.68, 801.82),
+    (70.0, 0.83, 9437.76),
+    (62.0, 3.98, 8827.39),
+    (61.0, 1.82, 7084.90),
+    (57.0, 2.78, 6286.60),
+    (56.0, 4.39, 14143.50),
+    (56.0, 3.47, 6279.55),
+    (52.0, 0.19, 12139.55),
+    (52.0, 1.33, 1748.02),
+    (51.0, 0.28, 5856.48),
+    (49.0, 0.49, 1194.45),
+    (41.0, 5.37, 8429.24),
+    (41.0, 2.40, 19651.05),
+    (39.0, 6.17, 10447.39),
+    (37.0, 6.04, 10213.29),
+    (37.0, 2.57, 1059.38),
+    (36.0, 1.71, 2352.87),
+    (36.0, 1.78, 6812.77),
+    (33.0, 0.59, 17789.85),
+    (30.0, 0.44, 83996.85),
+    (30.0, 2.74, 1349.87),
+    (25.0, 3.16, 4690.48),
+];
+
+const EARTH_L1: [(f64, f64, f64); 34] = [
+    (628331966747.0, 0.0, 0.0),
+    (206059.0, 2.678235, 6283.075850),
+    (4303.0, 2.6351, 12566.1517),
+    (425.0, 1.590, 3.523),
+    (119.0, 5.796, 26.298),
+    (109.0, 2.966, 1577.344),
+    (93.0, 2.59, 18849.23),
+    (72.0, 1.14, 529.69),
+    (68.0, 1.87, 398.15),
+    (67.0, 4.41, 5507.55),
+    (59.0, 2.89, 5223.69),
+    (56.0, 2.17, 155.42),
+    (45.0, 0.40, 796.30),
+    (36.0, 0.47, 775.52),
+    (29.0, 2.65, 7.11),
+    (21.0, 5.34, 0.98),
+    (19.0, 1.85, 5486.78),
+    (19.0, 4.97, 213.30),
+    (17.0, 2.99, 6275.96),
+    (16.0, 0.03, 2544.31),
+    (16.0, 1.43, 2146.17),
+    (15.0, 1.21, 10977.08),
+    (12.0, 2.83, 1748.02),
+    (12.0, 3.26, 5088.63),
+    (12.0, 5.27, 1194.45),
+    (12.0, 2.08, 4694.00),
+    (11.0, 0.77, 553.57),
+    (10.0, 1.30, 6286.60),
+    (10.0, 4.24, 1349.87),
+    (9.0, 2.70, 242.73),
+    (9.0, 5.64, 951.72),
+    (8.0, 5.30, 2352.87),
+    (6.0, 2.65, 9437.76),
+    (6.0, 4.67, 4690.48),
+];
+
+const EARTH_L2: [(f64, f64, f64); 20] = [
+    (52919.0, 0.0, 0.0),
+    (8720.0, 1.0721, 6283.0758),
+    (309.0, 0.867, 12566.152),
+    (27.0, 0.05, 3.52),
+    (16.0, 5.19, 26.30),
+    (16.0, 3.68, 155.42),
+    (10.0, 0.76, 18849.23),
+    (9.0, 2.06, 77713.77),
+    (7.0, 0.83, 775.52),
+    (5.0, 4.66, 1577.34),
+    (4.0, 1.03, 7.11),
+    (4.0, 3.44, 5573.14),
+    (3.0, 5.14, 796.30),
+    (3.0, 6.05, 5507.55),
+    (3.0, 1.19, 242.73),
+    (3.0, 6.12, 529.69),
+    (3.0, 0.31, 398.15),
+    (3.0, 2.28, 553.57),
+    (2.0, 4.38, 5223.69),
+    (2.0, 3.75, 0.98),
+];
+
+const EARTH_L3: [(f64, f64, f64); 7] = [
+    (289.0, 5.844, 6283.076),
+    (35.0, 0.0, 0.0),
+    (17.0, 5.49, 12566.15),
+    (3.0, 5.20, 155.42),
+    (1.0, 4.72, 3.52),
+    (1.0, 5.30, 18849.23),
+    (1.0, 5.97, 242.73),
+];
+
+#[allow(clippy::approx_constant)]
+const EARTH_L4: [(f64, f64, f64); 3] = [
+    (114.0, 3.142, 0.0),
+    (8.0, 4.13, 6283.08),
+    (1.0, 3.84, 12566.15),
+];
+
+#[allow(clippy::approx_constant)]
+const EARTH_L5: [(f64, f64, f64); 1] = [(1.0, 3.14, 0.0)];
+
+const EARTH_R0: [(f64, f64, f64); 40] = [
+    (100013989.0, 0.0, 0.0),
+    (1670700.0, 3.0984635, 6283.0758500),
+    (13956.0, 3.05525, 12566.15170),
+    (3084.0, 5.1985, 77713.7715),
+    (1628.0, 1.1739, 5753.3849),
+    (1576.0, 2.8469, 7860.4194),
+    (925.0, 5.453, 11506.770),
+    (542.0, 4.564, 3930.210),
+    (472.0, 3.661, 5884.927),
+    (346.0, 0.964, 5507.553),
+    (329.0, 5.900, 5223.694),
+    (307.0, 0.299, 5573.143),
+    (243.0, 4.273, 11790.629),
+    (212.0, 5.847, 1577.344),
+    (186.0, 5.022, 10977.079),
+    (175.0, 3.012, 18849.228),
+    (110.0, 5.055, 5486.778),
+    (98.0, 0.89, 6069.78),
+    (86.0, 5.69, 15720.84),
+    (86.0, 1.27, 161000.69),
+    (65.0, 0.27, 17260.15),
+    (63.0, 0.92, 529.69),
+    (57.0, 2.01, 83996.85),
+    (56.0, 5.24, 71430.70),
+    (49.0, 3.25, 2544.31),
+    (47.0, 2.58, 775.52),
+    (45.0, 5.54, 9437.76),
+    (43.0, 6.01, 6275.96),
+    (39.0, 5.36, 4694.00),
+    (38.0, 2.39, 8827.39),
+    (37.0, 0.83, 19651.05),
+    (37.0, 4.90, 12139.55),
+    (36.0, 1.67, 12036.46),
+    (35.0, 1.84, 2942.46),
+    (33.0, 0.24, 7084.90),
+    (32.0, 0.18, 5088.63),
+    (32.0, 1.78, 398.15),
+    (28.0, 1.21, 6286.60),
+    (28.0, 1.90, 6279.55),
+    (26.0, 4.59, 10447.39),
+];
+
+#[allow(clippy::approx_constant)]
+const EARTH_R1: [(f64, f64, f64); 10] = [
+    (103019.0, 1.107490, 6283.075850),
+    (1721.0, 1.0644, 12566.1517),
+    (702.0, 3.142, 0.0),
+    (32.0, 1.02, 18849.23),
+    (31.0, 2.84, 5507.55),
+    (25.0, 1.32, 5223.69),
+    (18.0, 1.42, 1577.34),
+    (10.0, 5.91, 10977.08),
+    (9.0, 1.42, 6275.96),
+    (9.0, 0.27, 5486.78),
+];
+
+#[allow(clippy::approx_constant)]
+const EARTH_R2: [(f64, f64, f64); 6] = [
+    (4359.0, 5.7846, 6283.0758),
+    (124.0, 5.579, 12566.152),
+    (12.0, 3.14, 0.0),
+    (9.0, 3.63, 77713.77),
+    (6.0, 1.87, 5573.14),
+    (3.0, 5.47, 18849.23),
+];
+
+const EARTH_R3: [(f64, f64, f64); 2] = [(145.0, 4.273, 6283.076), (7.0, 3.92, 12566.15)];
+
+const EARTH_R4: [(f64, f64, f64); 1] = [(4.0, 2.56, 6283.08)];
+
+fn sum_series(terms: &[(f64, f64, f64)], tau: f64) -> f64 {
+    terms.iter().map(|(a, b, c)| a * (b + c * tau).cos()).sum()
+}
+
+fn evaluate_series(series: &[&[(f64, f64, f64)]], tau: f64) -> f64 {
+    series
+        .iter()
+        .rev()
+        .fold(0.0, |acc, terms| acc * tau + sum_series(terms, tau))
+        / 1e8
+}
+
+/// Compute the apparent longitude of the sun with VSOP87
+///
+/// # Arguments
+/// * `jdn` - Julian day (fractional, UT)
+///
+/// # Returns
+/// Apparent sun longitude in radians, normalized to [0, 2π)
+pub fn sun_apparent_longitude(jdn: f64) -> f64 {
+    let jde = jdn + delta_t_days(jdn);
+    let tau = (jde - 2451545.0) / 365250.0;
+    let t = tau * 10.0;
+    let dr = PI / 180.0;
+
+    // Heliocentric longitude of the Earth and Sun-Earth distance
+    let earth_l = evaluate_series(
+        &[
+            &EARTH_L0, &EARTH_L1, &EARTH_L2, &EARTH_L3, &EARTH_L4, &EARTH_L5,
+        ],
+        tau,
+    );
+    let radius = evaluate_series(
+        &[&EARTH_R0, &EARTH_R1, &EARTH_R2, &EARTH_R3, &EARTH_R4],
+        tau,
+    );
+
+    // Geometric geocentric longitude, converted to the FK5 frame
+    let geometric = earth_l / dr + 180.0 - 0.09033 / 3600.0;
+
+    // Nutation in longitude (Meeus chapter 22, accurate to 0.5")
+    let omega = (125.04452 - 1934.136261 * t) * dr;
+    let sun_mean = (280.4665 + 36000.7698 * t) * dr;
+    let moon_mean = (218.3165 + 481267.8813 * t) * dr;
+    let nutation =
+        (-17.20 * omega.sin() - 1.32 * (2.0 * sun_mean).sin() - 0.23 * (2.0 * moon_mean).sin()
+            + 0.21 * (2.0 * omega).sin())
+            / 3600.0;
+
+    // Annual aberration
+    let aberration = -20.4898 / 3600.0 / radius;
+
+    let apparent = (geometric + nutation + aberration).rem_euclid(360.0);
+    apparent * dr
+}
+
+/// Compute the time of the k-th new moon with the Meeus chapter 49 phase series
+///
+/// This is `moon_phase::moon_phase_instant`: the mean phase plus every
+/// periodic and planetary term of chapter 49, which Meeus fitted to
+/// ELP-2000/82, converted to UT with the Espenak-Meeus ΔT. It is not a
+/// separate lunar theory; it differs from `lunar::new_moon` by the full set
+/// of correction terms and by ΔT. Iterating the truncated chapter 47
+/// longitude of `moon_position` until the elongation is zero moves the
+/// instant by about 20 s on average (84 s at most for 1000-2060), within the
+/// truncation error of those terms, so it is not used as a refinement.
+///
+/// Uses the same numbering as `lunar::new_moon` (k = 0 is the new moon of
+/// 1900 January 1).
+///
+/// # Returns
+/// Julian day (fractional, UT)
+pub fn new_moon_accurate(k: i32) -> f64 {
+    // Lunation 0 of the phase series is the new moon of 2000 January 6
+    moon_phase_instant(k - 1237, MoonPhase::NewMoon)
+}
+
+/// Sun longitude in the requested mode, in radians [0, 2π)
+pub fn sun_longitude_with_mode(jdn: f64, mode: EphemerisMode) -> f64 {
+    match mode {
+        EphemerisMode::Fast => sun_longitude(jdn),
+        EphemerisMode::Accurate => sun_apparent_longitude(jdn),
+    }
+}
+
+/// Time of the k-th new moon in the requested mode (fractional JD, UT)
+pub fn new_moon_with_mode(k: i32, mode: EphemerisMode) -> f64 {
+    match mode {
+        EphemerisMode::Fast => new_moon(k),
+        EphemerisMode::Accurate => new_moon_accurate(k),
+    }
+}
+
+/// A day on which fast and accurate modes give different calendar results
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModeDisagreement {
+    pub jd: i32,
+    pub day: i32,
+    pub month: i32,
+    pub year: i32,
+    pub fast_lunar: LunarDate,
+    pub accurate_lunar: LunarDate,
+    pub fast_term: usize,
+    pub accurate_term: usize,
+}
+
+impl ModeDisagreement {
+    /// True if the lunar date differs (as opposed to only the solar term)
+    pub fn lunar_differs(&self) -> bool {
+        self.fast_lunar != self.accurate_lunar
+    }
+}
+
+/// List every day in a range of solar years where the two modes disagree
+///
+/// A day is reported when either the lunar date (including the leap flag) or
+/// the active solar term differs between fast and accurate mode.
+///
+/// # Arguments
+/// * `start_year` - First solar year (inclusive)
+/// * `end_year` - Last solar year (inclusive)
+/// * `time_zone` - Timezone offset (7.0 for UTC+7:00)
+///
+/// # Returns
+/// Disagreeing days in chronological order
+pub fn compare_modes(start_year: i32, end_year: i32, time_zone: f64) -> Vec<ModeDisagreement> {
+    let start_jd = jd_from_date(1, 1, start_year);
+    let end_jd = jd_from_date(31, 12, end_year);
+
+    (start_jd..=end_jd)
+        .filter_map(|jd| {
+            let (day, month, year) = jd_to_date(jd);
+            let fast_lunar =
+                convert_solar_to_lunar_with_mode(day, month, year, time_zone, EphemerisMode::Fast);
+            let accurate_lunar = convert_solar_to_lunar_with_mode(
+                day,
+                month,
+                year,
+                time_zone,
+                EphemerisMode::Accurate,
+            );
+            let fast_term = get_tiet_khi_with_mode(jd, time_zone, EphemerisMode::Fast).index;
+            let accurate_term =
+                get_tiet_khi_with_mode(jd, time_zone, EphemerisMode::Accurate).index;
+
+            if fast_lunar == accurate_lunar && fast_term == accurate_term {
+                return None;
+            }
+
+            Some(ModeDisagreement {
+                jd,
+                day,
+                month,
+                year,
+                fast_lunar,
+                accurate_lunar,
+                fast_term,
+                accurate_term,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::julian::jd_to_datetime;
+    use crate::lunar::get_new_moon_day_with_mode;
+
+    fn longitude_deg(jd: f64) -> f64 {
+        sun_apparent_longitude(jd) * 180.0 / PI
+    }
+
+    #[test]
+    fn test_delta_t_known_values() {
+        // Observed ΔT: about 64 s in 2000 and 29 s in 1950
+        assert!((delta_t_seconds(2000.0) - 63.86).abs() < 0.01);
+        assert!((delta_t_seconds(1950.0) - 29.07).abs() < 0.01);
+        assert!(delta_t_seconds(1600.0) > 100.0);
+        // Observed: 69.36 s in 2020, and no jump where the table ends
+        assert!((delta_t_seconds(2020.0) - 69.36).abs() < 0.01);
+        assert!((delta_t_seconds(2024.999) - delta_t_seconds(2025.0)).abs() < 0.01);
+        assert!((delta_t_seconds(2049.999) - delta_t_seconds(2050.0)).abs() < 0.01);
+        // Medieval ΔT is about 26 minutes (Morrison & Stephenson)
+        assert!((delta_t_seconds(1000.0) - 1574.2).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_supported_years_per_mode() {
+        assert_eq!(EphemerisMode::Fast.supported_years(), 1800..=2199);
+        assert_eq!(EphemerisMode::Accurate.supported_years(), 1000..=3000);
+        assert_eq!(EphemerisMode::for_year(2024), Ok(EphemerisMode::Fast));
+        assert_eq!(EphemerisMode::for_year(1450), Ok(EphemerisMode::Accurate));
+        assert_eq!(EphemerisMode::for_year(2500), Ok(EphemerisMode::Accurate));
+
+        let err = EphemerisMode::for_year(999).unwrap_err();
+        assert_eq!(err.mode, EphemerisMode::Accurate);
+        assert_eq!(
+            err.to_string(),
+            "year 999 is outside the supported range 1000-3000 (accurate ephemeris)"
+        );
+        assert!(EphemerisMode::Fast.check_year(1799).is_err());
+    }
+
+    #[test]
+    fn test_accurate_new_moons_far_from_1900() {
+        // Total solar eclipse of 1415 June 7 (Julian), seen from Bohemia at
+        // about 06:30 UT; the new moon falls on the same Vietnamese day
+        let k = ((1415.43 - 1900.0) * 12.3685_f64).round() as i32;
+        let eclipse_day = (k - 2..k + 2)
+            .map(|k| get_new_moon_day_with_mode(k, 7.0, EphemerisMode::Accurate))
+            .find(|&jd| jd_to_date(jd) == (7, 6, 1415));
+        assert!(eclipse_day.is_some());
+    }
+
+    #[test]
+    fn test_sun_apparent_longitude_meeus_example() {
+        // Meeus example 25.b: 1992 October 13.0 TD, apparent λ = 199°54'21.8"
+        let jde = 2448908.5;
+        let jd_ut = jde - delta_t_days(jde);
+        let lambda = longitude_deg(jd_ut);
+        assert!((lambda - 199.906061).abs() < 0.0005, "λ = {}", lambda);
+    }
+
+    #[test]
+    fn test_equinox_2024_instant() {
+        // March equinox 2024: 2024-03-20 03:06 UTC
+        let before = 2460389.5 + 3.0 / 24.0 + 5.0 / 1440.0;
+        let after = before + 2.0 / 1440.0;
+        assert!(longitude_deg(before) > 359.9);
+        assert!(longitude_deg(after) < 0.1);
+        assert_eq!(jd_to_datetime(before, 0.0).day, 20);
+    }
+
+    #[test]
+    fn test_new_moon_accurate_matches_fast_numbering() {
+        for k in [0, 1237, 1536, 2400] {
+            let fast = new_moon(k);
+            let accurate = new_moon_accurate(k);
+            assert!((fast - accurate).abs() < 0.05, "k = {}", k);
+        }
+    }
+
+    #[test]
+    fn test_mode_default_is_fast() {
+        assert_eq!(EphemerisMode::default(), EphemerisMode::Fast);
+        assert_eq!(EphemerisMode::Accurate.as_str(), "accurate");
+    }
+
+    #[test]
+    fn test_compare_modes_reports_known_disagreement() {
+        // docs/almanac/fast-vs-accurate.md: 2026-08-12 → 2026-09-10, fast
+        // 1/7/2026 → 30/7/2026, accurate 30/6/2026 → 29/7/2026
+        let report = compare_modes(2026, 2026, 7.0);
+        let lunar: Vec<&ModeDisagreement> =
+            report.iter().filter(|row| row.lunar_differs()).collect();
+        assert_eq!(lunar.len(), 30);
+
+        let first = lunar[0];
+        let last = lunar[lunar.len() - 1];
+        assert_eq!((first.day, first.month, first.year), (12, 8, 2026));
+        assert_eq!((last.day, last.month, last.year), (10, 9, 2026));
+        let dm = |date: &LunarDate| (date.day, date.month, date.year);
+        assert_eq!(dm(&first.fast_lunar), (1, 7, 2026));
+        assert_eq!(dm(&first.accurate_lunar), (30, 6, 2026));
+        assert_eq!(dm(&last.fast_lunar), (30, 7, 2026));
+        assert_eq!(dm(&last.accurate_lunar), (29, 7, 2026));
+    }
+
+    #[test]
+    fn test_compare_modes_agree_in_2024() {
+        assert!(compare_modes(2024, 2024, 7.0).is_empty());
+    }
+}
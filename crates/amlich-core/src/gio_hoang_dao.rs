@@ -8,11 +8,26 @@
  * The 12 Stars:
  * - Good Stars (Hoàng Đạo - 6 stars): Thanh Long, Minh Đường, Kim Quỹ, Bảo Quang, Ngọc Đường, Tư Mệnh
  * - Bad Stars (Hắc Đạo - 6 stars): Thiên Hình, Chu Tước, Bạch Hổ, Thiên Lao, Nguyên Vũ, Câu Trận
+ *
+ * Hour boundaries (giờ địa phương): by default each branch covers two clock
+ * hours, Tý being 23:00-01:00. Traditionally the branches follow the sun,
+ * so `HourReckoning` can place them by local mean solar time (longitude) or
+ * apparent solar time (longitude plus the equation of time). Across
+ * Vietnam this moves the boundaries by up to about 50 minutes.
  */
-use crate::types::CHI;
+use crate::canchi::get_hour_canchi;
+use crate::julian::jd_from_date;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::sun_times::equation_of_time;
+use crate::types::{Can, CanChi, Chi};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 /// Star type (good or bad)
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StarType {
     Good,
     Bad,
@@ -133,23 +148,114 @@ pub fn get_hour_time_range(chi_index: usize) -> &'static str {
     RANGES[chi_index % 12]
 }
 
+/// Clock minute of the day at which each hour branch starts (Tý..Hợi),
+/// with two civil hours per branch
+pub const CLOCK_HOUR_STARTS: [i32; 12] = [
+    1380, 60, 180, 300, 420, 540, 660, 780, 900, 1020, 1140, 1260,
+];
+
+/// How the twelve hour branches are placed on the clock
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HourReckoning {
+    /// Two civil hours per branch, Tý at 23:00-01:00 of the zone clock
+    #[default]
+    Clock,
+    /// Local mean solar time at a longitude (degrees, east positive)
+    LocalMean { longitude: f64 },
+    /// Apparent (sundial) solar time at a longitude: local mean time
+    /// corrected by the equation of time
+    Apparent { longitude: f64 },
+}
+
+/// Clock start of each hour branch on a date
+///
+/// # Arguments
+/// * `day` - Day (1-31)
+/// * `month` - Month (1-12)
+/// * `year` - Year
+/// * `time_zone` - Offset of the clock in hours (7.0 for UTC+7:00)
+/// * `reckoning` - How the branches follow the sun
+///
+/// # Returns
+/// Minute of the day (0-1439) at which Tý..Hợi start, rounded to the minute
+pub fn get_hour_starts(
+    day: i32,
+    month: i32,
+    year: i32,
+    time_zone: f64,
+    reckoning: HourReckoning,
+) -> [i32; 12] {
+    // Clock time minus solar time, in minutes
+    let shift = match reckoning {
+        HourReckoning::Clock => return CLOCK_HOUR_STARTS,
+        HourReckoning::LocalMean { longitude } => time_zone * 60.0 - 4.0 * longitude,
+        HourReckoning::Apparent { longitude } => {
+            // The equation of time changes by under 30 s a day; take it at local noon
+            let noon = jd_from_date(day, month, year) as f64 - time_zone / 24.0;
+            time_zone * 60.0 - 4.0 * longitude - equation_of_time(noon)
+        }
+    };
+    CLOCK_HOUR_STARTS.map(|start| (start + shift.round() as i32).rem_euclid(1440))
+}
+
+/// Format the clock range of an hour branch, e.g. "22:57-00:57"
+///
+/// # Arguments
+/// * `starts` - Branch starts from `get_hour_starts`
+/// * `chi_index` - Hour Branch index (0-11)
+pub fn format_hour_range(starts: &[i32; 12], chi_index: usize) -> String {
+    let start = starts[chi_index % 12];
+    let end = starts[(chi_index + 1) % 12];
+    format!(
+        "{:02}:{:02}-{:02}:{:02}",
+        start / 60,
+        start % 60,
+        end / 60,
+        end % 60
+    )
+}
+
+/// Hour branch in effect at a clock minute of the day
+///
+/// # Arguments
+/// * `starts` - Branch starts from `get_hour_starts`
+/// * `minute_of_day` - Minutes since local midnight (0-1439)
+pub fn hour_chi_at(starts: &[i32; 12], minute_of_day: i32) -> Chi {
+    let minute = minute_of_day.rem_euclid(1440);
+    // Branches are contiguous, so the current one started most recently
+    let index = (0..12)
+        .min_by_key(|&i| (minute - starts[i]).rem_euclid(1440))
+        .unwrap_or(0);
+    Chi::from_index(index)
+}
+
 /// Information about a single hour
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HourInfo {
     pub hour_index: usize,
-    pub hour_chi: String,
+    pub hour_chi: Chi,
     pub time_range: String,
+    /// Clock minute of the day (0-1439) at which the hour starts
+    pub start_minute: i32,
     pub star: String,
     pub star_description: String,
     pub star_type: StarType,
     pub is_good: bool,
+    /// Can Chi of the hour (e.g. "Giáp Tý")
+    pub canchi: CanChi,
+    /// Nạp Âm of the hour's Can Chi
+    pub na_am: String,
+    /// Ages clashing with the hour (tuổi xung giờ)
+    pub tuoi_xung: Vec<String>,
 }
 
 /// Complete information about auspicious hours for a day
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GioHoangDao {
-    pub day_chi_index: usize,
-    pub day_chi: String,
+    pub day_chi: Chi,
     pub all_hours: Vec<HourInfo>,
     pub good_hours: Vec<HourInfo>,
     pub good_hour_count: usize,
@@ -159,19 +265,32 @@ pub struct GioHoangDao {
 /// Get Auspicious Hours (Giờ Hoàng Đạo) for a given day
 ///
 /// # Arguments
-/// * `day_chi_index` - Day's Branch index (0-11)
+/// * `day_can` - Day's Stem, used for the hour stems
+/// * `day_chi` - Day's Branch
 ///
 /// # Returns
 /// Complete hour information with stars
-pub fn get_gio_hoang_dao(day_chi_index: usize) -> GioHoangDao {
-    let start_hour = DAY_TO_START_HOUR[day_chi_index % 12];
+pub fn get_gio_hoang_dao(day_can: Can, day_chi: Chi) -> GioHoangDao {
+    get_gio_hoang_dao_at(day_can, day_chi, &CLOCK_HOUR_STARTS)
+}
+
+/// Get Auspicious Hours (Giờ Hoàng Đạo) with local hour boundaries
+///
+/// # Arguments
+/// * `day_can` - Day's Stem, used for the hour stems
+/// * `day_chi` - Day's Branch
+/// * `starts` - Branch starts from `get_hour_starts`
+///
+/// # Returns
+/// Complete hour information with stars and local time ranges
+pub fn get_gio_hoang_dao_at(day_can: Can, day_chi: Chi, starts: &[i32; 12]) -> GioHoangDao {
+    let start_hour = DAY_TO_START_HOUR[day_chi.index()];
     let mut hours = Vec::new();
     let mut good_hours = Vec::new();
 
     // Calculate star for each of the 12 hours
-    for i in 0..12 {
-        let hour_chi_index = i;
-        let hour_chi = CHI[hour_chi_index].to_string();
+    for hour_chi in Chi::ALL {
+        let hour_chi_index = hour_chi.index();
 
         // Calculate which star governs this hour
         // The star cycle starts at startHour with Thanh Long (index 0)
@@ -180,14 +299,27 @@ pub fn get_gio_hoang_dao(day_chi_index: usize) -> GioHoangDao {
 
         let is_good = star.star_type == StarType::Good;
 
+        // Same convention as the day conflict: hour stem with the opposing branch
+        let canchi = get_hour_canchi(day_can, hour_chi);
+        let na_am = canchi.na_am().to_string();
+        let opposing = hour_chi.opposing();
+        let tuoi_xung = vec![
+            format!("{} {}", canchi.can, opposing),
+            opposing.con_giap().to_string(),
+        ];
+
         let hour_info = HourInfo {
             hour_index: hour_chi_index,
-            hour_chi: hour_chi.clone(),
-            time_range: get_hour_time_range(hour_chi_index).to_string(),
+            hour_chi,
+            time_range: format_hour_range(starts, hour_chi_index),
+            start_minute: starts[hour_chi_index],
             star: star.name.to_string(),
             star_description: star.description.to_string(),
             star_type: star.star_type.clone(),
             is_good,
+            canchi,
+            na_am,
+            tuoi_xung,
         };
 
         if is_good {
@@ -204,8 +336,7 @@ pub fn get_gio_hoang_dao(day_chi_index: usize) -> GioHoangDao {
         .join(", ");
 
     GioHoangDao {
-        day_chi_index: day_chi_index % 12,
-        day_chi: CHI[day_chi_index % 12].to_string(),
+        day_chi,
         all_hours: hours,
         good_hour_count: good_hours.len(),
         good_hours,
@@ -216,14 +347,15 @@ pub fn get_gio_hoang_dao(day_chi_index: usize) -> GioHoangDao {
 /// Check if a specific hour is auspicious
 ///
 /// # Arguments
-/// * `day_chi_index` - Day's Branch index
-/// * `hour_chi_index` - Hour's Branch index
+/// * `day_can` - Day's Stem
+/// * `day_chi` - Day's Branch
+/// * `hour_chi` - Hour's Branch
 ///
 /// # Returns
 /// Hour details with star info
-pub fn is_hour_auspicious(day_chi_index: usize, hour_chi_index: usize) -> HourInfo {
-    let result = get_gio_hoang_dao(day_chi_index);
-    result.all_hours[hour_chi_index % 12].clone()
+pub fn is_hour_auspicious(day_can: Can, day_chi: Chi, hour_chi: Chi) -> HourInfo {
+    let result = get_gio_hoang_dao(day_can, day_chi);
+    result.all_hours[hour_chi.index()].clone()
 }
 
 #[cfg(test)]
@@ -261,13 +393,74 @@ mod tests {
         assert_eq!(get_hour_time_range(11), "21:00-23:00"); // Hợi
     }
 
+    #[test]
+    fn test_clock_starts_match_fixed_ranges() {
+        let starts = get_hour_starts(1, 1, 2025, 7.0, HourReckoning::Clock);
+        for i in 0..12 {
+            assert_eq!(format_hour_range(&starts, i), get_hour_time_range(i));
+        }
+        assert_eq!(hour_chi_at(&starts, 23 * 60 + 30), Chi::Ty);
+        assert_eq!(hour_chi_at(&starts, 30), Chi::Ty);
+        assert_eq!(hour_chi_at(&starts, 60), Chi::Suu);
+        assert_eq!(hour_chi_at(&starts, 22 * 60 + 59), Chi::Hoi);
+    }
+
+    #[test]
+    fn test_local_mean_solar_hours() {
+        // Hà Nội (105.85°E) is 3.4 min east of the UTC+7 meridian
+        let hanoi = get_hour_starts(
+            1,
+            1,
+            2025,
+            7.0,
+            HourReckoning::LocalMean { longitude: 105.85 },
+        );
+        assert_eq!(format_hour_range(&hanoi, 0), "22:57-00:57");
+        // Lai Châu (103.3°E) runs 7 min behind the clock
+        let lai_chau = get_hour_starts(
+            1,
+            1,
+            2025,
+            7.0,
+            HourReckoning::LocalMean { longitude: 103.3 },
+        );
+        assert_eq!(format_hour_range(&lai_chau, 6), "11:07-13:07");
+        // Sydney in daylight saving: the sun is 56 min behind the clock
+        let sydney = get_hour_starts(
+            15,
+            1,
+            2025,
+            11.0,
+            HourReckoning::LocalMean { longitude: 151.0 },
+        );
+        assert_eq!(format_hour_range(&sydney, 0), "23:56-01:56");
+        assert_eq!(hour_chi_at(&sydney, 23 * 60 + 30), Chi::Hoi);
+        assert_eq!(hour_chi_at(&sydney, 60), Chi::Ty);
+    }
+
+    #[test]
+    fn test_apparent_solar_hours_follow_equation_of_time() {
+        // Early November the sun is 16 min fast, so Ngọ starts before 11:00 in Hà Nội
+        let hanoi = HourReckoning::Apparent { longitude: 105.85 };
+        let nov = get_hour_starts(3, 11, 2024, 7.0, hanoi);
+        assert_eq!(format_hour_range(&nov, 6), "10:40-12:40");
+        // Mid February it is 14 min slow
+        let feb = get_hour_starts(11, 2, 2024, 7.0, hanoi);
+        assert_eq!(format_hour_range(&feb, 6), "11:11-13:11");
+
+        let result = get_gio_hoang_dao_at(Can::Giap, Chi::Ty, &nov);
+        assert_eq!(result.all_hours[6].time_range, "10:40-12:40");
+        assert_eq!(result.all_hours[6].start_minute, 640);
+        assert!(result.summary.contains("Ngọ (10:40-12:40)"));
+    }
+
     #[test]
     fn test_gio_hoang_dao_basic() {
         // Test Tý day (index 0)
-        let result = get_gio_hoang_dao(0);
+        let result = get_gio_hoang_dao(Can::Giap, Chi::Ty);
 
-        assert_eq!(result.day_chi_index, 0);
-        assert_eq!(result.day_chi, "Tý");
+        assert_eq!(result.day_chi.index(), 0);
+        assert_eq!(result.day_chi, Chi::Ty);
         assert_eq!(result.all_hours.len(), 12);
         assert_eq!(result.good_hour_count, 6);
         assert_eq!(result.good_hours.len(), 6);
@@ -276,32 +469,28 @@ mod tests {
     #[test]
     fn test_gio_hoang_dao_ty_day() {
         // For Tý day, Thanh Long starts at Thân hour (index 8)
-        let result = get_gio_hoang_dao(0);
+        let result = get_gio_hoang_dao(Can::Giap, Chi::Ty);
 
         // Hour 8 (Thân) should have Thanh Long (good)
         assert_eq!(result.all_hours[8].star, "Thanh Long");
         assert!(result.all_hours[8].is_good);
 
         // Good hours: Tý, Sửu, Mão, Ngọ, Thân, Dậu
-        let good: Vec<&str> = result
-            .good_hours
-            .iter()
-            .map(|h| h.hour_chi.as_str())
-            .collect();
-        assert!(good.contains(&"Tý"));
-        assert!(good.contains(&"Sửu"));
-        assert!(good.contains(&"Mão"));
-        assert!(good.contains(&"Ngọ"));
-        assert!(good.contains(&"Thân"));
-        assert!(good.contains(&"Dậu"));
+        let good: Vec<Chi> = result.good_hours.iter().map(|h| h.hour_chi).collect();
+        assert!(good.contains(&Chi::Ty));
+        assert!(good.contains(&Chi::Suu));
+        assert!(good.contains(&Chi::Mao));
+        assert!(good.contains(&Chi::Ngo));
+        assert!(good.contains(&Chi::Than));
+        assert!(good.contains(&Chi::Dau));
     }
 
     #[test]
     fn test_gio_hoang_dao_thin_day() {
         // For Thìn day (index 4), Thanh Long starts at Thìn hour (index 4)
-        let result = get_gio_hoang_dao(4);
+        let result = get_gio_hoang_dao(Can::Giap, Chi::Thin);
 
-        assert_eq!(result.day_chi, "Thìn");
+        assert_eq!(result.day_chi, Chi::Thin);
 
         // Hour 4 (Thìn) should have Thanh Long (good)
         assert_eq!(result.all_hours[4].star, "Thanh Long");
@@ -311,23 +500,23 @@ mod tests {
     #[test]
     fn test_is_hour_auspicious() {
         // Tý day, Tý hour (index 0): S=8 → star_index=(0+12-8)%12=4 → Kim Quỹ (good)
-        let hour_info = is_hour_auspicious(0, 0);
+        let hour_info = is_hour_auspicious(Can::Giap, Chi::Ty, Chi::Ty);
         assert_eq!(hour_info.star, "Kim Quỹ");
         assert!(hour_info.is_good);
 
         // Tý day, Dần hour (index 2): star_index=(2+12-8)%12=6 → Bạch Hổ (bad)
-        let hour_info2 = is_hour_auspicious(0, 2);
+        let hour_info2 = is_hour_auspicious(Can::Giap, Chi::Ty, Chi::Dan);
         assert_eq!(hour_info2.star, "Bạch Hổ");
         assert!(!hour_info2.is_good);
 
         // Thìn day, Thân hour (index 8): S=4 → star_index=(8+12-4)%12=4 → Kim Quỹ (good)
-        let hour_info3 = is_hour_auspicious(4, 8);
+        let hour_info3 = is_hour_auspicious(Can::Giap, Chi::Thin, Chi::Than);
         assert!(hour_info3.is_good);
     }
 
     #[test]
     fn test_good_hours_summary() {
-        let result = get_gio_hoang_dao(0);
+        let result = get_gio_hoang_dao(Can::Giap, Chi::Ty);
 
         // Summary should contain good hours
         assert!(!result.summary.is_empty());
@@ -337,8 +526,8 @@ mod tests {
     #[test]
     fn test_all_days_have_6_good_hours() {
         // Every day should have exactly 6 good hours
-        for day_chi in 0..12 {
-            let result = get_gio_hoang_dao(day_chi);
+        for day_chi in Chi::ALL {
+            let result = get_gio_hoang_dao(Can::from_index(day_chi.index()), day_chi);
             assert_eq!(
                 result.good_hour_count, 6,
                 "Day {} should have 6 good hours",
@@ -346,4 +535,20 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_hour_canchi_na_am_and_conflict() {
+        // Giáp Tý day: hours run from Giáp Tý to Ất Hợi
+        let result = get_gio_hoang_dao(Can::Giap, Chi::Ty);
+        let ty = &result.all_hours[0];
+        assert_eq!(ty.canchi.full, "Giáp Tý");
+        assert_eq!(ty.na_am, "Hải Trung Kim");
+        assert_eq!(ty.tuoi_xung, vec!["Giáp Ngọ", "Ngọ (Ngựa)"]);
+        assert_eq!(result.all_hours[11].canchi.full, "Ất Hợi");
+
+        // Mậu Ngọ day: Tý hour is Nhâm Tý
+        let result = get_gio_hoang_dao(Can::Mau, Chi::Ngo);
+        assert_eq!(result.all_hours[0].canchi.full, "Nhâm Tý");
+        assert_eq!(result.all_hours[0].na_am, "Tang Đố Mộc");
+    }
 }
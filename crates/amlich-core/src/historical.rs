@@ -0,0 +1,543 @@
+/**
+ * Historical Vietnamese Calendar
+ *
+ * Vietnamese calendars were computed for UTC+8 (the Chinese meridian) until
+ * the Democratic Republic of Vietnam adopted UTC+7 by Decision 121-CP of
+ * 1967-08-08. Converting older dates with UTC+7 can be a day off at month
+ * boundaries, e.g. for death anniversaries recorded in the 1940s-60s.
+ *
+ * `TimezonePolicy::HistoricalVietnam` picks the offset from the date, and a
+ * table of officially published month starts (`MONTH_START_OVERRIDES`) wins
+ * over the computed new moon day.
+ */
+use crate::ephemeris::{new_moon_with_mode, EphemerisMode};
+use crate::julian::{jd_from_date, jd_to_date};
+use crate::lunar::{
+    convert_lunar_to_solar_with_mode, convert_solar_to_lunar_with_mode, get_new_moon_day_with_mode,
+    leap_month_offset_from, lunar_month11_from, lunar_to_solar, solar_to_lunar,
+    LunarConversionError, LunarDate, MonthBoundaries, SolarDate,
+};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::timezone::TimeZone;
+use alloc::vec::Vec;
+
+/// First day computed for UTC+7 under `TimezonePolicy::HistoricalVietnam`
+pub const UTC7_ADOPTION_DATE: SolarDate = SolarDate {
+    day: 8,
+    month: 8,
+    year: 1967,
+};
+
+/// How the calendar time zone is chosen for a date
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimezonePolicy {
+    /// One offset for all dates, in hours (7.0 for UTC+7:00)
+    Fixed(f64),
+    /// UTC+8 before `UTC7_ADOPTION_DATE`, UTC+7 from then on, with
+    /// official month starts from the override table
+    HistoricalVietnam,
+    /// The offset a time zone has on each date (daylight saving time,
+    /// historical changes)
+    Zone(TimeZone),
+}
+
+impl TimezonePolicy {
+    /// Time zone offset in effect on a solar date
+    pub fn offset_for(&self, day: i32, month: i32, year: i32) -> f64 {
+        match self {
+            TimezonePolicy::Fixed(offset) => *offset,
+            TimezonePolicy::HistoricalVietnam => {
+                let adoption = UTC7_ADOPTION_DATE;
+                if jd_from_date(day, month, year)
+                    < jd_from_date(adoption.day, adoption.month, adoption.year)
+                {
+                    8.0
+                } else {
+                    7.0
+                }
+            }
+            TimezonePolicy::Zone(zone) => zone.offset_hours(day, month, year),
+        }
+    }
+
+    /// Time zone offset in effect at an instant, given as a (fractional)
+    /// Julian day in UT
+    pub fn offset_at(&self, jd: f64) -> f64 {
+        match self {
+            TimezonePolicy::Fixed(offset) => *offset,
+            TimezonePolicy::HistoricalVietnam => {
+                let (day, month, year) = jd_to_date((jd + 0.5).floor() as i32);
+                self.offset_for(day, month, year)
+            }
+            TimezonePolicy::Zone(zone) => zone.offset_minutes_at(jd) as f64 / 60.0,
+        }
+    }
+}
+
+/// Month boundaries with each new moon on the local day of the offset in
+/// effect at that new moon
+///
+/// Under `TimezonePolicy::Zone` a year spans daylight saving changes; taking
+/// one offset for all new moons would move some month starts by a day and
+/// give lunar day 0 or skipped days around the change.
+pub(crate) struct PolicyMonths {
+    pub(crate) policy: TimezonePolicy,
+    pub(crate) mode: EphemerisMode,
+}
+
+impl PolicyMonths {
+    /// Local day of the k-th new moon and the offset it was computed with
+    fn new_moon(&self, k: i32) -> (i32, f64) {
+        let instant = new_moon_with_mode(k, self.mode);
+        let time_zone = self.policy.offset_at(instant);
+        ((instant + 0.5 + time_zone / 24.0).floor() as i32, time_zone)
+    }
+}
+
+impl MonthBoundaries for PolicyMonths {
+    fn new_moon_day(&mut self, k: i32) -> i32 {
+        self.new_moon(k).0
+    }
+
+    fn lunar_month11(&mut self, year: i32) -> i32 {
+        lunar_month11_from(year, self.mode, |k| self.new_moon(k))
+    }
+
+    fn leap_month_offset(&mut self, a11: i32) -> i32 {
+        leap_month_offset_from(a11, self.mode, |k| self.new_moon(k))
+    }
+}
+
+/// Officially published start of a lunar month
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonthStartOverride {
+    pub lunar_year: i32,
+    pub lunar_month: i32,
+    pub is_leap: bool,
+    /// Solar date of day 1 as published
+    pub start: SolarDate,
+    pub region: &'static str,
+    pub source: &'static str,
+}
+
+/// Official month starts used by `TimezonePolicy::HistoricalVietnam`
+///
+/// Kept as a static table rather than a data file so that it is available
+/// without `std`. An entry must cite a published calendar whose month start
+/// differs from the computed one; Tết Mậu Thân 1968 (29/1 in the North) is
+/// not listed because UTC+7 already gives it. No such entry has been
+/// verified yet, so the table is empty.
+pub const MONTH_START_OVERRIDES: &[MonthStartOverride] = &[];
+
+/// Official month starts used by `TimezonePolicy::HistoricalVietnam`
+pub fn month_start_overrides() -> &'static [MonthStartOverride] {
+    MONTH_START_OVERRIDES
+}
+
+/// A lunar month with its (possibly overridden) first day
+struct MonthEntry {
+    month: i32,
+    year: i32,
+    is_leap: bool,
+    start_jd: i32,
+}
+
+/// Lunar months around `jd` in one time zone, with official starts applied
+fn month_entries_around(
+    jd: i32,
+    time_zone: f64,
+    mode: EphemerisMode,
+    overrides: &[MonthStartOverride],
+) -> Vec<MonthEntry> {
+    let k = ((jd as f64 - 2415021.076998695) / 29.530588853).floor() as i32;
+    (k - 1..=k + 2)
+        .map(|k| {
+            let start_jd = get_new_moon_day_with_mode(k, time_zone, mode);
+            let (day, month, year) = jd_to_date(start_jd);
+            let label = convert_solar_to_lunar_with_mode(day, month, year, time_zone, mode);
+            let start_jd = overrides
+                .iter()
+                .find(|o| {
+                    o.lunar_year == label.year
+                        && o.lunar_month == label.month
+                        && o.is_leap == label.is_leap
+                })
+                .map(|o| jd_from_date(o.start.day, o.start.month, o.start.year))
+                .unwrap_or(start_jd);
+            MonthEntry {
+                month: label.month,
+                year: label.year,
+                is_leap: label.is_leap,
+                start_jd,
+            }
+        })
+        .collect()
+}
+
+fn solar_to_lunar_with_overrides(
+    day: i32,
+    month: i32,
+    year: i32,
+    time_zone: f64,
+    mode: EphemerisMode,
+    overrides: &[MonthStartOverride],
+) -> LunarDate {
+    let jd = jd_from_date(day, month, year);
+    month_entries_around(jd, time_zone, mode, overrides)
+        .into_iter()
+        .filter(|m| m.start_jd <= jd)
+        .max_by_key(|m| m.start_jd)
+        .map(|m| LunarDate {
+            day: jd - m.start_jd + 1,
+            month: m.month,
+            year: m.year,
+            is_leap: m.is_leap,
+        })
+        .unwrap_or_else(|| convert_solar_to_lunar_with_mode(day, month, year, time_zone, mode))
+}
+
+fn lunar_to_solar_with_overrides(
+    lunar_day: i32,
+    lunar_month: i32,
+    lunar_year: i32,
+    lunar_leap: bool,
+    mode: EphemerisMode,
+    overrides: &[MonthStartOverride],
+) -> Result<SolarDate, LunarConversionError> {
+    let policy = TimezonePolicy::HistoricalVietnam;
+    let mut time_zone = 7.0;
+    let mut start =
+        convert_lunar_to_solar_with_mode(1, lunar_month, lunar_year, lunar_leap, time_zone, mode)?;
+    if policy.offset_for(start.day, start.month, start.year) != time_zone {
+        time_zone = 8.0;
+        start = convert_lunar_to_solar_with_mode(
+            1,
+            lunar_month,
+            lunar_year,
+            lunar_leap,
+            time_zone,
+            mode,
+        )?;
+    }
+
+    // Look up the month in the middle so an override of a day or two either
+    // way still finds it and its successor
+    let mid = jd_from_date(start.day, start.month, start.year) + 14;
+    let entries = month_entries_around(mid, time_zone, mode, overrides);
+    let index = entries
+        .iter()
+        .position(|m| m.year == lunar_year && m.month == lunar_month && m.is_leap == lunar_leap)
+        .ok_or(if lunar_leap {
+            LunarConversionError::NoSuchLeapMonth {
+                month: lunar_month,
+                year: lunar_year,
+            }
+        } else {
+            LunarConversionError::InvalidMonth(lunar_month)
+        })?;
+    let month_start = entries[index].start_jd;
+    let month_length = entries
+        .get(index + 1)
+        .map(|next| next.start_jd - month_start)
+        .unwrap_or(30);
+    if lunar_day < 1 || lunar_day > month_length {
+        return Err(LunarConversionError::DayOutOfRange {
+            day: lunar_day,
+            month_length,
+        });
+    }
+
+    let (day, month, year) = jd_to_date(month_start + lunar_day - 1);
+    Ok(SolarDate { day, month, year })
+}
+
+/// Convert solar date to lunar date under a time zone policy
+///
+/// # Arguments
+/// * `day` - Solar day (1-31)
+/// * `month` - Solar month (1-12)
+/// * `year` - Solar year
+/// * `policy` - Time zone policy
+/// * `mode` - Ephemeris mode; fast mode misplaces some pre-1968 new moons
+///   (see `docs/almanac/fast-vs-accurate.md`), so genealogy use should
+///   prefer `EphemerisMode::Accurate`
+///
+/// # Returns
+/// Lunar date
+pub fn convert_solar_to_lunar_with_policy(
+    day: i32,
+    month: i32,
+    year: i32,
+    policy: TimezonePolicy,
+    mode: EphemerisMode,
+) -> LunarDate {
+    match policy {
+        TimezonePolicy::Fixed(time_zone) => {
+            convert_solar_to_lunar_with_mode(day, month, year, time_zone, mode)
+        }
+        TimezonePolicy::HistoricalVietnam => solar_to_lunar_with_overrides(
+            day,
+            month,
+            year,
+            policy.offset_for(day, month, year),
+            mode,
+            month_start_overrides(),
+        ),
+        TimezonePolicy::Zone(_) => {
+            solar_to_lunar(&mut PolicyMonths { policy, mode }, day, month, year)
+        }
+    }
+}
+
+/// Convert lunar date to solar date under a time zone policy
+///
+/// # Arguments
+/// * `lunar_day` - Lunar day (1-30)
+/// * `lunar_month` - Lunar month (1-12)
+/// * `lunar_year` - Lunar year
+/// * `lunar_leap` - Is leap month
+/// * `policy` - Time zone policy
+/// * `mode` - Ephemeris mode
+///
+/// # Returns
+/// Solar date, or the reason the lunar date does not exist
+pub fn convert_lunar_to_solar_with_policy(
+    lunar_day: i32,
+    lunar_month: i32,
+    lunar_year: i32,
+    lunar_leap: bool,
+    policy: TimezonePolicy,
+    mode: EphemerisMode,
+) -> Result<SolarDate, LunarConversionError> {
+    match policy {
+        TimezonePolicy::Fixed(time_zone) => convert_lunar_to_solar_with_mode(
+            lunar_day,
+            lunar_month,
+            lunar_year,
+            lunar_leap,
+            time_zone,
+            mode,
+        ),
+        TimezonePolicy::HistoricalVietnam => lunar_to_solar_with_overrides(
+            lunar_day,
+            lunar_month,
+            lunar_year,
+            lunar_leap,
+            mode,
+            month_start_overrides(),
+        ),
+        TimezonePolicy::Zone(_) => lunar_to_solar(
+            &mut PolicyMonths { policy, mode },
+            lunar_day,
+            lunar_month,
+            lunar_year,
+            lunar_leap,
+            mode,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HISTORICAL: TimezonePolicy = TimezonePolicy::HistoricalVietnam;
+    const FAST: EphemerisMode = EphemerisMode::Fast;
+
+    fn lunar(day: i32, month: i32, year: i32) -> LunarDate {
+        LunarDate {
+            day,
+            month,
+            year,
+            is_leap: false,
+        }
+    }
+
+    #[test]
+    fn test_offset_switches_on_adoption_date() {
+        assert_eq!(HISTORICAL.offset_for(7, 8, 1967), 8.0);
+        assert_eq!(HISTORICAL.offset_for(8, 8, 1967), 7.0);
+        assert_eq!(TimezonePolicy::Fixed(7.0).offset_for(1, 1, 1950), 7.0);
+    }
+
+    #[test]
+    fn test_pre_1967_dates_use_utc8() {
+        // New moon of 1956-11-02 16:43 UTC: 2 Nov at UTC+7, 3 Nov at UTC+8
+        assert_eq!(
+            convert_solar_to_lunar_with_policy(3, 11, 1956, HISTORICAL, FAST),
+            lunar(1, 10, 1956)
+        );
+        assert_eq!(
+            convert_solar_to_lunar_with_policy(3, 11, 1956, TimezonePolicy::Fixed(7.0), FAST),
+            lunar(2, 10, 1956)
+        );
+        assert_eq!(
+            convert_lunar_to_solar_with_policy(1, 10, 1956, false, HISTORICAL, FAST),
+            Ok(SolarDate {
+                day: 3,
+                month: 11,
+                year: 1956
+            })
+        );
+    }
+
+    #[test]
+    fn test_missing_months_report_the_right_error() {
+        assert_eq!(
+            convert_lunar_to_solar_with_policy(1, 5, 1956, true, HISTORICAL, FAST),
+            Err(LunarConversionError::NoSuchLeapMonth {
+                month: 5,
+                year: 1956
+            })
+        );
+        assert_eq!(
+            convert_lunar_to_solar_with_policy(1, 13, 1956, false, HISTORICAL, FAST),
+            Err(LunarConversionError::InvalidMonth(13))
+        );
+    }
+
+    #[test]
+    fn test_tet_mau_than_1968() {
+        // The North's calendar moved to UTC+7 in 1967; the South (UTC+8)
+        // celebrated one day later
+        assert_eq!(
+            convert_solar_to_lunar_with_policy(29, 1, 1968, HISTORICAL, FAST),
+            lunar(1, 1, 1968)
+        );
+        assert_eq!(
+            convert_solar_to_lunar_with_policy(29, 1, 1968, TimezonePolicy::Fixed(8.0), FAST),
+            lunar(30, 12, 1967)
+        );
+    }
+
+    #[test]
+    fn test_override_wins_over_computed_start() {
+        // Synthetic override: move 1/10/1956 back one day
+        let overrides = [MonthStartOverride {
+            lunar_year: 1956,
+            lunar_month: 10,
+            is_leap: false,
+            start: SolarDate {
+                day: 2,
+                month: 11,
+                year: 1956,
+            },
+            region: "north",
+            source: "test",
+        }];
+        assert_eq!(
+            solar_to_lunar_with_overrides(2, 11, 1956, 8.0, FAST, &overrides),
+            lunar(1, 10, 1956)
+        );
+        // Days before the official start are unchanged
+        assert_eq!(
+            solar_to_lunar_with_overrides(1, 11, 1956, 8.0, FAST, &overrides),
+            convert_solar_to_lunar_with_mode(1, 11, 1956, 8.0, FAST)
+        );
+        assert_eq!(
+            lunar_to_solar_with_overrides(1, 10, 1956, false, FAST, &overrides),
+            Ok(SolarDate {
+                day: 2,
+                month: 11,
+                year: 1956
+            })
+        );
+    }
+
+    #[test]
+    fn test_zone_policy_matches_fixed_offset() {
+        let zone = TimezonePolicy::Zone(TimeZone::VIETNAM);
+        assert_eq!(zone.offset_for(10, 2, 2024), 7.0);
+        assert_eq!(
+            convert_solar_to_lunar_with_policy(10, 2, 2024, zone, FAST),
+            lunar(1, 1, 2024)
+        );
+        assert_eq!(
+            convert_lunar_to_solar_with_policy(1, 1, 2024, false, zone, FAST),
+            convert_lunar_to_solar_with_policy(1, 1, 2024, false, TimezonePolicy::Fixed(7.0), FAST)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tz")]
+    fn test_zone_policy_resolves_daylight_saving_offset() {
+        // 1/3/2024 starts on 8 April in California (PDT, UTC-7)
+        let los_angeles = TimezonePolicy::Zone("America/Los_Angeles".parse().unwrap());
+        assert_eq!(los_angeles.offset_for(8, 4, 2024), -7.0);
+        assert_eq!(
+            convert_lunar_to_solar_with_policy(1, 3, 2024, false, los_angeles, FAST),
+            Ok(SolarDate {
+                day: 8,
+                month: 4,
+                year: 2024
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tz")]
+    fn test_zone_policy_counts_days_across_daylight_saving() {
+        // Sydney (DST ends in April, starts in October) and Los Angeles
+        // (leap month 9 of 2033 spans the November change)
+        for (zone, year) in [("Australia/Sydney", 2001), ("America/Los_Angeles", 2033)] {
+            let policy = TimezonePolicy::Zone(zone.parse().unwrap());
+            let start = jd_from_date(1, 1, year);
+            let mut previous: Option<LunarDate> = None;
+            for jd in start..start + 366 {
+                let (day, month, year) = jd_to_date(jd);
+                let lunar = convert_solar_to_lunar_with_policy(day, month, year, policy, FAST);
+                if let Some(previous) = previous {
+                    if lunar.day == 1 {
+                        assert!(
+                            previous.day == 29 || previous.day == 30,
+                            "{zone} {year}-{month}-{day}: month after {previous}"
+                        );
+                    } else {
+                        assert_eq!(
+                            lunar.day,
+                            previous.day + 1,
+                            "{zone} {year}-{month}-{day}: {lunar} after {previous}"
+                        );
+                        assert_eq!(
+                            (lunar.month, lunar.is_leap),
+                            (previous.month, previous.is_leap)
+                        );
+                    }
+                }
+                let solar = convert_lunar_to_solar_with_policy(
+                    lunar.day,
+                    lunar.month,
+                    lunar.year,
+                    lunar.is_leap,
+                    policy,
+                    FAST,
+                );
+                assert_eq!(solar, Ok(SolarDate { day, month, year }));
+                previous = Some(lunar);
+            }
+        }
+    }
+
+    #[test]
+    fn test_override_table_is_valid() {
+        for o in month_start_overrides() {
+            assert!((1..=12).contains(&o.lunar_month));
+            assert!(!o.source.trim().is_empty());
+            // Start dates must be real dates (no 1968-02-30)
+            let (d, m, y) = jd_to_date(jd_from_date(o.start.day, o.start.month, o.start.year));
+            assert_eq!((d, m, y), (o.start.day, o.start.month, o.start.year));
+            // An override must change the result of the policy
+            let time_zone = HISTORICAL.offset_for(o.start.day, o.start.month, o.start.year);
+            let computed = convert_lunar_to_solar_with_mode(
+                1,
+                o.lunar_month,
+                o.lunar_year,
+                o.is_leap,
+                time_zone,
+                EphemerisMode::Accurate,
+            );
+            assert_ne!(computed, Ok(o.start), "{o:?} is already computed");
+        }
+    }
+}
@@ -1,8 +1,11 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use once_cell::race::OnceBox;
 use serde::Deserialize;
-use std::sync::OnceLock;
 
-const SOLAR_HOLIDAYS_JSON: &str = include_str!("../data/holidays/solar-holidays.json");
-const LUNAR_FESTIVALS_JSON: &str = include_str!("../data/holidays/lunar-festivals.json");
+use crate::static_data::{self, LUNAR_FESTIVALS, SOLAR_HOLIDAYS};
 
 #[derive(Debug, Deserialize)]
 struct SolarHolidaysFile {
@@ -112,25 +115,25 @@ pub struct Regions {
     pub south: BilingualText,
 }
 
-static SOLAR_HOLIDAYS: OnceLock<Vec<SolarHolidayData>> = OnceLock::new();
-static LUNAR_FESTIVALS: OnceLock<Vec<LunarFestivalData>> = OnceLock::new();
+static SOLAR_HOLIDAY_DATA: OnceBox<Vec<SolarHolidayData>> = OnceBox::new();
+static LUNAR_FESTIVAL_DATA: OnceBox<Vec<LunarFestivalData>> = OnceBox::new();
 
 pub fn solar_holidays() -> &'static [SolarHolidayData] {
-    SOLAR_HOLIDAYS
+    SOLAR_HOLIDAY_DATA
         .get_or_init(|| {
-            let parsed: SolarHolidaysFile = serde_json::from_str(SOLAR_HOLIDAYS_JSON)
-                .expect("Failed to parse data/holidays/solar-holidays.json");
-            parsed.holidays
+            let parsed: SolarHolidaysFile =
+                static_data::load(&SOLAR_HOLIDAYS, "data/holidays/solar-holidays.json");
+            Box::new(parsed.holidays)
         })
         .as_slice()
 }
 
 pub fn lunar_festivals() -> &'static [LunarFestivalData] {
-    LUNAR_FESTIVALS
+    LUNAR_FESTIVAL_DATA
         .get_or_init(|| {
-            let parsed: LunarFestivalsFile = serde_json::from_str(LUNAR_FESTIVALS_JSON)
-                .expect("Failed to parse data/holidays/lunar-festivals.json");
-            parsed.festivals
+            let parsed: LunarFestivalsFile =
+                static_data::load(&LUNAR_FESTIVALS, "data/holidays/lunar-festivals.json");
+            Box::new(parsed.festivals)
         })
         .as_slice()
 }
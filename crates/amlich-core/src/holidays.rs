@@ -1,17 +1,23 @@
-use crate::holiday_data::{lunar_festivals, solar_holidays};
+use alloc::format;
 /**
  * Vietnamese Holidays Module
  *
  * Provides functions to get Vietnamese lunar holidays for a given year.
  * Holiday data is loaded from shared JSON files at compile time.
  */
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::eclipse::get_eclipses_for_year;
+use crate::holiday_data::{lunar_festivals, solar_holidays};
 use crate::julian::{jd_from_date, jd_to_date};
-use crate::lunar::{convert_lunar_to_solar, LunarDate};
+use crate::lunar::{convert_lunar_to_solar, LunarConversionError, LunarDate};
 use crate::tietkhi::get_all_tiet_khi_for_year;
 use crate::types::VIETNAM_TIMEZONE;
 
 /// Information about a Vietnamese holiday
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Holiday {
     pub name: String,
     pub description: String,
@@ -36,33 +42,42 @@ struct LunarHolidayInput<'a> {
 
 // Helper function to create a lunar holiday
 fn create_lunar_holiday(input: LunarHolidayInput<'_>, time_zone: f64) -> Option<Holiday> {
-    let solar = convert_lunar_to_solar(
-        input.lunar_day,
-        input.lunar_month,
-        input.lunar_year,
-        false,
-        time_zone,
-    );
-    if solar.0 > 0 {
-        Some(Holiday {
-            name: input.name.to_string(),
-            description: input.description.to_string(),
-            lunar_date: Some(LunarDate {
-                day: input.lunar_day,
-                month: input.lunar_month,
-                year: input.lunar_year,
-                is_leap: false,
-            }),
-            solar_day: solar.0,
-            solar_month: solar.1,
-            solar_year: solar.2,
-            is_solar: false,
-            category: input.category.to_string(),
-            is_major: input.is_major,
-        })
-    } else {
-        None
-    }
+    let convert = |lunar_day| {
+        convert_lunar_to_solar(
+            lunar_day,
+            input.lunar_month,
+            input.lunar_year,
+            false,
+            time_zone,
+        )
+    };
+
+    // Day-30 observances (Giao Thừa) fall on the last day of a 29-day month
+    let (lunar_day, solar) = match convert(input.lunar_day) {
+        Ok(solar) => (input.lunar_day, solar),
+        Err(LunarConversionError::DayOutOfRange {
+            day: 30,
+            month_length,
+        }) => (month_length, convert(month_length).ok()?),
+        Err(_) => return None,
+    };
+
+    Some(Holiday {
+        name: input.name.to_string(),
+        description: input.description.to_string(),
+        lunar_date: Some(LunarDate {
+            day: lunar_day,
+            month: input.lunar_month,
+            year: input.lunar_year,
+            is_leap: false,
+        }),
+        solar_day: solar.day,
+        solar_month: solar.month,
+        solar_year: solar.year,
+        is_solar: false,
+        category: input.category.to_string(),
+        is_major: input.is_major,
+    })
 }
 
 fn nth_weekday_of_month(year: i32, month: i32, weekday: usize, nth: i32) -> (i32, i32, i32) {
@@ -210,6 +225,24 @@ pub fn get_vietnamese_holidays(solar_year: i32) -> Vec<Holiday> {
         }
     }
 
+    // Nhật thực / Nguyệt thực, as printed in traditional almanacs
+    for eclipse in get_eclipses_for_year(solar_year, time_zone) {
+        holidays.push(Holiday {
+            name: eclipse.name,
+            description: format!(
+                "{}, maximum {:02}:{:02}",
+                eclipse.description, eclipse.local.hour, eclipse.local.minute
+            ),
+            lunar_date: Some(eclipse.lunar_date),
+            solar_day: eclipse.local.day,
+            solar_month: eclipse.local.month,
+            solar_year: eclipse.local.year,
+            is_solar: true,
+            category: "astronomy".to_string(),
+            is_major: false,
+        });
+    }
+
     // Sort by date
     holidays.sort_by(|a, b| {
         let date_a = (a.solar_year, a.solar_month, a.solar_day);
@@ -344,4 +377,39 @@ mod tests {
         assert_eq!(father_day.solar_day, 16);
         assert_eq!(father_day.solar_month, 6);
     }
+
+    #[test]
+    fn test_giao_thua_on_short_month() {
+        // Tháng Chạp of 2023 has 30 days: Giao Thừa is 30/12 (2024-02-09)
+        let holidays = get_vietnamese_holidays(2024);
+        let giao_thua = holidays.iter().find(|h| h.name == "Giao Thừa").unwrap();
+        assert_eq!((giao_thua.solar_day, giao_thua.solar_month), (9, 2));
+        assert_eq!(giao_thua.lunar_date.as_ref().unwrap().day, 30);
+
+        // Tháng Chạp of 2024 has 29 days: Giao Thừa moves to 29/12 (2025-01-28)
+        let holidays = get_vietnamese_holidays(2025);
+        let giao_thua = holidays.iter().find(|h| h.name == "Giao Thừa").unwrap();
+        assert_eq!((giao_thua.solar_day, giao_thua.solar_month), (28, 1));
+        assert_eq!(giao_thua.lunar_date.as_ref().unwrap().day, 29);
+    }
+
+    #[test]
+    fn test_eclipses_listed() {
+        let holidays = get_vietnamese_holidays(2024);
+        let eclipses: Vec<&Holiday> = holidays
+            .iter()
+            .filter(|h| h.category == "astronomy")
+            .collect();
+        assert_eq!(eclipses.len(), 4);
+
+        let total = eclipses
+            .iter()
+            .find(|h| h.name == "Nhật thực toàn phần")
+            .unwrap();
+        assert_eq!((total.solar_day, total.solar_month), (9, 4));
+        assert_eq!(total.lunar_date.as_ref().unwrap().day, 1);
+        assert!(!get_major_holidays(2024)
+            .iter()
+            .any(|h| h.category == "astronomy"));
+    }
 }
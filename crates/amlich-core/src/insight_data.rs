@@ -1,9 +1,12 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use once_cell::race::OnceBox;
 use serde::Deserialize;
-use std::collections::HashMap;
-use std::sync::OnceLock;
 
-const CANCHI_JSON: &str = include_str!("../data/canchi.json");
-const TIET_KHI_JSON: &str = include_str!("../data/tiet-khi.json");
+use crate::static_data::{self, CANCHI, TIET_KHI};
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct BilingualText {
@@ -52,8 +55,8 @@ pub struct DayGuidance {
 struct CanChiFile {
     can: Vec<CanInfo>,
     chi: Vec<ChiInfo>,
-    elements: HashMap<String, ElementInfo>,
-    day_guidance: HashMap<String, DayGuidance>,
+    elements: BTreeMap<String, ElementInfo>,
+    day_guidance: BTreeMap<String, DayGuidance>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -74,19 +77,15 @@ struct TietKhiFile {
     tiet_khi: Vec<TietKhiInsight>,
 }
 
-static CANCHI_DATA: OnceLock<CanChiFile> = OnceLock::new();
-static TIET_KHI_DATA: OnceLock<TietKhiFile> = OnceLock::new();
+static CANCHI_DATA: OnceBox<CanChiFile> = OnceBox::new();
+static TIET_KHI_DATA: OnceBox<TietKhiFile> = OnceBox::new();
 
 fn canchi_data() -> &'static CanChiFile {
-    CANCHI_DATA.get_or_init(|| {
-        serde_json::from_str(CANCHI_JSON).expect("Failed to parse data/canchi.json")
-    })
+    CANCHI_DATA.get_or_init(|| Box::new(static_data::load(&CANCHI, "data/canchi.json")))
 }
 
 fn tiet_khi_data() -> &'static TietKhiFile {
-    TIET_KHI_DATA.get_or_init(|| {
-        serde_json::from_str(TIET_KHI_JSON).expect("Failed to parse data/tiet-khi.json")
-    })
+    TIET_KHI_DATA.get_or_init(|| Box::new(static_data::load(&TIET_KHI, "data/tiet-khi.json")))
 }
 
 pub fn all_can() -> &'static [CanInfo] {
@@ -97,11 +96,11 @@ pub fn all_chi() -> &'static [ChiInfo] {
     &canchi_data().chi
 }
 
-pub fn all_elements() -> &'static HashMap<String, ElementInfo> {
+pub fn all_elements() -> &'static BTreeMap<String, ElementInfo> {
     &canchi_data().elements
 }
 
-pub fn all_day_guidance() -> &'static HashMap<String, DayGuidance> {
+pub fn all_day_guidance() -> &'static BTreeMap<String, DayGuidance> {
     &canchi_data().day_guidance
 }
 
@@ -3,9 +3,19 @@
 //! Formula from <http://www.tondering.dk/claus/calendar.html>
 //! Based on algorithms from "Astronomical Algorithms" by Jean Meeus, 1998
 
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+
+/// Julian day number of 1582-10-15, the first day of the Gregorian calendar
+pub const GREGORIAN_REFORM_JD: i32 = 2299161;
+
 /// Compute the (integral) Julian day number of day dd/mm/yyyy
 ///
 /// This is the number of days between 1/1/4713 BC (Julian calendar) and dd/mm/yyyy.
+/// Dates from 1582-10-15 are Gregorian and earlier ones Julian, as in
+/// historical sources; `is_valid_date` rejects the ten days skipped by the
+/// reform. Use `jd_from_gregorian` for proleptic Gregorian dates (ISO 8601,
+/// chrono).
 ///
 /// # Arguments
 /// * `day` - Day of month (1-31)
@@ -15,18 +25,39 @@
 /// # Returns
 /// Julian day number as i32
 pub fn jd_from_date(day: i32, month: i32, year: i32) -> i32 {
+    let jd = jd_from_gregorian(day, month, year);
+    if jd < GREGORIAN_REFORM_JD {
+        jd_from_julian(day, month, year)
+    } else {
+        jd
+    }
+}
+
+/// Julian day number of a proleptic Gregorian date
+pub fn jd_from_gregorian(day: i32, month: i32, year: i32) -> i32 {
     let a = (14 - month) / 12;
     let y = year + 4800 - a;
     let m = month + 12 * a - 3;
+    day + (153 * m + 2) / 5 + 365 * y + y / 4 - y / 100 + y / 400 - 32045
+}
 
-    let mut jd = day + (153 * m + 2) / 5 + 365 * y + y / 4 - y / 100 + y / 400 - 32045;
-
-    // Before October 15, 1582 (Julian calendar)
-    if jd < 2299161 {
-        jd = day + (153 * m + 2) / 5 + 365 * y + y / 4 - 32083;
-    }
+/// Julian day number of a (proleptic) Julian calendar date
+pub fn jd_from_julian(day: i32, month: i32, year: i32) -> i32 {
+    let a = (14 - month) / 12;
+    let y = year + 4800 - a;
+    let m = month + 12 * a - 3;
+    day + (153 * m + 2) / 5 + 365 * y + y / 4 - 32083
+}
 
-    jd
+/// True if dd/mm/yyyy exists in the calendar used by `jd_from_date`
+///
+/// Rejects day 31 of short months, 29 February outside leap years (every
+/// fourth year before the reform, Gregorian rules after) and 1582-10-05
+/// to 1582-10-14.
+pub fn is_valid_date(day: i32, month: i32, year: i32) -> bool {
+    (1..=12).contains(&month)
+        && (1..=31).contains(&day)
+        && jd_to_date(jd_from_date(day, month, year)) == (day, month, year)
 }
 
 /// Convert a Julian day number to day/month/year
@@ -62,6 +93,43 @@ pub fn jd_to_date(jd: i32) -> (i32, i32, i32) {
     (day, month, year)
 }
 
+/// Calendar date and wall-clock time, to the minute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JulianDateTime {
+    pub day: i32,
+    pub month: i32,
+    pub year: i32,
+    pub hour: i32,
+    pub minute: i32,
+}
+
+/// Convert a fractional Julian day to a date and time in the given timezone
+///
+/// The instant is rounded to the nearest minute before splitting it into
+/// calendar fields, so 23:59:40 becomes 00:00 of the next day.
+///
+/// # Arguments
+/// * `jd` - Julian day (fractional, UT)
+/// * `time_zone` - Timezone offset in hours (0.0 for UTC)
+///
+/// # Returns
+/// Local calendar date and time
+pub fn jd_to_datetime(jd: f64, time_zone: f64) -> JulianDateTime {
+    let total_minutes = ((jd + 0.5 + time_zone / 24.0) * 1440.0).round() as i64;
+    let day_number = total_minutes.div_euclid(1440) as i32;
+    let minute_of_day = total_minutes.rem_euclid(1440) as i32;
+    let (day, month, year) = jd_to_date(day_number);
+
+    JulianDateTime {
+        day,
+        month,
+        year,
+        hour: minute_of_day / 60,
+        minute: minute_of_day % 60,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,6 +195,54 @@ mod tests {
 
         // Days before should use Julian calendar
         let jd_before = jd_from_date(4, 10, 1582);
-        assert!(jd_before < 2299161);
+        assert_eq!(jd_before, GREGORIAN_REFORM_JD - 1);
+        assert_eq!(jd_from_gregorian(4, 10, 1582), GREGORIAN_REFORM_JD - 11);
+        assert_eq!(jd_from_julian(15, 10, 1582), GREGORIAN_REFORM_JD + 10);
+    }
+
+    #[test]
+    fn test_valid_dates_across_the_reform() {
+        assert!(is_valid_date(4, 10, 1582));
+        assert!(is_valid_date(15, 10, 1582));
+        for day in 5..=14 {
+            assert!(!is_valid_date(day, 10, 1582), "1582-10-{day}");
+        }
+        // 1500 is a Julian leap year, 1900 is not a Gregorian one
+        assert!(is_valid_date(29, 2, 1500));
+        assert!(!is_valid_date(29, 2, 1900));
+        assert!(is_valid_date(29, 2, 2024));
+        assert!(!is_valid_date(31, 4, 2024));
+        assert!(!is_valid_date(0, 1, 2024));
+        assert!(!is_valid_date(1, 13, 2024));
+
+        // 1450-03-01 (Julian) is 1450-03-10 proleptic Gregorian
+        assert_eq!(jd_from_date(1, 3, 1450), jd_from_gregorian(10, 3, 1450));
+    }
+
+    #[test]
+    fn test_jd_to_datetime() {
+        // J2000.0 epoch: 2000-01-01 12:00 UT
+        let utc = jd_to_datetime(2451545.0, 0.0);
+        assert_eq!(
+            utc,
+            JulianDateTime {
+                day: 1,
+                month: 1,
+                year: 2000,
+                hour: 12,
+                minute: 0,
+            }
+        );
+
+        // Same instant in Vietnam (UTC+7) is 19:00
+        let local = jd_to_datetime(2451545.0, 7.0);
+        assert_eq!((local.day, local.hour, local.minute), (1, 19, 0));
+
+        // 17:30 UT rolls over to the next local day at UTC+7
+        let next = jd_to_datetime(2451545.0 + 5.5 / 24.0, 7.0);
+        assert_eq!(
+            (next.day, next.month, next.hour, next.minute),
+            (2, 1, 0, 30)
+        );
     }
 }
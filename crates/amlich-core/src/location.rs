@@ -0,0 +1,53 @@
+//! Observer Location
+//!
+//! Geographic position used for rise/set computations.
+
+/// Geographic location of an observer
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Location {
+    /// Latitude in degrees, north positive (-90 to 90)
+    pub latitude: f64,
+    /// Longitude in degrees, east positive (-180 to 180)
+    pub longitude: f64,
+    /// Height above sea level in meters
+    pub elevation: f64,
+}
+
+impl Location {
+    /// Create a location at sea level
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+            elevation: 0.0,
+        }
+    }
+
+    /// Set the height above sea level in meters
+    pub fn with_elevation(mut self, elevation: f64) -> Self {
+        self.elevation = elevation;
+        self
+    }
+}
+
+/// Hà Nội (Hoàn Kiếm)
+pub const HANOI: Location = Location {
+    latitude: 21.0285,
+    longitude: 105.8542,
+    elevation: 0.0,
+};
+
+/// Huế
+pub const HUE: Location = Location {
+    latitude: 16.4637,
+    longitude: 107.5909,
+    elevation: 0.0,
+};
+
+/// Thành phố Hồ Chí Minh
+pub const HO_CHI_MINH_CITY: Location = Location {
+    latitude: 10.8231,
+    longitude: 106.6297,
+    elevation: 0.0,
+};
@@ -1,17 +1,31 @@
-use crate::sun::get_sun_longitude;
+use crate::ephemeris::{new_moon_with_mode, EphemerisMode, YearOutOfRange};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::sun::get_sun_longitude_with_mode;
 /**
  * Lunar Calendar Conversion
  *
  * Algorithms from "Astronomical Algorithms" by Jean Meeus, 1998
  * Based on implementation by Ho Ngoc Duc
  */
-use std::f64::consts::PI;
+#[cfg(feature = "almanac")]
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use core::cmp::Ordering;
+use core::error::Error;
+use core::f64::consts::PI;
+use core::fmt;
+use core::str::FromStr;
 
 /// Compute the time of the k-th new moon after the new moon of 1/1/1900 13:52 UCT
 ///
 /// Measured as the number of days since 1/1/4713 BC noon UCT.
 /// For example, 2415079.9758617813 for k=2 or 2414961.935157746 for k=-2
 ///
+/// This is the Fast ephemeris: the truncated series and its 1900-era ΔT
+/// term are only trusted within `MIN_SUPPORTED_YEAR..=MAX_SUPPORTED_YEAR`.
+/// Use `ephemeris::new_moon_accurate` further out.
+///
 /// # Arguments
 /// * `k` - Number of new moons after 1/1/1900 13:52 UCT
 ///
@@ -64,7 +78,20 @@ pub fn new_moon(k: i32) -> f64 {
 /// # Returns
 /// Julian day number (integer)
 pub fn get_new_moon_day(k: i32, time_zone: f64) -> i32 {
-    (new_moon(k) + 0.5 + time_zone / 24.0).floor() as i32
+    get_new_moon_day_with_mode(k, time_zone, EphemerisMode::Fast)
+}
+
+/// Compute the day of the k-th new moon with a selectable ephemeris
+///
+/// # Arguments
+/// * `k` - New moon index
+/// * `time_zone` - Time zone offset (7.0 for UTC+7:00)
+/// * `mode` - Ephemeris mode
+///
+/// # Returns
+/// Julian day number (integer)
+pub fn get_new_moon_day_with_mode(k: i32, time_zone: f64, mode: EphemerisMode) -> i32 {
+    (new_moon_with_mode(k, mode) + 0.5 + time_zone / 24.0).floor() as i32
 }
 
 /// Find the day that starts the lunar month 11 of the given year
@@ -76,15 +103,40 @@ pub fn get_new_moon_day(k: i32, time_zone: f64) -> i32 {
 /// # Returns
 /// Julian day number of lunar month 11
 pub fn get_lunar_month11(year: i32, time_zone: f64) -> i32 {
+    get_lunar_month11_with_mode(year, time_zone, EphemerisMode::Fast)
+}
+
+/// Find the day that starts the lunar month 11 with a selectable ephemeris
+///
+/// # Arguments
+/// * `year` - Solar year
+/// * `time_zone` - Time zone offset (7.0 for UTC+7:00)
+/// * `mode` - Ephemeris mode
+///
+/// # Returns
+/// Julian day number of lunar month 11
+pub fn get_lunar_month11_with_mode(year: i32, time_zone: f64, mode: EphemerisMode) -> i32 {
+    lunar_month11_from(year, mode, |k| {
+        (get_new_moon_day_with_mode(k, time_zone, mode), time_zone)
+    })
+}
+
+/// `get_lunar_month11_with_mode` over a new moon source returning each new
+/// moon's local day and the offset it was computed with
+pub(crate) fn lunar_month11_from(
+    year: i32,
+    mode: EphemerisMode,
+    mut new_moon_day: impl FnMut(i32) -> (i32, f64),
+) -> i32 {
     use crate::julian::jd_from_date;
 
     let off = jd_from_date(31, 12, year) - 2415021;
     let k = (off as f64 / 29.530588853).floor() as i32;
-    let mut nm = get_new_moon_day(k, time_zone);
-    let sun_long = get_sun_longitude(nm, time_zone);
+    let (mut nm, time_zone) = new_moon_day(k);
+    let sun_long = get_sun_longitude_with_mode(nm, time_zone, mode);
 
     if sun_long >= 9 {
-        nm = get_new_moon_day(k - 1, time_zone);
+        nm = new_moon_day(k - 1).0;
     }
 
     nm
@@ -99,15 +151,44 @@ pub fn get_lunar_month11(year: i32, time_zone: f64) -> i32 {
 /// # Returns
 /// Leap month offset (1-based)
 pub fn get_leap_month_offset(a11: i32, time_zone: f64) -> i32 {
+    get_leap_month_offset_with_mode(a11, time_zone, EphemerisMode::Fast)
+}
+
+/// Find the index of the leap month with a selectable ephemeris
+///
+/// # Arguments
+/// * `a11` - Julian day of lunar month 11
+/// * `time_zone` - Time zone offset (7.0 for UTC+7:00)
+/// * `mode` - Ephemeris mode
+///
+/// # Returns
+/// Leap month offset (1-based)
+pub fn get_leap_month_offset_with_mode(a11: i32, time_zone: f64, mode: EphemerisMode) -> i32 {
+    leap_month_offset_from(a11, mode, |k| {
+        (get_new_moon_day_with_mode(k, time_zone, mode), time_zone)
+    })
+}
+
+/// `get_leap_month_offset_with_mode` over a new moon source returning each
+/// new moon's local day and the offset it was computed with
+pub(crate) fn leap_month_offset_from(
+    a11: i32,
+    mode: EphemerisMode,
+    mut new_moon_day: impl FnMut(i32) -> (i32, f64),
+) -> i32 {
     let k = ((a11 as f64 - 2415021.076998695) / 29.530588853 + 0.5).floor() as i32;
+    let mut month_sun_longitude = |offset: i32| {
+        let (day, time_zone) = new_moon_day(k + offset);
+        get_sun_longitude_with_mode(day, time_zone, mode)
+    };
     let mut last;
     let mut i = 1; // Start with the month following lunar month 11
-    let mut arc = get_sun_longitude(get_new_moon_day(k + i, time_zone), time_zone);
+    let mut arc = month_sun_longitude(i);
 
     loop {
         last = arc;
         i += 1;
-        arc = get_sun_longitude(get_new_moon_day(k + i, time_zone), time_zone);
+        arc = month_sun_longitude(i);
         if !(arc != last && i < 14) {
             break;
         }
@@ -117,7 +198,12 @@ pub fn get_leap_month_offset(a11: i32, time_zone: f64) -> i32 {
 }
 
 /// Lunar date representation
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Dates order by year, month, leap flag and day, so a leap month sorts
+/// right after the regular month of the same number. Displays (and parses)
+/// as `day/month/year`, with a ` (nhuận)` suffix in a leap month.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LunarDate {
     pub day: i32,
     pub month: i32,
@@ -125,6 +211,148 @@ pub struct LunarDate {
     pub is_leap: bool,
 }
 
+impl Ord for LunarDate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.year, self.month, self.is_leap, self.day).cmp(&(
+            other.year,
+            other.month,
+            other.is_leap,
+            other.day,
+        ))
+    }
+}
+
+impl PartialOrd for LunarDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+const LEAP_SUFFIX: &str = " (nhuận)";
+
+impl fmt::Display for LunarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.day, self.month, self.year)?;
+        if self.is_leap {
+            f.write_str(LEAP_SUFFIX)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when text is not a `day/month/year` lunar date
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLunarDateError {
+    value: String,
+}
+
+impl fmt::Display for ParseLunarDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid lunar date: {:?} (expected day/month/year, e.g. 15/8/2024 or 1/6/2025 (nhuận))",
+            self.value
+        )
+    }
+}
+
+impl Error for ParseLunarDateError {}
+
+impl FromStr for LunarDate {
+    type Err = ParseLunarDateError;
+
+    /// Parse the `Display` form; checks the ranges of day (1-30) and month
+    /// (1-12) but not whether the date exists
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseLunarDateError {
+            value: s.to_string(),
+        };
+        let text = s.trim();
+        let (text, is_leap) = match text.strip_suffix(LEAP_SUFFIX.trim_start()) {
+            Some(rest) => (rest.trim_end(), true),
+            None => (text, false),
+        };
+        let mut parts = text.split('/').map(|part| part.trim().parse::<i32>());
+        let (Some(Ok(day)), Some(Ok(month)), Some(Ok(year)), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(err());
+        };
+        if !(1..=30).contains(&day) || !(1..=12).contains(&month) {
+            return Err(err());
+        }
+        Ok(LunarDate {
+            day,
+            month,
+            year,
+            is_leap,
+        })
+    }
+}
+
+/// Solar calendar date (Gregorian from 1582-10-15, Julian before)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolarDate {
+    pub day: i32,
+    pub month: i32,
+    pub year: i32,
+}
+
+/// First year supported by the Fast ephemeris (and `convert_lunar_to_solar`)
+pub const MIN_SUPPORTED_YEAR: i32 = 1800;
+
+/// Last year supported by the Fast ephemeris (and `convert_lunar_to_solar`)
+pub const MAX_SUPPORTED_YEAR: i32 = 2199;
+
+/// Reasons a lunar date cannot be converted to a solar date
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LunarConversionError {
+    /// The lunar year has no leap month with this number
+    NoSuchLeapMonth { month: i32, year: i32 },
+    /// The day does not exist in the month (e.g. day 30 of a 29-day month)
+    DayOutOfRange { day: i32, month_length: i32 },
+    /// The month number is outside 1-12
+    InvalidMonth(i32),
+    /// The lunar year is outside the ephemeris mode's supported years
+    YearOutOfRange { year: i32, min: i32, max: i32 },
+}
+
+impl From<YearOutOfRange> for LunarConversionError {
+    fn from(err: YearOutOfRange) -> Self {
+        Self::YearOutOfRange {
+            year: err.year,
+            min: err.min(),
+            max: err.max(),
+        }
+    }
+}
+
+impl fmt::Display for LunarConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSuchLeapMonth { month, year } => {
+                write!(f, "lunar year {year} has no leap month {month}")
+            }
+            Self::DayOutOfRange { day, month_length } => {
+                write!(
+                    f,
+                    "lunar day {day} does not exist in a {month_length}-day month"
+                )
+            }
+            Self::InvalidMonth(month) => write!(f, "invalid lunar month: {month}"),
+            Self::YearOutOfRange { year, min, max } => {
+                write!(
+                    f,
+                    "lunar year {year} is outside the supported range {min}-{max}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for LunarConversionError {}
+
 /// Convert solar date to lunar date
 ///
 /// # Arguments
@@ -136,26 +364,170 @@ pub struct LunarDate {
 /// # Returns
 /// Lunar date
 pub fn convert_solar_to_lunar(day: i32, month: i32, year: i32, time_zone: f64) -> LunarDate {
+    convert_solar_to_lunar_with_mode(day, month, year, time_zone, EphemerisMode::Fast)
+}
+
+/// Convert solar date to lunar date with a selectable ephemeris
+///
+/// # Arguments
+/// * `day` - Solar day (1-31)
+/// * `month` - Solar month (1-12)
+/// * `year` - Solar year
+/// * `time_zone` - Time zone offset (7.0 for UTC+7:00)
+/// * `mode` - Ephemeris mode
+///
+/// # Returns
+/// Lunar date
+pub fn convert_solar_to_lunar_with_mode(
+    day: i32,
+    month: i32,
+    year: i32,
+    time_zone: f64,
+    mode: EphemerisMode,
+) -> LunarDate {
+    solar_to_lunar(&mut FixedOffset { time_zone, mode }, day, month, year)
+}
+
+/// Convert solar date to lunar date, rejecting years outside the mode's range
+///
+/// `convert_solar_to_lunar_with_mode` answers for any year, but far from
+/// the supported range the answer is plausible-looking and wrong.
+///
+/// # Arguments
+/// * `day` - Solar day (1-31)
+/// * `month` - Solar month (1-12)
+/// * `year` - Solar year (Julian calendar before 1582-10-15)
+/// * `time_zone` - Time zone offset (7.0 for UTC+7:00)
+/// * `mode` - Ephemeris mode
+///
+/// # Returns
+/// Lunar date, or the supported range the year falls outside of
+pub fn convert_solar_to_lunar_checked(
+    day: i32,
+    month: i32,
+    year: i32,
+    time_zone: f64,
+    mode: EphemerisMode,
+) -> Result<LunarDate, YearOutOfRange> {
+    mode.check_year(year)?;
+    Ok(convert_solar_to_lunar_with_mode(
+        day, month, year, time_zone, mode,
+    ))
+}
+
+/// Month boundary lookups used by the solar/lunar conversions
+pub(crate) trait MonthBoundaries {
+    fn new_moon_day(&mut self, k: i32) -> i32;
+    fn lunar_month11(&mut self, year: i32) -> i32;
+    fn leap_month_offset(&mut self, a11: i32) -> i32;
+}
+
+/// Month boundaries at one fixed offset, computed directly
+struct FixedOffset {
+    time_zone: f64,
+    mode: EphemerisMode,
+}
+
+impl MonthBoundaries for FixedOffset {
+    fn new_moon_day(&mut self, k: i32) -> i32 {
+        get_new_moon_day_with_mode(k, self.time_zone, self.mode)
+    }
+
+    fn lunar_month11(&mut self, year: i32) -> i32 {
+        get_lunar_month11_with_mode(year, self.time_zone, self.mode)
+    }
+
+    fn leap_month_offset(&mut self, a11: i32) -> i32 {
+        get_leap_month_offset_with_mode(a11, self.time_zone, self.mode)
+    }
+}
+
+/// Memoized month boundaries for converting many dates
+///
+/// `convert_solar_to_lunar` evaluates the same new moons, month-11 starts
+/// and leap month searches for every day of a month; range APIs keep one
+/// cache so each is computed once.
+#[cfg(feature = "almanac")]
+pub(crate) struct LunarCache<B> {
+    boundaries: B,
+    new_moon_days: BTreeMap<i32, i32>,
+    month11: BTreeMap<i32, i32>,
+    leap_offsets: BTreeMap<i32, i32>,
+}
+
+#[cfg(feature = "almanac")]
+impl<B: MonthBoundaries> LunarCache<B> {
+    pub(crate) fn with_boundaries(boundaries: B) -> Self {
+        Self {
+            boundaries,
+            new_moon_days: BTreeMap::new(),
+            month11: BTreeMap::new(),
+            leap_offsets: BTreeMap::new(),
+        }
+    }
+
+    /// Convert solar date to lunar date (see `convert_solar_to_lunar_with_mode`)
+    pub(crate) fn solar_to_lunar(&mut self, day: i32, month: i32, year: i32) -> LunarDate {
+        solar_to_lunar(self, day, month, year)
+    }
+}
+
+#[cfg(feature = "almanac")]
+impl<B: MonthBoundaries> MonthBoundaries for LunarCache<B> {
+    fn new_moon_day(&mut self, k: i32) -> i32 {
+        *self
+            .new_moon_days
+            .entry(k)
+            .or_insert_with(|| self.boundaries.new_moon_day(k))
+    }
+
+    fn lunar_month11(&mut self, year: i32) -> i32 {
+        *self
+            .month11
+            .entry(year)
+            .or_insert_with(|| self.boundaries.lunar_month11(year))
+    }
+
+    fn leap_month_offset(&mut self, a11: i32) -> i32 {
+        *self
+            .leap_offsets
+            .entry(a11)
+            .or_insert_with(|| self.boundaries.leap_month_offset(a11))
+    }
+}
+
+/// Convert solar date to lunar date from the given month boundaries
+pub(crate) fn solar_to_lunar<B: MonthBoundaries>(
+    boundaries: &mut B,
+    day: i32,
+    month: i32,
+    year: i32,
+) -> LunarDate {
     use crate::julian::jd_from_date;
 
     let day_number = jd_from_date(day, month, year);
     let k = ((day_number as f64 - 2415021.076998695) / 29.530588853).floor() as i32;
-    let mut month_start = get_new_moon_day(k + 1, time_zone);
+    let mut month_start = boundaries.new_moon_day(k + 1);
 
     if month_start > day_number {
-        month_start = get_new_moon_day(k, time_zone);
+        month_start = boundaries.new_moon_day(k);
+    }
+    // Far east of UTC+7 (e.g. UTC+10) the k-th new moon can still fall on
+    // the next local day
+    if month_start > day_number {
+        month_start = boundaries.new_moon_day(k - 1);
     }
 
-    let mut a11 = get_lunar_month11(year, time_zone);
+    let mut a11 = boundaries.lunar_month11(year);
     let mut b11 = a11;
 
     let mut lunar_year: i32;
     if a11 >= month_start {
         lunar_year = year;
-        a11 = get_lunar_month11(year - 1, time_zone);
+        a11 = boundaries.lunar_month11(year - 1);
     } else {
         lunar_year = year + 1;
-        b11 = get_lunar_month11(year + 1, time_zone);
+        b11 = boundaries.lunar_month11(year + 1);
     }
 
     let lunar_day = day_number - month_start + 1;
@@ -164,7 +536,7 @@ pub fn convert_solar_to_lunar(day: i32, month: i32, year: i32, time_zone: f64) -
     let mut lunar_month = diff + 11;
 
     if b11 - a11 > 365 {
-        let leap_month_diff = get_leap_month_offset(a11, time_zone);
+        let leap_month_diff = boundaries.leap_month_offset(a11);
         if diff >= leap_month_diff {
             lunar_month = diff + 10;
             if diff == leap_month_diff {
@@ -199,25 +571,79 @@ pub fn convert_solar_to_lunar(day: i32, month: i32, year: i32, time_zone: f64) -
 /// * `time_zone` - Time zone offset (7.0 for UTC+7:00)
 ///
 /// # Returns
-/// Tuple of (day, month, year) or (0, 0, 0) if invalid
+/// Solar date, or the reason the lunar date does not exist
 pub fn convert_lunar_to_solar(
     lunar_day: i32,
     lunar_month: i32,
     lunar_year: i32,
     lunar_leap: bool,
     time_zone: f64,
-) -> (i32, i32, i32) {
+) -> Result<SolarDate, LunarConversionError> {
+    convert_lunar_to_solar_with_mode(
+        lunar_day,
+        lunar_month,
+        lunar_year,
+        lunar_leap,
+        time_zone,
+        EphemerisMode::Fast,
+    )
+}
+
+/// Convert lunar date to solar date with a selectable ephemeris
+///
+/// # Arguments
+/// * `lunar_day` - Lunar day (1-30)
+/// * `lunar_month` - Lunar month (1-12)
+/// * `lunar_year` - Lunar year
+/// * `lunar_leap` - Is leap month
+/// * `time_zone` - Time zone offset (7.0 for UTC+7:00)
+/// * `mode` - Ephemeris mode
+///
+/// # Returns
+/// Solar date, or the reason the lunar date does not exist
+pub fn convert_lunar_to_solar_with_mode(
+    lunar_day: i32,
+    lunar_month: i32,
+    lunar_year: i32,
+    lunar_leap: bool,
+    time_zone: f64,
+    mode: EphemerisMode,
+) -> Result<SolarDate, LunarConversionError> {
+    lunar_to_solar(
+        &mut FixedOffset { time_zone, mode },
+        lunar_day,
+        lunar_month,
+        lunar_year,
+        lunar_leap,
+        mode,
+    )
+}
+
+/// Convert lunar date to solar date from the given month boundaries
+pub(crate) fn lunar_to_solar<B: MonthBoundaries>(
+    boundaries: &mut B,
+    lunar_day: i32,
+    lunar_month: i32,
+    lunar_year: i32,
+    lunar_leap: bool,
+    mode: EphemerisMode,
+) -> Result<SolarDate, LunarConversionError> {
     use crate::julian::jd_to_date;
 
+    mode.check_year(lunar_year)?;
+    if !(1..=12).contains(&lunar_month) {
+        return Err(LunarConversionError::InvalidMonth(lunar_month));
+    }
+
     let a11: i32;
     let b11: i32;
 
     if lunar_month < 11 {
-        a11 = get_lunar_month11(lunar_year - 1, time_zone);
-        b11 = get_lunar_month11(lunar_year, time_zone);
+        a11 = boundaries.lunar_month11(lunar_year - 1);
+        b11 = boundaries.lunar_month11(lunar_year);
     } else {
-        a11 = get_lunar_month11(lunar_year, time_zone);
-        b11 = get_lunar_month11(lunar_year + 1, time_zone);
+        a11 = boundaries.lunar_month11(lunar_year);
+        b11 = boundaries.lunar_month11(lunar_year + 1);
     }
 
     let k = (0.5 + (a11 as f64 - 2415021.076998695) / 29.530588853).floor() as i32;
@@ -228,7 +654,7 @@ pub fn convert_lunar_to_solar(
     }
 
     if b11 - a11 > 365 {
-        let leap_off = get_leap_month_offset(a11, time_zone);
+        let leap_off = boundaries.leap_month_offset(a11);
         let mut leap_month = leap_off - 2;
 
         if leap_month < 0 {
@@ -236,14 +662,31 @@ pub fn convert_lunar_to_solar(
         }
 
         if lunar_leap && lunar_month != leap_month {
-            return (0, 0, 0);
+            return Err(LunarConversionError::NoSuchLeapMonth {
+                month: lunar_month,
+                year: lunar_year,
+            });
         } else if lunar_leap || off >= leap_off {
             off += 1;
         }
+    } else if lunar_leap {
+        return Err(LunarConversionError::NoSuchLeapMonth {
+            month: lunar_month,
+            year: lunar_year,
+        });
     }
 
-    let month_start = get_new_moon_day(k + off, time_zone);
-    jd_to_date(month_start + lunar_day - 1)
+    let month_start = boundaries.new_moon_day(k + off);
+    let month_length = boundaries.new_moon_day(k + off + 1) - month_start;
+    if lunar_day < 1 || lunar_day > month_length {
+        return Err(LunarConversionError::DayOutOfRange {
+            day: lunar_day,
+            month_length,
+        });
+    }
+
+    let (day, month, year) = jd_to_date(month_start + lunar_day - 1);
+    Ok(SolarDate { day, month, year })
 }
 
 #[cfg(test)]
@@ -304,15 +747,22 @@ mod tests {
     #[test]
     fn test_lunar_to_solar_tet_2024() {
         // 1/1/2024 lunar = February 10, 2024
-        let (d, m, y) = convert_lunar_to_solar(1, 1, 2024, false, 7.0);
-        assert_eq!((d, m, y), (10, 2, 2024));
+        let solar = convert_lunar_to_solar(1, 1, 2024, false, 7.0).unwrap();
+        assert_eq!(
+            solar,
+            SolarDate {
+                day: 10,
+                month: 2,
+                year: 2024
+            }
+        );
     }
 
     #[test]
     fn test_lunar_to_solar_tet_2025() {
         // 1/1/2025 lunar = January 29, 2025
-        let (d, m, y) = convert_lunar_to_solar(1, 1, 2025, false, 7.0);
-        assert_eq!((d, m, y), (29, 1, 2025));
+        let solar = convert_lunar_to_solar(1, 1, 2025, false, 7.0).unwrap();
+        assert_eq!((solar.day, solar.month, solar.year), (29, 1, 2025));
     }
 
     #[test]
@@ -327,11 +777,12 @@ mod tests {
 
         for (d, m, y) in test_dates {
             let lunar = convert_solar_to_lunar(d, m, y, 7.0);
-            let (d2, m2, y2) =
-                convert_lunar_to_solar(lunar.day, lunar.month, lunar.year, lunar.is_leap, 7.0);
+            let solar =
+                convert_lunar_to_solar(lunar.day, lunar.month, lunar.year, lunar.is_leap, 7.0)
+                    .unwrap();
             assert_eq!(
                 (d, m, y),
-                (d2, m2, y2),
+                (solar.day, solar.month, solar.year),
                 "Roundtrip failed for {}/{}/{}",
                 d,
                 m,
@@ -339,4 +790,154 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_lunar_to_solar_no_such_leap_month() {
+        // 2024 has no leap month; 2025 has leap month 6 but not 5
+        assert_eq!(
+            convert_lunar_to_solar(1, 4, 2024, true, 7.0),
+            Err(LunarConversionError::NoSuchLeapMonth {
+                month: 4,
+                year: 2024
+            })
+        );
+        assert_eq!(
+            convert_lunar_to_solar(1, 5, 2025, true, 7.0),
+            Err(LunarConversionError::NoSuchLeapMonth {
+                month: 5,
+                year: 2025
+            })
+        );
+        assert!(convert_lunar_to_solar(1, 6, 2025, true, 7.0).is_ok());
+    }
+
+    #[test]
+    fn test_lunar_to_solar_day_out_of_range() {
+        // Month 12 of Giáp Thìn (2024) has 29 days: Tết 2025 is 2025-01-29
+        let day_29 = convert_lunar_to_solar(29, 12, 2024, false, 7.0).unwrap();
+        assert_eq!((day_29.day, day_29.month, day_29.year), (28, 1, 2025));
+        assert_eq!(
+            convert_lunar_to_solar(30, 12, 2024, false, 7.0),
+            Err(LunarConversionError::DayOutOfRange {
+                day: 30,
+                month_length: 29
+            })
+        );
+        assert!(matches!(
+            convert_lunar_to_solar(0, 1, 2024, false, 7.0),
+            Err(LunarConversionError::DayOutOfRange { day: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_lunar_to_solar_invalid_month_and_year() {
+        assert_eq!(
+            convert_lunar_to_solar(1, 13, 2024, false, 7.0),
+            Err(LunarConversionError::InvalidMonth(13))
+        );
+        assert_eq!(
+            convert_lunar_to_solar(1, 1, 1500, false, 7.0),
+            Err(LunarConversionError::YearOutOfRange {
+                year: 1500,
+                min: MIN_SUPPORTED_YEAR,
+                max: MAX_SUPPORTED_YEAR
+            })
+        );
+        assert_eq!(
+            LunarConversionError::DayOutOfRange {
+                day: 30,
+                month_length: 29
+            }
+            .to_string(),
+            "lunar day 30 does not exist in a 29-day month"
+        );
+    }
+
+    #[test]
+    fn test_range_follows_ephemeris_mode() {
+        // Accurate mode reaches the 15th century (Julian calendar dates)
+        let accurate = EphemerisMode::Accurate;
+        let solar = convert_lunar_to_solar_with_mode(1, 1, 1500, false, 7.0, accurate).unwrap();
+        let lunar =
+            convert_solar_to_lunar_checked(solar.day, solar.month, solar.year, 7.0, accurate)
+                .unwrap();
+        assert_eq!(lunar.to_string(), "1/1/1500");
+        assert_eq!(
+            convert_lunar_to_solar_with_mode(1, 1, 3001, false, 7.0, accurate),
+            Err(LunarConversionError::YearOutOfRange {
+                year: 3001,
+                min: 1000,
+                max: 3000
+            })
+        );
+
+        assert_eq!(
+            convert_solar_to_lunar_checked(1, 1, 1500, 7.0, EphemerisMode::Fast),
+            Err(YearOutOfRange {
+                year: 1500,
+                mode: EphemerisMode::Fast
+            })
+        );
+        assert!(convert_solar_to_lunar_checked(10, 2, 2024, 7.0, EphemerisMode::Fast).is_ok());
+    }
+
+    #[test]
+    fn test_lunar_date_order_puts_leap_month_after_regular() {
+        let date = |day, month, year, is_leap| LunarDate {
+            day,
+            month,
+            year,
+            is_leap,
+        };
+        let mut dates = vec![
+            date(1, 7, 2025, false),
+            date(29, 6, 2025, true),
+            date(30, 6, 2025, false),
+            date(1, 1, 2026, false),
+            date(30, 12, 2024, false),
+        ];
+        dates.sort();
+        assert_eq!(
+            dates,
+            vec![
+                date(30, 12, 2024, false),
+                date(30, 6, 2025, false),
+                date(29, 6, 2025, true),
+                date(1, 7, 2025, false),
+                date(1, 1, 2026, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lunar_date_display_and_parse() {
+        let regular = LunarDate {
+            day: 15,
+            month: 8,
+            year: 2024,
+            is_leap: false,
+        };
+        let leap = LunarDate {
+            day: 1,
+            month: 6,
+            year: 2025,
+            is_leap: true,
+        };
+        assert_eq!(regular.to_string(), "15/8/2024");
+        assert_eq!(leap.to_string(), "1/6/2025 (nhuận)");
+        assert_eq!("15/8/2024".parse(), Ok(regular.clone()));
+        assert_eq!(" 1/6/2025 (nhuận) ".parse(), Ok(leap.clone()));
+        assert_eq!(leap.to_string().parse(), Ok(leap));
+
+        for bad in ["", "15/8", "15/8/2024/1", "31/1/2024", "1/13/2024", "a/b/c"] {
+            assert!(
+                bad.parse::<LunarDate>().is_err(),
+                "{bad:?} should not parse"
+            );
+        }
+        assert_eq!(
+            "1/13/2024".parse::<LunarDate>().unwrap_err().to_string(),
+            "invalid lunar date: \"1/13/2024\" (expected day/month/year, e.g. 15/8/2024 or 1/6/2025 (nhuận))"
+        );
+    }
 }
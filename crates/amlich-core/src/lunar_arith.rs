@@ -0,0 +1,460 @@
+/**
+ * Lunar Date Arithmetic
+ *
+ * Adding lunar days, months and years, counting days and months between
+ * dates and finding the next occurrence of a recurring lunar date (anniversaries, giỗ).
+ *
+ * Months are counted by new moon index, so a leap month counts as a month
+ * of its own. Where a month number is ambiguous (a year with a leap copy)
+ * or a day does not exist (day 30 of a 29-day month), the caller chooses
+ * the outcome through `LeapMonthPolicy` and `MissingDayPolicy`.
+ */
+use crate::julian::{jd_from_date, jd_to_date};
+use crate::lunar::{
+    convert_lunar_to_solar, convert_solar_to_lunar, get_new_moon_day, LunarConversionError,
+    LunarDate, SolarDate,
+};
+use crate::lunar_year::{get_lunar_year, LunarMonth, LunarYear};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use alloc::vec::Vec;
+
+/// Which month to use when the target year has a leap copy of the month
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LeapMonthPolicy {
+    /// Always use the regular month
+    #[default]
+    Regular,
+    /// Use the leap month when the target year has one with this number,
+    /// otherwise the regular month
+    Leap,
+    /// Use both the regular and the leap month
+    Both,
+}
+
+/// What to do when the day does not exist in the target month
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MissingDayPolicy {
+    /// Use the last day of the month (day 30 becomes day 29)
+    #[default]
+    Clamp,
+    /// Move to the first day of the following month
+    RollForward,
+}
+
+/// New moon index of the new moon falling on the given Julian day
+fn new_moon_index(jd: i32) -> i32 {
+    (0.5 + (jd as f64 - 2415021.076998695) / 29.530588853).floor() as i32
+}
+
+/// Julian day of the given day within a month, applying the missing day policy
+fn resolve_day(start_jd: i32, length: i32, day: i32, policy: MissingDayPolicy) -> i32 {
+    let day = match policy {
+        MissingDayPolicy::Clamp => day.min(length),
+        MissingDayPolicy::RollForward => day,
+    };
+    start_jd + day - 1
+}
+
+fn lunar_from_jd(jd: i32, time_zone: f64) -> LunarDate {
+    let (day, month, year) = jd_to_date(jd);
+    convert_solar_to_lunar(day, month, year, time_zone)
+}
+
+/// Months of a lunar year with the given number, selected by the leap policy
+fn select_months(year: &LunarYear, month: i32, policy: LeapMonthPolicy) -> Vec<&LunarMonth> {
+    let regular = year.month(month, false);
+    let leap = year.month(month, true);
+    match policy {
+        LeapMonthPolicy::Regular => regular.into_iter().collect(),
+        LeapMonthPolicy::Leap => leap.or(regular).into_iter().collect(),
+        LeapMonthPolicy::Both => regular.into_iter().chain(leap).collect(),
+    }
+}
+
+fn check_day(day: i32) -> Result<(), LunarConversionError> {
+    if (1..=30).contains(&day) {
+        Ok(())
+    } else {
+        Err(LunarConversionError::DayOutOfRange {
+            day,
+            month_length: 30,
+        })
+    }
+}
+
+impl LunarDate {
+    /// Julian day of the first day of this date's month
+    fn month_start_jd(&self, time_zone: f64) -> Result<i32, LunarConversionError> {
+        check_day(self.day)?;
+        let start = convert_lunar_to_solar(1, self.month, self.year, self.is_leap, time_zone)?;
+        Ok(jd_from_date(start.day, start.month, start.year))
+    }
+
+    /// Julian day of this date
+    fn jd(&self, time_zone: f64) -> Result<i32, LunarConversionError> {
+        let solar =
+            convert_lunar_to_solar(self.day, self.month, self.year, self.is_leap, time_zone)?;
+        Ok(jd_from_date(solar.day, solar.month, solar.year))
+    }
+
+    /// Add (or subtract, for negative `days`) days
+    ///
+    /// Counts real days, so month lengths and leap months need no policy:
+    /// one day after 29/12/2024 (a 29-day month) is 1/1/2025.
+    ///
+    /// # Arguments
+    /// * `days` - Number of days to add
+    /// * `time_zone` - Time zone offset (7.0 for UTC+7:00)
+    pub fn add_days(&self, days: i32, time_zone: f64) -> Result<LunarDate, LunarConversionError> {
+        let result = lunar_from_jd(self.jd(time_zone)? + days, time_zone);
+        // Surface the supported-range check for the target year
+        convert_lunar_to_solar(1, result.month, result.year, result.is_leap, time_zone)?;
+        Ok(result)
+    }
+
+    /// Number of days from `self` to `other`
+    ///
+    /// The result is negative when `other` is earlier.
+    pub fn days_until(
+        &self,
+        other: &LunarDate,
+        time_zone: f64,
+    ) -> Result<i32, LunarConversionError> {
+        Ok(other.jd(time_zone)? - self.jd(time_zone)?)
+    }
+
+    /// Add (or subtract, for negative `months`) lunar months
+    ///
+    /// Leap months are counted as months of their own: one month after
+    /// 15/6/2025 is 15/6 nhuận/2025.
+    ///
+    /// # Arguments
+    /// * `months` - Number of lunar months to add
+    /// * `missing_day` - Policy when the day does not exist in the target month
+    /// * `time_zone` - Time zone offset (7.0 for UTC+7:00)
+    pub fn add_months(
+        &self,
+        months: i32,
+        missing_day: MissingDayPolicy,
+        time_zone: f64,
+    ) -> Result<LunarDate, LunarConversionError> {
+        let k = new_moon_index(self.month_start_jd(time_zone)?) + months;
+        let start_jd = get_new_moon_day(k, time_zone);
+        let length = get_new_moon_day(k + 1, time_zone) - start_jd;
+        let result = lunar_from_jd(
+            resolve_day(start_jd, length, self.day, missing_day),
+            time_zone,
+        );
+        // Surface the supported-range check for the target year
+        convert_lunar_to_solar(1, result.month, result.year, result.is_leap, time_zone)?;
+        Ok(result)
+    }
+
+    /// Add (or subtract, for negative `years`) lunar years
+    ///
+    /// The month number is kept; the leap policy, not the leap flag of
+    /// `self`, decides whether the regular or leap month of the target year
+    /// is used. Returns one date, or two with `LeapMonthPolicy::Both` when
+    /// the target year has a leap copy of the month.
+    ///
+    /// # Arguments
+    /// * `years` - Number of lunar years to add
+    /// * `leap_month` - Policy for a target month that has a leap copy
+    /// * `missing_day` - Policy when the day does not exist in the target month
+    /// * `time_zone` - Time zone offset (7.0 for UTC+7:00)
+    pub fn add_years(
+        &self,
+        years: i32,
+        leap_month: LeapMonthPolicy,
+        missing_day: MissingDayPolicy,
+        time_zone: f64,
+    ) -> Result<Vec<LunarDate>, LunarConversionError> {
+        check_day(self.day)?;
+        if !(1..=12).contains(&self.month) {
+            return Err(LunarConversionError::InvalidMonth(self.month));
+        }
+
+        let target = get_lunar_year(self.year + years, time_zone)?;
+        Ok(select_months(&target, self.month, leap_month)
+            .into_iter()
+            .map(|m| {
+                lunar_from_jd(
+                    resolve_day(m.start_jd, m.length, self.day, missing_day),
+                    time_zone,
+                )
+            })
+            .collect())
+    }
+
+    /// Number of lunar months from `self` to `other`, counting leap months
+    ///
+    /// Only the months are compared; the day of month is ignored. The result
+    /// is negative when `other` is earlier.
+    pub fn months_until(
+        &self,
+        other: &LunarDate,
+        time_zone: f64,
+    ) -> Result<i32, LunarConversionError> {
+        let from = new_moon_index(self.month_start_jd(time_zone)?);
+        let to = new_moon_index(other.month_start_jd(time_zone)?);
+        Ok(to - from)
+    }
+}
+
+/// Find the next solar date, on or after `after`, of a recurring lunar date
+///
+/// # Arguments
+/// * `lunar_day` - Lunar day (1-30)
+/// * `lunar_month` - Lunar month (1-12)
+/// * `after` - First solar date to consider
+/// * `leap_month` - Policy for years with a leap copy of the month
+/// * `missing_day` - Policy when the day does not exist in the month
+/// * `time_zone` - Time zone offset (7.0 for UTC+7:00)
+///
+/// # Returns
+/// Earliest matching solar date
+pub fn next_lunar_occurrence(
+    lunar_day: i32,
+    lunar_month: i32,
+    after: SolarDate,
+    leap_month: LeapMonthPolicy,
+    missing_day: MissingDayPolicy,
+    time_zone: f64,
+) -> Result<SolarDate, LunarConversionError> {
+    check_day(lunar_day)?;
+    if !(1..=12).contains(&lunar_month) {
+        return Err(LunarConversionError::InvalidMonth(lunar_month));
+    }
+
+    let after_jd = jd_from_date(after.day, after.month, after.year);
+    let mut year = convert_solar_to_lunar(after.day, after.month, after.year, time_zone).year;
+    loop {
+        let lunar_year = get_lunar_year(year, time_zone)?;
+        let next = select_months(&lunar_year, lunar_month, leap_month)
+            .into_iter()
+            .map(|m| resolve_day(m.start_jd, m.length, lunar_day, missing_day))
+            .filter(|&jd| jd >= after_jd)
+            .min();
+        if let Some(jd) = next {
+            let (day, month, year) = jd_to_date(jd);
+            return Ok(SolarDate { day, month, year });
+        }
+        year += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lunar(day: i32, month: i32, year: i32, is_leap: bool) -> LunarDate {
+        LunarDate {
+            day,
+            month,
+            year,
+            is_leap,
+        }
+    }
+
+    fn solar(day: i32, month: i32, year: i32) -> SolarDate {
+        SolarDate { day, month, year }
+    }
+
+    #[test]
+    fn test_add_days_across_leap_month() {
+        // Month 6 of 2025 has 30 days and is followed by 6 nhuận
+        let start = lunar(10, 6, 2025, false);
+        assert_eq!(start.add_days(30, 7.0).unwrap(), lunar(10, 6, 2025, true));
+        assert_eq!(lunar(10, 6, 2025, true).add_days(-30, 7.0).unwrap(), start);
+        assert_eq!(start.days_until(&lunar(10, 6, 2025, true), 7.0), Ok(30));
+        assert_eq!(lunar(10, 6, 2025, true).days_until(&start, 7.0), Ok(-30));
+    }
+
+    #[test]
+    fn test_add_days_across_short_month() {
+        // Month 12 of 2024 has 29 days
+        let last = lunar(29, 12, 2024, false);
+        assert_eq!(last.add_days(1, 7.0).unwrap(), lunar(1, 1, 2025, false));
+        assert_eq!(
+            lunar(15, 12, 2024, false).add_days(20, 7.0).unwrap(),
+            lunar(6, 1, 2025, false)
+        );
+        assert_eq!(last.days_until(&lunar(1, 1, 2025, false), 7.0), Ok(1));
+        assert_eq!(
+            lunar(30, 12, 2024, false).add_days(1, 7.0),
+            Err(LunarConversionError::DayOutOfRange {
+                day: 30,
+                month_length: 29
+            })
+        );
+    }
+
+    #[test]
+    fn test_add_months_counts_leap_month() {
+        let start = lunar(15, 5, 2025, false);
+        let clamp = MissingDayPolicy::Clamp;
+        assert_eq!(
+            start.add_months(1, clamp, 7.0).unwrap(),
+            lunar(15, 6, 2025, false)
+        );
+        assert_eq!(
+            start.add_months(2, clamp, 7.0).unwrap(),
+            lunar(15, 6, 2025, true)
+        );
+        assert_eq!(
+            start.add_months(3, clamp, 7.0).unwrap(),
+            lunar(15, 7, 2025, false)
+        );
+        assert_eq!(
+            lunar(15, 7, 2025, false)
+                .add_months(-2, clamp, 7.0)
+                .unwrap(),
+            lunar(15, 6, 2025, false)
+        );
+    }
+
+    #[test]
+    fn test_add_months_across_year() {
+        // Month 12 of 2024 has 29 days
+        let start = lunar(30, 11, 2024, false);
+        assert_eq!(
+            start.add_months(1, MissingDayPolicy::Clamp, 7.0).unwrap(),
+            lunar(29, 12, 2024, false)
+        );
+        assert_eq!(
+            start
+                .add_months(1, MissingDayPolicy::RollForward, 7.0)
+                .unwrap(),
+            lunar(1, 1, 2025, false)
+        );
+    }
+
+    #[test]
+    fn test_add_years_leap_policies() {
+        let start = lunar(10, 6, 2024, false);
+        let clamp = MissingDayPolicy::Clamp;
+        assert_eq!(
+            start
+                .add_years(1, LeapMonthPolicy::Regular, clamp, 7.0)
+                .unwrap(),
+            vec![lunar(10, 6, 2025, false)]
+        );
+        assert_eq!(
+            start
+                .add_years(1, LeapMonthPolicy::Leap, clamp, 7.0)
+                .unwrap(),
+            vec![lunar(10, 6, 2025, true)]
+        );
+        assert_eq!(
+            start
+                .add_years(1, LeapMonthPolicy::Both, clamp, 7.0)
+                .unwrap(),
+            vec![lunar(10, 6, 2025, false), lunar(10, 6, 2025, true)]
+        );
+        // No leap month 6 in 2026: every policy falls back to the regular month
+        assert_eq!(
+            lunar(10, 6, 2025, true)
+                .add_years(1, LeapMonthPolicy::Leap, clamp, 7.0)
+                .unwrap(),
+            vec![lunar(10, 6, 2026, false)]
+        );
+    }
+
+    #[test]
+    fn test_add_years_missing_day() {
+        // Month 2 of 2025 has 29 days
+        let start = lunar(30, 2, 2024, false);
+        assert_eq!(
+            start
+                .add_years(1, LeapMonthPolicy::Regular, MissingDayPolicy::Clamp, 7.0)
+                .unwrap(),
+            vec![lunar(29, 2, 2025, false)]
+        );
+        assert_eq!(
+            start
+                .add_years(
+                    1,
+                    LeapMonthPolicy::Regular,
+                    MissingDayPolicy::RollForward,
+                    7.0
+                )
+                .unwrap(),
+            vec![lunar(1, 3, 2025, false)]
+        );
+    }
+
+    #[test]
+    fn test_months_until() {
+        let tet_2025 = lunar(1, 1, 2025, false);
+        let tet_2026 = lunar(1, 1, 2026, false);
+        assert_eq!(tet_2025.months_until(&tet_2026, 7.0), Ok(13));
+        assert_eq!(tet_2026.months_until(&tet_2025, 7.0), Ok(-13));
+        assert_eq!(
+            lunar(1, 1, 2024, false).months_until(&tet_2025, 7.0),
+            Ok(12)
+        );
+    }
+
+    #[test]
+    fn test_next_lunar_occurrence() {
+        let clamp = MissingDayPolicy::Clamp;
+        // 10/6/2025 = 2025-07-04, 10/6 nhuận/2025 = 2025-08-03
+        assert_eq!(
+            next_lunar_occurrence(10, 6, solar(1, 7, 2025), LeapMonthPolicy::Both, clamp, 7.0),
+            Ok(solar(4, 7, 2025))
+        );
+        assert_eq!(
+            next_lunar_occurrence(10, 6, solar(5, 7, 2025), LeapMonthPolicy::Both, clamp, 7.0),
+            Ok(solar(3, 8, 2025))
+        );
+        assert_eq!(
+            next_lunar_occurrence(
+                10,
+                6,
+                solar(5, 7, 2025),
+                LeapMonthPolicy::Regular,
+                clamp,
+                7.0
+            ),
+            Ok(solar(23, 7, 2026))
+        );
+        // The occurrence date itself counts
+        assert_eq!(
+            next_lunar_occurrence(
+                1,
+                1,
+                solar(29, 1, 2025),
+                LeapMonthPolicy::Regular,
+                clamp,
+                7.0
+            ),
+            Ok(solar(29, 1, 2025))
+        );
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert_eq!(
+            next_lunar_occurrence(
+                31,
+                1,
+                solar(1, 1, 2025),
+                LeapMonthPolicy::Regular,
+                MissingDayPolicy::Clamp,
+                7.0
+            ),
+            Err(LunarConversionError::DayOutOfRange {
+                day: 31,
+                month_length: 30
+            })
+        );
+        assert_eq!(
+            lunar(1, 4, 2024, true).add_months(1, MissingDayPolicy::Clamp, 7.0),
+            Err(LunarConversionError::NoSuchLeapMonth {
+                month: 4,
+                year: 2024
+            })
+        );
+    }
+}
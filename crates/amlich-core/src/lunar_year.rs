@@ -0,0 +1,209 @@
+/**
+ * Lunar Year Layout
+ *
+ * Month starts, month lengths and the leap month of a whole lunar year,
+ * derived from the same new moon days used by `convert_solar_to_lunar`.
+ */
+use crate::ephemeris::EphemerisMode;
+use crate::julian::jd_to_date;
+use crate::lunar::{
+    convert_solar_to_lunar_with_mode, get_lunar_month11_with_mode, get_new_moon_day_with_mode,
+    LunarConversionError, SolarDate,
+};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use alloc::vec::Vec;
+
+/// One month of a lunar year
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LunarMonth {
+    /// Month number (1-12)
+    pub month: i32,
+    /// Whether this is the leap (nhuận) month
+    pub is_leap: bool,
+    /// Julian day number of day 1 (mùng 1)
+    pub start_jd: i32,
+    /// Solar date of day 1
+    pub start: SolarDate,
+    /// Number of days, 29 (tháng thiếu) or 30 (tháng đủ)
+    pub length: i32,
+}
+
+/// Month layout of a lunar year, from Tết to the last day of tháng Chạp
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LunarYear {
+    pub year: i32,
+    /// The 12 or 13 months in calendar order, leap month after its regular month
+    pub months: Vec<LunarMonth>,
+    /// Number of the leap month, if the year has one
+    pub leap_month: Option<i32>,
+    /// Solar date of Tết (1/1)
+    pub tet: SolarDate,
+    /// Total number of days in the lunar year
+    pub total_days: i32,
+}
+
+impl LunarYear {
+    /// Whether the year has 13 months
+    pub fn is_leap_year(&self) -> bool {
+        self.leap_month.is_some()
+    }
+
+    /// Look up a month by number and leap flag
+    pub fn month(&self, month: i32, is_leap: bool) -> Option<&LunarMonth> {
+        self.months
+            .iter()
+            .find(|m| m.month == month && m.is_leap == is_leap)
+    }
+
+    /// Length of a month (29 or 30), or `None` if the month does not exist
+    pub fn month_length(&self, month: i32, is_leap: bool) -> Option<i32> {
+        self.month(month, is_leap).map(|m| m.length)
+    }
+}
+
+/// Get the month layout of a lunar year
+///
+/// # Arguments
+/// * `year` - Lunar year
+/// * `time_zone` - Time zone offset (7.0 for UTC+7:00)
+///
+/// # Returns
+/// Lunar year layout, or an error if the year is outside the supported range
+pub fn get_lunar_year(year: i32, time_zone: f64) -> Result<LunarYear, LunarConversionError> {
+    get_lunar_year_with_mode(year, time_zone, EphemerisMode::Fast)
+}
+
+/// Get the month layout of a lunar year with a selectable ephemeris
+///
+/// # Arguments
+/// * `year` - Lunar year
+/// * `time_zone` - Time zone offset (7.0 for UTC+7:00)
+/// * `mode` - Ephemeris mode
+///
+/// # Returns
+/// Lunar year layout, or an error if the year is outside the supported range
+pub fn get_lunar_year_with_mode(
+    year: i32,
+    time_zone: f64,
+    mode: EphemerisMode,
+) -> Result<LunarYear, LunarConversionError> {
+    mode.check_year(year)?;
+
+    // Lunar year Y lies between month 11 of Y-1 and at most four months
+    // after month 11 of Y (11, leap 11, 12, leap 12).
+    let new_moon_index =
+        |jd: i32| (0.5 + (jd as f64 - 2415021.076998695) / 29.530588853).floor() as i32;
+    let first_k = new_moon_index(get_lunar_month11_with_mode(year - 1, time_zone, mode));
+    let last_k = new_moon_index(get_lunar_month11_with_mode(year, time_zone, mode)) + 4;
+
+    let mut months = Vec::with_capacity(13);
+    let mut next_start = get_new_moon_day_with_mode(first_k, time_zone, mode);
+    for k in first_k..=last_k {
+        let start_jd = next_start;
+        next_start = get_new_moon_day_with_mode(k + 1, time_zone, mode);
+
+        let (day, month, solar_year) = jd_to_date(start_jd);
+        let lunar = convert_solar_to_lunar_with_mode(day, month, solar_year, time_zone, mode);
+        if lunar.year != year {
+            continue;
+        }
+
+        months.push(LunarMonth {
+            month: lunar.month,
+            is_leap: lunar.is_leap,
+            start_jd,
+            start: SolarDate {
+                day,
+                month,
+                year: solar_year,
+            },
+            length: next_start - start_jd,
+        });
+    }
+
+    let leap_month = months.iter().find(|m| m.is_leap).map(|m| m.month);
+    let tet = months[0].start;
+    let total_days = months.iter().map(|m| m.length).sum();
+
+    Ok(LunarYear {
+        year,
+        months,
+        leap_month,
+        tet,
+        total_days,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lunar_year_2024() {
+        let year = get_lunar_year(2024, 7.0).unwrap();
+        assert_eq!(year.months.len(), 12);
+        assert_eq!(year.leap_month, None);
+        assert_eq!(
+            year.tet,
+            SolarDate {
+                day: 10,
+                month: 2,
+                year: 2024
+            }
+        );
+        // Lunar 2024 runs from Tết 2024 to the day before Tết 2025 (January 29)
+        assert_eq!(year.total_days, 354);
+        // Tháng Chạp 2024 has 29 days, so Giao Thừa falls on 29/12
+        assert_eq!(year.month_length(12, false), Some(29));
+    }
+
+    #[test]
+    fn test_lunar_year_2025_leap_month() {
+        let year = get_lunar_year(2025, 7.0).unwrap();
+        assert_eq!(year.months.len(), 13);
+        assert_eq!(year.leap_month, Some(6));
+        assert!(year.is_leap_year());
+
+        let numbers: Vec<(i32, bool)> = year.months.iter().map(|m| (m.month, m.is_leap)).collect();
+        assert_eq!(numbers[5], (6, false));
+        assert_eq!(numbers[6], (6, true));
+        assert_eq!(numbers[12], (12, false));
+
+        // Tết 2025 is January 29, 2025
+        assert_eq!(
+            year.tet,
+            SolarDate {
+                day: 29,
+                month: 1,
+                year: 2025
+            }
+        );
+        assert_eq!(year.total_days, 384);
+    }
+
+    #[test]
+    fn test_lunar_year_months_are_contiguous() {
+        for y in [1999, 2024, 2025, 2033] {
+            let year = get_lunar_year(y, 7.0).unwrap();
+            assert_eq!(year.months[0].month, 1);
+            assert!(!year.months[0].is_leap);
+            for pair in year.months.windows(2) {
+                assert_eq!(pair[0].start_jd + pair[0].length, pair[1].start_jd);
+            }
+            for m in &year.months {
+                assert!(m.length == 29 || m.length == 30, "{y}: {m:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_lunar_year_out_of_range() {
+        assert!(matches!(
+            get_lunar_year(1700, 7.0),
+            Err(LunarConversionError::YearOutOfRange { year: 1700, .. })
+        ));
+    }
+}
@@ -0,0 +1,87 @@
+//! Float functions for no_std builds
+//!
+//! `f64::sin`, `floor` and friends live in std. Without it the same method
+//! names come from this trait, backed by libm, so the astronomy modules read
+//! the same either way: they import `FloatExt` only when `std` is off.
+//! Test builds always link std (the harness needs it), so they use the std
+//! methods too.
+
+pub(crate) trait FloatExt {
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn sqrt(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn fract(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+impl FloatExt for f64 {
+    fn sin(self) -> f64 {
+        libm::sin(self)
+    }
+
+    fn cos(self) -> f64 {
+        libm::cos(self)
+    }
+
+    fn tan(self) -> f64 {
+        libm::tan(self)
+    }
+
+    fn asin(self) -> f64 {
+        libm::asin(self)
+    }
+
+    fn acos(self) -> f64 {
+        libm::acos(self)
+    }
+
+    fn atan(self) -> f64 {
+        libm::atan(self)
+    }
+
+    fn atan2(self, other: f64) -> f64 {
+        libm::atan2(self, other)
+    }
+
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    fn floor(self) -> f64 {
+        libm::floor(self)
+    }
+
+    fn ceil(self) -> f64 {
+        libm::ceil(self)
+    }
+
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+
+    fn fract(self) -> f64 {
+        self - libm::trunc(self)
+    }
+
+    fn powi(self, n: i32) -> f64 {
+        libm::pow(self, n as f64)
+    }
+
+    fn rem_euclid(self, rhs: f64) -> f64 {
+        let r = self % rhs;
+        if r < 0.0 {
+            r + rhs.abs()
+        } else {
+            r
+        }
+    }
+}
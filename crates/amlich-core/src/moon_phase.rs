@@ -0,0 +1,463 @@
+use crate::ephemeris::delta_t_days;
+/**
+ * Moon Phases
+ *
+ * Exact instants of the four principal phases (new moon, first quarter,
+ * full moon, last quarter) and the illuminated fraction of the moon's disk.
+ *
+ * Algorithms from "Astronomical Algorithms" by Jean Meeus, 1998
+ * (chapter 49 for phases, chapter 48 for illumination)
+ */
+use crate::julian::{jd_from_date, jd_to_datetime, JulianDateTime};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+/// Mean synodic month in days
+pub(crate) const SYNODIC_MONTH: f64 = 29.530588861;
+
+/// Mean new moon of 2000 January 6, the origin of the phase index `k`
+pub(crate) const PHASE_EPOCH: f64 = 2451550.09766;
+
+/// One of the four principal moon phases
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MoonPhase {
+    NewMoon,
+    FirstQuarter,
+    FullMoon,
+    LastQuarter,
+}
+
+impl MoonPhase {
+    /// All phases in the order they occur within a lunation
+    pub const ALL: [MoonPhase; 4] = [
+        MoonPhase::NewMoon,
+        MoonPhase::FirstQuarter,
+        MoonPhase::FullMoon,
+        MoonPhase::LastQuarter,
+    ];
+
+    /// Vietnamese name of the phase
+    pub fn name(self) -> &'static str {
+        match self {
+            MoonPhase::NewMoon => "Sóc",
+            MoonPhase::FirstQuarter => "Thượng Huyền",
+            MoonPhase::FullMoon => "Vọng",
+            MoonPhase::LastQuarter => "Hạ Huyền",
+        }
+    }
+
+    /// English description of the phase
+    pub fn description(self) -> &'static str {
+        match self {
+            MoonPhase::NewMoon => "New Moon (Sóc)",
+            MoonPhase::FirstQuarter => "First Quarter (Thượng Huyền)",
+            MoonPhase::FullMoon => "Full Moon (Vọng)",
+            MoonPhase::LastQuarter => "Last Quarter (Hạ Huyền)",
+        }
+    }
+
+    /// Fraction of a lunation at which the phase occurs
+    fn fraction(self) -> f64 {
+        match self {
+            MoonPhase::NewMoon => 0.0,
+            MoonPhase::FirstQuarter => 0.25,
+            MoonPhase::FullMoon => 0.5,
+            MoonPhase::LastQuarter => 0.75,
+        }
+    }
+}
+
+/// A moon phase at an exact instant
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoonPhaseEvent {
+    pub phase: MoonPhase,
+    pub name: String,
+    /// Lunation index counted from the new moon of 2000 January 6
+    pub lunation: i32,
+    /// Julian day (fractional, UT) of the phase
+    pub jd: f64,
+    pub utc: JulianDateTime,
+    pub local: JulianDateTime,
+}
+
+/// Illuminated part of the moon's disk at an instant
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoonIllumination {
+    /// Sun-Moon angle seen from the moon, in degrees (0 = full, 180 = new)
+    pub phase_angle: f64,
+    /// Illuminated fraction of the disk (0.0 to 1.0)
+    pub illuminated_fraction: f64,
+    /// True between new moon and full moon
+    pub is_waxing: bool,
+}
+
+/// Compute the instant of a moon phase in dynamical time (JDE)
+///
+/// # Arguments
+/// * `lunation` - Lunation index (0 = new moon of 2000 January 6)
+/// * `phase` - Which phase of that lunation
+///
+/// # Returns
+/// Julian Ephemeris Day (fractional)
+pub fn moon_phase_jde(lunation: i32, phase: MoonPhase) -> f64 {
+    let k = lunation as f64 + phase.fraction();
+    let t = k / 1236.85;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+    let dr = PI / 180.0;
+
+    // Mean phase
+    let mut jde =
+        PHASE_EPOCH + SYNODIC_MONTH * k + 0.00015437 * t2 - 0.000000150 * t3 + 0.00000000073 * t4;
+
+    // Eccentricity of Earth's orbit
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t2;
+
+    // Sun's mean anomaly
+    let m = (2.5534 + 29.10535670 * k - 0.0000014 * t2 - 0.00000011 * t3) * dr;
+
+    // Moon's mean anomaly
+    let mpr =
+        (201.5643 + 385.81693528 * k + 0.0107582 * t2 + 0.00001238 * t3 - 0.000000058 * t4) * dr;
+
+    // Moon's argument of latitude
+    let f =
+        (160.7108 + 390.67050284 * k - 0.0016118 * t2 - 0.00000227 * t3 + 0.000000011 * t4) * dr;
+
+    // Longitude of the ascending node of the lunar orbit
+    let omega = (124.7746 - 1.56375588 * k + 0.0020672 * t2 + 0.00000215 * t3) * dr;
+
+    let correction = match phase {
+        MoonPhase::NewMoon | MoonPhase::FullMoon => {
+            let (c_mpr, c_m, c_2mpr, c_2f, c_mpr_m, c_mpr_pm, c_2m) = if phase == MoonPhase::NewMoon
+            {
+                (
+                    -0.40720, 0.17241, 0.01608, 0.01039, 0.00739, -0.00514, 0.00208,
+                )
+            } else {
+                (
+                    -0.40614, 0.17302, 0.01614, 0.01043, 0.00734, -0.00515, 0.00209,
+                )
+            };
+
+            c_mpr * mpr.sin()
+                + c_m * e * m.sin()
+                + c_2mpr * (2.0 * mpr).sin()
+                + c_2f * (2.0 * f).sin()
+                + c_mpr_m * e * (mpr - m).sin()
+                + c_mpr_pm * e * (mpr + m).sin()
+                + c_2m * e * e * (2.0 * m).sin()
+                - 0.00111 * (mpr - 2.0 * f).sin()
+                - 0.00057 * (mpr + 2.0 * f).sin()
+                + 0.00056 * e * (2.0 * mpr + m).sin()
+                - 0.00042 * (3.0 * mpr).sin()
+                + 0.00042 * e * (m + 2.0 * f).sin()
+                + 0.00038 * e * (m - 2.0 * f).sin()
+                - 0.00024 * e * (2.0 * mpr - m).sin()
+                - 0.00017 * omega.sin()
+                - 0.00007 * (mpr + 2.0 * m).sin()
+                + 0.00004 * (2.0 * mpr - 2.0 * f).sin()
+                + 0.00004 * (3.0 * m).sin()
+                + 0.00003 * (mpr + m - 2.0 * f).sin()
+                + 0.00003 * (2.0 * mpr + 2.0 * f).sin()
+                - 0.00003 * (mpr + m + 2.0 * f).sin()
+                + 0.00003 * (mpr - m + 2.0 * f).sin()
+                - 0.00002 * (mpr - m - 2.0 * f).sin()
+                - 0.00002 * (3.0 * mpr + m).sin()
+                + 0.00002 * (4.0 * mpr).sin()
+        }
+        MoonPhase::FirstQuarter | MoonPhase::LastQuarter => {
+            let quarter = -0.62801 * mpr.sin() + 0.17172 * e * m.sin()
+                - 0.01183 * e * (mpr + m).sin()
+                + 0.00862 * (2.0 * mpr).sin()
+                + 0.00804 * (2.0 * f).sin()
+                + 0.00454 * e * (mpr - m).sin()
+                + 0.00204 * e * e * (2.0 * m).sin()
+                - 0.00180 * (mpr - 2.0 * f).sin()
+                - 0.00070 * (mpr + 2.0 * f).sin()
+                - 0.00040 * (3.0 * mpr).sin()
+                - 0.00034 * e * (2.0 * mpr - m).sin()
+                + 0.00032 * e * (m + 2.0 * f).sin()
+                + 0.00032 * e * (m - 2.0 * f).sin()
+                - 0.00028 * e * e * (mpr + 2.0 * m).sin()
+                + 0.00027 * e * (2.0 * mpr + m).sin()
+                - 0.00017 * omega.sin()
+                - 0.00005 * (mpr - m - 2.0 * f).sin()
+                + 0.00004 * (2.0 * mpr + 2.0 * f).sin()
+                - 0.00004 * (mpr + m + 2.0 * f).sin()
+                + 0.00004 * (mpr - 2.0 * m).sin()
+                + 0.00003 * (mpr + m - 2.0 * f).sin()
+                + 0.00003 * (3.0 * m).sin()
+                + 0.00002 * (2.0 * mpr - 2.0 * f).sin()
+                + 0.00002 * (mpr - m + 2.0 * f).sin()
+                - 0.00002 * (3.0 * mpr + m).sin();
+
+            let w = 0.00306 - 0.00038 * e * m.cos() + 0.00026 * mpr.cos()
+                - 0.00002 * (mpr - m).cos()
+                + 0.00002 * (mpr + m).cos()
+                + 0.00002 * (2.0 * f).cos();
+
+            if phase == MoonPhase::FirstQuarter {
+                quarter + w
+            } else {
+                quarter - w
+            }
+        }
+    };
+    jde += correction;
+
+    // Additional corrections from planetary arguments
+    const PLANETARY: [(f64, f64, f64); 13] = [
+        (0.000165, 251.88, 0.016321),
+        (0.000164, 251.83, 26.651886),
+        (0.000126, 349.42, 36.412478),
+        (0.000110, 84.66, 18.206239),
+        (0.000062, 141.74, 53.303771),
+        (0.000060, 207.14, 2.453732),
+        (0.000056, 154.84, 7.306860),
+        (0.000047, 34.52, 27.261239),
+        (0.000042, 207.19, 0.121824),
+        (0.000040, 291.34, 1.844379),
+        (0.000037, 161.72, 24.198154),
+        (0.000035, 239.56, 25.513099),
+        (0.000023, 331.55, 3.592518),
+    ];
+    jde += 0.000325 * ((299.77 + 0.107408 * k - 0.009173 * t2) * dr).sin();
+    for (coefficient, base, rate) in PLANETARY {
+        jde += coefficient * ((base + rate * k) * dr).sin();
+    }
+
+    jde
+}
+
+/// Compute the instant of a moon phase in universal time
+///
+/// # Arguments
+/// * `lunation` - Lunation index (0 = new moon of 2000 January 6)
+/// * `phase` - Which phase of that lunation
+///
+/// # Returns
+/// Julian day (fractional, UT)
+pub fn moon_phase_instant(lunation: i32, phase: MoonPhase) -> f64 {
+    let jde = moon_phase_jde(lunation, phase);
+    jde - delta_t_days(jde)
+}
+
+fn build_event(lunation: i32, phase: MoonPhase, time_zone: f64) -> MoonPhaseEvent {
+    let jd = moon_phase_instant(lunation, phase);
+
+    MoonPhaseEvent {
+        phase,
+        name: phase.name().to_string(),
+        lunation,
+        jd,
+        utc: jd_to_datetime(jd, 0.0),
+        local: jd_to_datetime(jd, time_zone),
+    }
+}
+
+/// Find the first occurrence of a phase at or after an instant
+///
+/// # Arguments
+/// * `phase` - Phase to look for
+/// * `jd` - Julian day (fractional, UT) to search from
+/// * `time_zone` - Timezone offset for the `local` field
+///
+/// # Returns
+/// The next moon phase event
+pub fn next_moon_phase(phase: MoonPhase, jd: f64, time_zone: f64) -> MoonPhaseEvent {
+    // Start one lunation early so the mean estimate never overshoots
+    let mut lunation = ((jd - PHASE_EPOCH) / SYNODIC_MONTH - phase.fraction()).floor() as i32 - 1;
+
+    while moon_phase_instant(lunation, phase) < jd {
+        lunation += 1;
+    }
+
+    build_event(lunation, phase, time_zone)
+}
+
+/// Find the first full moon on or after a local date
+///
+/// # Arguments
+/// * `day` - Solar day (1-31)
+/// * `month` - Solar month (1-12)
+/// * `year` - Solar year
+/// * `time_zone` - Timezone offset (7.0 for UTC+7:00)
+///
+/// # Returns
+/// The next full moon event, starting the search at local midnight
+pub fn get_next_full_moon(day: i32, month: i32, year: i32, time_zone: f64) -> MoonPhaseEvent {
+    let local_midnight = jd_from_date(day, month, year) as f64 - 0.5 - time_zone / 24.0;
+    next_moon_phase(MoonPhase::FullMoon, local_midnight, time_zone)
+}
+
+/// Get every principal moon phase whose local date falls in a solar year
+///
+/// # Arguments
+/// * `year` - Solar year
+/// * `time_zone` - Timezone offset (7.0 for UTC+7:00)
+///
+/// # Returns
+/// Moon phase events in chronological order
+pub fn get_moon_phases_for_year(year: i32, time_zone: f64) -> Vec<MoonPhaseEvent> {
+    let start = jd_from_date(1, 1, year) as f64 - 0.5 - time_zone / 24.0;
+    let end = jd_from_date(1, 1, year + 1) as f64 - 0.5 - time_zone / 24.0;
+
+    let mut events = Vec::new();
+    let mut lunation = ((start - PHASE_EPOCH) / SYNODIC_MONTH).floor() as i32 - 1;
+
+    loop {
+        for phase in MoonPhase::ALL {
+            let event = build_event(lunation, phase, time_zone);
+            if event.jd >= end {
+                return events;
+            }
+            if event.jd >= start {
+                events.push(event);
+            }
+        }
+        lunation += 1;
+    }
+}
+
+/// Compute the illuminated fraction and phase angle of the moon
+///
+/// Uses the lower-accuracy expression for the phase angle from Meeus
+/// chapter 48, which is good to a few hundredths of a degree.
+///
+/// # Arguments
+/// * `jd` - Julian day (fractional)
+///
+/// # Returns
+/// Moon illumination at that instant
+pub fn get_moon_illumination(jd: f64) -> MoonIllumination {
+    let t = (jd - 2451545.0) / 36525.0;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+    let dr = PI / 180.0;
+
+    // Mean elongation of the moon
+    let d = (297.8501921 + 445267.1114034 * t - 0.0018819 * t2 + t3 / 545868.0 - t4 / 113065000.0)
+        .rem_euclid(360.0);
+
+    // Sun's mean anomaly
+    let m = 357.5291092 + 35999.0502909 * t - 0.0001536 * t2 + t3 / 24490000.0;
+
+    // Moon's mean anomaly
+    let mpr = 134.9633964 + 477198.8675055 * t + 0.0087414 * t2 + t3 / 69699.0 - t4 / 14712000.0;
+
+    let phase_angle = 180.0 - d - 6.289 * (mpr * dr).sin() + 2.100 * (m * dr).sin()
+        - 1.274 * ((2.0 * d - mpr) * dr).sin()
+        - 0.658 * (2.0 * d * dr).sin()
+        - 0.214 * (2.0 * mpr * dr).sin()
+        - 0.110 * (d * dr).sin();
+
+    // Fold into [0, 180]: the angle is symmetric around full moon
+    let phase_angle = phase_angle.rem_euclid(360.0);
+    let phase_angle = if phase_angle > 180.0 {
+        360.0 - phase_angle
+    } else {
+        phase_angle
+    };
+
+    MoonIllumination {
+        phase_angle,
+        illuminated_fraction: (1.0 + (phase_angle * dr).cos()) / 2.0,
+        is_waxing: d < 180.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lunar::convert_solar_to_lunar;
+
+    #[test]
+    fn test_new_moon_jde_meeus_example() {
+        // Meeus example 49.a: new moon of 1977 February, JDE 2443192.65118
+        let jde = moon_phase_jde(-283, MoonPhase::NewMoon);
+        assert!((jde - 2443192.65118).abs() < 1e-5, "jde = {}", jde);
+    }
+
+    #[test]
+    fn test_last_quarter_jde_meeus_example() {
+        // Meeus example 49.b: last quarter of 2044 January, JDE 2467636.49186
+        let jde = moon_phase_jde(544, MoonPhase::LastQuarter);
+        assert!((jde - 2467636.49186).abs() < 1e-5, "jde = {}", jde);
+    }
+
+    #[test]
+    fn test_new_moon_total_eclipse_2024() {
+        // New moon of the 2024-04-08 total solar eclipse, 18:21 UTC
+        let event = next_moon_phase(MoonPhase::NewMoon, jd_from_date(1, 4, 2024) as f64, 7.0);
+
+        assert_eq!(
+            (event.utc.day, event.utc.month, event.utc.year),
+            (8, 4, 2024)
+        );
+        let utc_minutes = event.utc.hour * 60 + event.utc.minute;
+        assert!((utc_minutes - (18 * 60 + 21)).abs() <= 2);
+        assert_eq!((event.local.day, event.local.hour), (9, 1));
+    }
+
+    #[test]
+    fn test_next_full_moon_mid_autumn_2024() {
+        // Full moon of 2024-09-18 02:34 UTC, the day after Tết Trung Thu (15/8)
+        let event = get_next_full_moon(1, 9, 2024, 7.0);
+
+        assert_eq!(event.phase, MoonPhase::FullMoon);
+        assert_eq!(event.name, "Vọng");
+        assert_eq!((event.local.day, event.local.month), (18, 9));
+        let utc_minutes = event.utc.hour * 60 + event.utc.minute;
+        assert!((utc_minutes - (2 * 60 + 34)).abs() <= 2);
+
+        let lunar = convert_solar_to_lunar(18, 9, 2024, 7.0);
+        assert_eq!((lunar.day, lunar.month), (16, 8));
+    }
+
+    #[test]
+    fn test_next_moon_phase_is_not_before_start() {
+        let start = jd_from_date(10, 2, 2024) as f64;
+        for phase in MoonPhase::ALL {
+            let event = next_moon_phase(phase, start, 7.0);
+            assert!(event.jd >= start);
+            assert!(event.jd - start < SYNODIC_MONTH + 1.0);
+        }
+    }
+
+    #[test]
+    fn test_moon_phases_for_year_ordered() {
+        let events = get_moon_phases_for_year(2024, 7.0);
+
+        assert!(events.len() >= 49 && events.len() <= 51);
+        for pair in events.windows(2) {
+            assert!(pair[1].jd > pair[0].jd);
+        }
+        assert!(events.iter().all(|event| event.local.year == 2024));
+    }
+
+    #[test]
+    fn test_moon_illumination() {
+        // Meeus example 48.a: 1992 April 12 0h TD, k = 0.6786
+        let illumination = get_moon_illumination(2448724.5);
+        assert!((illumination.illuminated_fraction - 0.6786).abs() < 0.005);
+        assert!(illumination.is_waxing);
+
+        let full = get_next_full_moon(1, 9, 2024, 7.0);
+        let at_full = get_moon_illumination(full.jd);
+        assert!(at_full.illuminated_fraction > 0.99);
+        assert!(at_full.phase_angle < 10.0);
+
+        let new = next_moon_phase(MoonPhase::NewMoon, full.jd, 7.0);
+        let at_new = get_moon_illumination(new.jd);
+        assert!(at_new.illuminated_fraction < 0.01);
+    }
+}
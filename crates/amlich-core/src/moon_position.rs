@@ -0,0 +1,434 @@
+/**
+ * Moon Position, Moonrise and Moonset
+ *
+ * Geocentric lunar coordinates from the ELP-2000/82 series of "Astronomical
+ * Algorithms" by Jean Meeus, 1998 (chapter 47, terms above 0.002°), the same
+ * theory behind the new moon corrections in `lunar::new_moon`. Positions are
+ * corrected for parallax (chapter 40), which shifts the moon by up to 1° and
+ * moves rise/set times by several minutes.
+ */
+use crate::ephemeris::delta_t_days;
+use crate::julian::{jd_from_date, jd_to_datetime, JulianDateTime};
+use crate::location::Location;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use core::f64::consts::PI;
+
+/// Equatorial radius of the earth in km
+const EARTH_RADIUS_KM: f64 = 6378.14;
+
+/// Mean radius of the moon in km
+const MOON_RADIUS_KM: f64 = 1737.4;
+
+/// Atmospheric refraction at the horizon in degrees (34')
+const HORIZON_REFRACTION: f64 = 0.5667;
+
+/// Sampling step of the rise/set search (10 minutes); the moon's altitude
+/// changes by less than 3° in that time, so no crossing is missed
+const SEARCH_STEP: f64 = 10.0 / 1440.0;
+
+// Periodic terms for longitude and distance (Meeus table 47.A): multiples of
+// D, M, M', F, then Σl in 1e-6 degree and Σr in 1e-3 km
+const LONGITUDE_DISTANCE_TERMS: [(f64, f64, f64, f64, f64, f64); 32] = [
+    (0.0, 0.0, 1.0, 0.0, 6288774.0, -20905355.0),
+    (2.0, 0.0, -1.0, 0.0, 1274027.0, -3699111.0),
+    (2.0, 0.0, 0.0, 0.0, 658314.0, -2955968.0),
+    (0.0, 0.0, 2.0, 0.0, 213618.0, -569925.0),
+    (0.0, 1.0, 0.0, 0.0, -185116.0, 48888.0),
+    (0.0, 0.0, 0.0, 2.0, -114332.0, -3149.0),
+    (2.0, 0.0, -2.0, 0.0, 58793.0, 246158.0),
+    (2.0, -1.0, -1.0, 0.0, 57066.0, -152138.0),
+    (2.0, 0.0, 1.0, 0.0, 53322.0, -170733.0),
+    (2.0, -1.0, 0.0, 0.0, 45758.0, -204586.0),
+    (0.0, 1.0, -1.0, 0.0, -40923.0, -129620.0),
+    (1.0, 0.0, 0.0, 0.0, -34720.0, 108743.0),
+    (0.0, 1.0, 1.0, 0.0, -30383.0, 104755.0),
+    (2.0, 0.0, 0.0, -2.0, 15327.0, 10321.0),
+    (0.0, 0.0, 1.0, 2.0, -12528.0, 0.0),
+    (0.0, 0.0, 1.0, -2.0, 10980.0, 79661.0),
+    (4.0, 0.0, -1.0, 0.0, 10675.0, -34782.0),
+    (0.0, 0.0, 3.0, 0.0, 10034.0, -23210.0),
+    (4.0, 0.0, -2.0, 0.0, 8548.0, -21636.0),
+    (2.0, 1.0, -1.0, 0.0, -7888.0, 24208.0),
+    (2.0, 1.0, 0.0, 0.0, -6766.0, 30824.0),
+    (1.0, 0.0, -1.0, 0.0, -5163.0, -8379.0),
+    (1.0, 1.0, 0.0, 0.0, 4987.0, -16675.0),
+    (2.0, -1.0, 1.0, 0.0, 4036.0, -12831.0),
+    (2.0, 0.0, 2.0, 0.0, 3994.0, -10445.0),
+    (4.0, 0.0, 0.0, 0.0, 3861.0, -11650.0),
+    (2.0, 0.0, -3.0, 0.0, 3665.0, 14403.0),
+    (0.0, 1.0, -2.0, 0.0, -2689.0, -7003.0),
+    (2.0, 0.0, -1.0, 2.0, -2602.0, 0.0),
+    (2.0, -1.0, -2.0, 0.0, 2390.0, 10056.0),
+    (1.0, 0.0, 1.0, 0.0, -2348.0, 6322.0),
+    (2.0, -2.0, 0.0, 0.0, 2236.0, -9884.0),
+];
+
+// Periodic terms for latitude (Meeus table 47.B): multiples of D, M, M', F,
+// then Σb in 1e-6 degree
+const LATITUDE_TERMS: [(f64, f64, f64, f64, f64); 20] = [
+    (0.0, 0.0, 0.0, 1.0, 5128122.0),
+    (0.0, 0.0, 1.0, 1.0, 280602.0),
+    (0.0, 0.0, 1.0, -1.0, 277693.0),
+    (2.0, 0.0, 0.0, -1.0, 173237.0),
+    (2.0, 0.0, -1.0, 1.0, 55413.0),
+    (2.0, 0.0, -1.0, -1.0, 46271.0),
+    (2.0, 0.0, 0.0, 1.0, 32573.0),
+    (0.0, 0.0, 2.0, 1.0, 17198.0),
+    (2.0, 0.0, 1.0, -1.0, 9266.0),
+    (0.0, 0.0, 2.0, -1.0, 8822.0),
+    (2.0, -1.0, 0.0, -1.0, 8216.0),
+    (2.0, 0.0, -2.0, -1.0, 4324.0),
+    (2.0, 0.0, 1.0, 1.0, 4200.0),
+    (2.0, 1.0, 0.0, -1.0, -3359.0),
+    (2.0, -1.0, -1.0, 1.0, 2463.0),
+    (2.0, -1.0, 0.0, 1.0, 2211.0),
+    (2.0, -1.0, -1.0, -1.0, 2065.0),
+    (0.0, 1.0, -1.0, -1.0, -1870.0),
+    (4.0, 0.0, -1.0, -1.0, 1828.0),
+    (0.0, 1.0, 0.0, 1.0, -1794.0),
+];
+
+/// Position of the moon seen from a location at an instant
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoonPosition {
+    /// Apparent geocentric ecliptic longitude in degrees (0-360)
+    pub longitude: f64,
+    /// Geocentric ecliptic latitude in degrees
+    pub latitude: f64,
+    /// Distance between the centres of the earth and the moon in km
+    pub distance_km: f64,
+    /// Topocentric right ascension in degrees (0-360)
+    pub right_ascension: f64,
+    /// Topocentric declination in degrees
+    pub declination: f64,
+    /// Topocentric altitude of the moon's centre in degrees, without refraction
+    pub altitude: f64,
+    /// Azimuth in degrees, measured from north through east (0-360)
+    pub azimuth: f64,
+}
+
+/// Moon events of one local day at a location
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoonTimes {
+    pub location: Location,
+    /// `None` on the day each month when the moon does not rise
+    pub moonrise: Option<JulianDateTime>,
+    /// `None` on the day each month when the moon does not set
+    pub moonset: Option<JulianDateTime>,
+    /// Upper culmination (the moon due south or north); `None` on the day
+    /// each month without one
+    pub transit: Option<JulianDateTime>,
+    /// Altitude at transit in degrees
+    pub transit_altitude: Option<f64>,
+}
+
+/// Geocentric ecliptic longitude, latitude (degrees) and distance (km) at a
+/// Julian Ephemeris Day, with the nutation in longitude and the true
+/// obliquity of the ecliptic
+fn geocentric_moon(jde: f64) -> (f64, f64, f64, f64) {
+    let dr = PI / 180.0;
+    let t = (jde - 2451545.0) / 36525.0;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+
+    // Mean longitude, elongation, anomalies and argument of latitude
+    let lp = 218.3164477 + 481267.88123421 * t - 0.0015786 * t2 + t3 / 538841.0 - t4 / 65194000.0;
+    let d = 297.8501921 + 445267.1114034 * t - 0.0018819 * t2 + t3 / 545868.0 - t4 / 113065000.0;
+    let m = 357.5291092 + 35999.0502909 * t - 0.0001536 * t2 + t3 / 24490000.0;
+    let mp = 134.9633964 + 477198.8675055 * t + 0.0087414 * t2 + t3 / 69699.0 - t4 / 14712000.0;
+    let f = 93.2720950 + 483202.0175233 * t - 0.0036539 * t2 - t3 / 3526000.0 + t4 / 863310000.0;
+
+    let a1 = 119.75 + 131.849 * t;
+    let a2 = 53.09 + 479264.290 * t;
+    let a3 = 313.45 + 481266.484 * t;
+
+    // Eccentricity of the earth's orbit, applied to terms containing M
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t2;
+    let eccentricity_factor = |m_multiple: f64| match m_multiple.abs() as i32 {
+        1 => e,
+        2 => e * e,
+        _ => 1.0,
+    };
+
+    let mut sum_l = 0.0;
+    let mut sum_r = 0.0;
+    for &(cd, cm, cmp, cf, l, r) in &LONGITUDE_DISTANCE_TERMS {
+        let arg = (cd * d + cm * m + cmp * mp + cf * f) * dr;
+        let factor = eccentricity_factor(cm);
+        sum_l += l * factor * arg.sin();
+        sum_r += r * factor * arg.cos();
+    }
+    let mut sum_b = 0.0;
+    for &(cd, cm, cmp, cf, b) in &LATITUDE_TERMS {
+        let arg = (cd * d + cm * m + cmp * mp + cf * f) * dr;
+        sum_b += b * eccentricity_factor(cm) * arg.sin();
+    }
+
+    // Venus, Jupiter and the flattening of the earth
+    sum_l += 3958.0 * (a1 * dr).sin() + 1962.0 * ((lp - f) * dr).sin() + 318.0 * (a2 * dr).sin();
+    sum_b += -2235.0 * (lp * dr).sin()
+        + 382.0 * (a3 * dr).sin()
+        + 175.0 * ((a1 - f) * dr).sin()
+        + 175.0 * ((a1 + f) * dr).sin()
+        + 127.0 * ((lp - mp) * dr).sin()
+        - 115.0 * ((lp + mp) * dr).sin();
+
+    // Nutation in longitude and obliquity (Meeus chapter 22, low accuracy)
+    let omega = 125.04452 - 1934.136261 * t;
+    let sun_mean = 280.4665 + 36000.7698 * t;
+    let nutation = -0.004778 * (omega * dr).sin() - 0.000367 * (2.0 * sun_mean * dr).sin();
+    let obliquity = 23.4392911 - 0.0130042 * t + 0.00256 * (omega * dr).cos();
+
+    let longitude = (lp + sum_l / 1e6 + nutation).rem_euclid(360.0);
+    let latitude = sum_b / 1e6;
+    let distance = 385000.56 + sum_r / 1000.0;
+
+    (longitude, latitude, distance, obliquity)
+}
+
+/// Greenwich apparent sidereal time in degrees, close enough to mean
+/// sidereal time for rise/set work (Meeus formula 12.4)
+fn greenwich_sidereal_time(jd: f64) -> f64 {
+    let t = (jd - 2451545.0) / 36525.0;
+    (280.46061837 + 360.98564736629 * (jd - 2451545.0) + 0.000387933 * t * t
+        - t * t * t / 38710000.0)
+        .rem_euclid(360.0)
+}
+
+/// Compute the position of the moon seen from a location
+///
+/// # Arguments
+/// * `jd` - Julian day (fractional, UT)
+/// * `location` - Observer location
+///
+/// # Returns
+/// Geocentric ecliptic and topocentric equatorial/horizontal coordinates
+pub fn get_moon_position(jd: f64, location: &Location) -> MoonPosition {
+    let dr = PI / 180.0;
+    let (longitude, latitude, distance_km, obliquity) = geocentric_moon(jd + delta_t_days(jd));
+
+    // Ecliptic to equatorial (Meeus 13.3, 13.4)
+    let (lambda, beta, epsilon) = (longitude * dr, latitude * dr, obliquity * dr);
+    let alpha = (lambda.sin() * epsilon.cos() - beta.tan() * epsilon.sin()).atan2(lambda.cos());
+    let delta = (beta.sin() * epsilon.cos() + beta.cos() * epsilon.sin() * lambda.sin()).asin();
+
+    // Observer's geocentric position (Meeus chapter 11)
+    let phi = location.latitude * dr;
+    let u = (0.99664719 * phi.tan()).atan();
+    let height = location.elevation / (EARTH_RADIUS_KM * 1000.0);
+    let rho_sin = 0.99664719 * u.sin() + height * phi.sin();
+    let rho_cos = u.cos() + height * phi.cos();
+
+    // Parallax in right ascension and declination (Meeus 40.6, 40.7)
+    let sin_parallax = EARTH_RADIUS_KM / distance_km;
+    let hour_angle = (greenwich_sidereal_time(jd) + location.longitude) * dr - alpha;
+    let denominator = delta.cos() - rho_cos * sin_parallax * hour_angle.cos();
+    let delta_alpha = (-rho_cos * sin_parallax * hour_angle.sin()).atan2(denominator);
+    let topo_delta =
+        ((delta.sin() - rho_sin * sin_parallax) * delta_alpha.cos()).atan2(denominator);
+    let topo_hour_angle = hour_angle - delta_alpha;
+
+    // Equatorial to horizontal (Meeus 13.5, 13.6), azimuth from north
+    let altitude = (phi.sin() * topo_delta.sin()
+        + phi.cos() * topo_delta.cos() * topo_hour_angle.cos())
+    .asin();
+    let azimuth = topo_hour_angle
+        .sin()
+        .atan2(topo_hour_angle.cos() * phi.sin() - topo_delta.tan() * phi.cos())
+        / dr
+        + 180.0;
+
+    MoonPosition {
+        longitude,
+        latitude,
+        distance_km,
+        right_ascension: ((alpha + delta_alpha) / dr).rem_euclid(360.0),
+        declination: topo_delta / dr,
+        altitude: altitude / dr,
+        azimuth: azimuth.rem_euclid(360.0),
+    }
+}
+
+/// Altitude of the moon's centre above the rise/set threshold: the upper
+/// limb on the refracted horizon, lowered further by the observer's height
+fn rise_set_margin(jd: f64, location: &Location) -> f64 {
+    let position = get_moon_position(jd, location);
+    let semi_diameter = (MOON_RADIUS_KM / position.distance_km).asin().to_degrees();
+    let dip = 0.0347 * location.elevation.max(0.0).sqrt();
+    position.altitude + HORIZON_REFRACTION + semi_diameter + dip
+}
+
+/// Sine of the topocentric hour angle, which turns from negative to positive
+/// at upper culmination
+fn transit_margin(jd: f64, location: &Location) -> f64 {
+    let position = get_moon_position(jd, location);
+    let hour_angle = greenwich_sidereal_time(jd) + location.longitude - position.right_ascension;
+    hour_angle.to_radians().sin()
+}
+
+/// Refine a sign change of `f` between `a` and `b` by bisection to about a
+/// second
+fn bisect(f: impl Fn(f64) -> f64, mut a: f64, mut b: f64) -> f64 {
+    let rising = f(a) < 0.0;
+    while b - a > 1.0 / 86400.0 {
+        let mid = (a + b) / 2.0;
+        if (f(mid) < 0.0) == rising {
+            a = mid;
+        } else {
+            b = mid;
+        }
+    }
+    (a + b) / 2.0
+}
+
+/// Compute moonrise, moonset and transit for a local date
+///
+/// Each event is the first of its kind on that date; because the moon rises
+/// about 50 minutes later every day, one date each month has no moonrise and
+/// another has no moonset.
+///
+/// # Arguments
+/// * `day` - Day (1-31)
+/// * `month` - Month (1-12)
+/// * `year` - Year
+/// * `time_zone` - Time zone offset of the returned times (7.0 for UTC+7:00)
+/// * `location` - Observer location; elevation lowers the apparent horizon
+///
+/// # Returns
+/// Moon events of the local date
+pub fn get_moon_times(
+    day: i32,
+    month: i32,
+    year: i32,
+    time_zone: f64,
+    location: &Location,
+) -> MoonTimes {
+    let local_midnight = jd_from_date(day, month, year) as f64 - 0.5 - time_zone / 24.0;
+    let steps = (1.0 / SEARCH_STEP).round() as i32;
+
+    let mut moonrise = None;
+    let mut moonset = None;
+    let mut transit = None;
+
+    let mut previous_jd = local_midnight;
+    let mut previous_alt = rise_set_margin(previous_jd, location);
+    let mut previous_hour = transit_margin(previous_jd, location);
+    for step in 1..=steps {
+        let jd = local_midnight + step as f64 * SEARCH_STEP;
+        let alt = rise_set_margin(jd, location);
+        let hour = transit_margin(jd, location);
+
+        if previous_alt < 0.0 && alt >= 0.0 && moonrise.is_none() {
+            moonrise = Some(bisect(|t| rise_set_margin(t, location), previous_jd, jd));
+        }
+        if previous_alt >= 0.0 && alt < 0.0 && moonset.is_none() {
+            moonset = Some(bisect(|t| rise_set_margin(t, location), previous_jd, jd));
+        }
+        // Skip the jump from +1 to -1 at lower culmination
+        if previous_hour < 0.0 && hour >= 0.0 && previous_hour > -0.5 && transit.is_none() {
+            transit = Some(bisect(|t| transit_margin(t, location), previous_jd, jd));
+        }
+
+        previous_jd = jd;
+        previous_alt = alt;
+        previous_hour = hour;
+    }
+
+    let local = |jd: f64| jd_to_datetime(jd, time_zone);
+    MoonTimes {
+        location: *location,
+        moonrise: moonrise.map(local),
+        moonset: moonset.map(local),
+        transit: transit.map(local),
+        transit_altitude: transit.map(|jd| get_moon_position(jd, location).altitude),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::location::HANOI;
+    use crate::sun_times::get_sun_times;
+
+    fn minutes(t: &JulianDateTime) -> i32 {
+        t.hour * 60 + t.minute
+    }
+
+    #[test]
+    fn test_geocentric_moon_meeus_example() {
+        // Meeus example 47.a: 1992 April 12, 0h TD
+        let (longitude, latitude, distance, obliquity) = geocentric_moon(2448724.5);
+        assert!((longitude - 133.167265).abs() < 0.005, "λ = {longitude}");
+        assert!((latitude - -3.229126).abs() < 0.005, "β = {latitude}");
+        assert!((distance - 368409.7).abs() < 20.0, "Δ = {distance}");
+        assert!((obliquity - 23.440636).abs() < 0.001, "ε = {obliquity}");
+    }
+
+    #[test]
+    fn test_full_moon_rises_at_sunset() {
+        // Tết Trung Thu 2024 (15/8 = 2024-09-17), full moon 09:34 next morning
+        let moon = get_moon_times(17, 9, 2024, 7.0, &HANOI);
+        let sun = get_sun_times(17, 9, 2024, 7.0, &HANOI);
+        let rise = minutes(moon.moonrise.as_ref().unwrap());
+        let sunset = minutes(sun.sunset.as_ref().unwrap());
+        assert!(
+            (rise - sunset).abs() <= 45,
+            "moonrise {rise}, sunset {sunset}"
+        );
+
+        // The morning of the full moon it sets around sunrise
+        let moon = get_moon_times(18, 9, 2024, 7.0, &HANOI);
+        let sun = get_sun_times(18, 9, 2024, 7.0, &HANOI);
+        let set = minutes(moon.moonset.as_ref().unwrap());
+        let sunrise = minutes(sun.sunrise.as_ref().unwrap());
+        assert!(
+            (set - sunrise).abs() <= 45,
+            "moonset {set}, sunrise {sunrise}"
+        );
+    }
+
+    #[test]
+    fn test_new_moon_transits_near_noon() {
+        // New moon 2024-04-08 18:21 UTC
+        let moon = get_moon_times(9, 4, 2024, 7.0, &HANOI);
+        let sun = get_sun_times(9, 4, 2024, 7.0, &HANOI);
+        let transit = minutes(moon.transit.as_ref().unwrap());
+        let noon = minutes(&sun.solar_noon);
+        assert!(
+            (transit - noon).abs() <= 60,
+            "transit {transit}, noon {noon}"
+        );
+        assert!(moon.transit_altitude.unwrap() > 60.0);
+    }
+
+    #[test]
+    fn test_altitude_at_events() {
+        let moon = get_moon_times(17, 9, 2024, 7.0, &HANOI);
+        let rise = moon.moonrise.unwrap();
+        let jd = jd_from_date(rise.day, rise.month, rise.year) as f64 - 0.5 - 7.0 / 24.0
+            + (rise.hour * 60 + rise.minute) as f64 / 1440.0;
+        let position = get_moon_position(jd, &HANOI);
+        // Centre about 50' below the horizon, in the east
+        assert!(
+            (position.altitude - -0.8).abs() < 0.3,
+            "h = {}",
+            position.altitude
+        );
+        assert!((60.0..120.0).contains(&position.azimuth));
+    }
+
+    #[test]
+    fn test_one_day_a_month_without_each_event() {
+        let days: Vec<MoonTimes> = (1..=30)
+            .map(|day| get_moon_times(day, 9, 2024, 7.0, &HANOI))
+            .collect();
+        let missing = |f: fn(&MoonTimes) -> bool| days.iter().filter(|t| f(t)).count();
+        assert_eq!(missing(|t| t.moonrise.is_none()), 1);
+        assert_eq!(missing(|t| t.moonset.is_none()), 1);
+        assert_eq!(missing(|t| t.transit.is_none()), 1);
+    }
+}
@@ -0,0 +1,90 @@
+//! The JSON files under `data/`, baked into `static` value trees by
+//! `build.rs`.
+//!
+//! The feature modules deserialize their typed tables straight from these
+//! trees, so the almanac, insight and holiday data need neither a JSON parser
+//! nor std at runtime.
+
+use serde::de::value::{Error, MapDeserializer, SeqDeserializer, StrDeserializer};
+use serde::de::{self, IntoDeserializer, Visitor};
+use serde::Deserialize;
+
+/// A JSON value whose strings, arrays and objects live in static memory.
+///
+/// The data files use no `null`s or fractional numbers, so neither has a
+/// variant; `build.rs` rejects them. Optional fields are left out instead.
+#[derive(Debug)]
+pub(crate) enum StaticValue {
+    Bool(bool),
+    Int(i64),
+    Str(&'static str),
+    Array(&'static [StaticValue]),
+    Object(&'static [(&'static str, StaticValue)]),
+}
+
+include!(concat!(env!("OUT_DIR"), "/static_data.rs"));
+
+/// Deserializes a baked table, panicking with `path` if it does not match
+/// `T` (the data is fixed at build time, so this is a packaging bug).
+pub(crate) fn load<T: Deserialize<'static>>(value: &'static StaticValue, path: &str) -> T {
+    T::deserialize(value).unwrap_or_else(|e| panic!("Failed to parse {path}: {e}"))
+}
+
+impl<'de> IntoDeserializer<'de, Error> for &'static StaticValue {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &'static StaticValue {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match *self {
+            StaticValue::Bool(b) => visitor.visit_bool(b),
+            StaticValue::Int(i) => visitor.visit_i64(i),
+            StaticValue::Str(s) => visitor.visit_str(s),
+            StaticValue::Array(items) => {
+                let mut seq = SeqDeserializer::new(items.iter());
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            StaticValue::Object(fields) => {
+                let mut map = MapDeserializer::new(fields.iter().map(|(k, v)| (*k, v)));
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match *self {
+            StaticValue::Str(s) => {
+                StrDeserializer::<Error>::new(s).deserialize_enum(name, variants, visitor)
+            }
+            _ => Err(de::Error::invalid_type(
+                de::Unexpected::Other("non-string value"),
+                &"a unit variant name",
+            )),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
@@ -3,7 +3,10 @@
  *
  * Algorithm from "Astronomical Algorithms" by Jean Meeus, 1998
  */
-use std::f64::consts::PI;
+use crate::ephemeris::{sun_longitude_with_mode, EphemerisMode};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use core::f64::consts::PI;
 
 /// Compute the longitude of the sun at any time
 ///
@@ -54,8 +57,21 @@ pub fn sun_longitude(jdn: f64) -> f64 {
 /// # Returns
 /// Sun longitude index (0-11)
 pub fn get_sun_longitude(day_number: i32, time_zone: f64) -> i32 {
+    get_sun_longitude_with_mode(day_number, time_zone, EphemerisMode::Fast)
+}
+
+/// Compute sun position at local midnight with a selectable ephemeris
+///
+/// # Arguments
+/// * `day_number` - Julian day number
+/// * `time_zone` - Time zone offset (e.g., 7.0 for UTC+7:00)
+/// * `mode` - Ephemeris mode
+///
+/// # Returns
+/// Sun longitude index (0-11)
+pub fn get_sun_longitude_with_mode(day_number: i32, time_zone: f64, mode: EphemerisMode) -> i32 {
     let jdn = day_number as f64 - 0.5 - time_zone / 24.0;
-    let longitude = sun_longitude(jdn);
+    let longitude = sun_longitude_with_mode(jdn, mode);
     (longitude / PI * 6.0).floor() as i32
 }
 
@@ -75,7 +91,7 @@ mod tests {
         for jd in test_jds {
             let sl = sun_longitude(jd);
             assert!(
-                sl >= 0.0 && sl < 2.0 * PI,
+                (0.0..2.0 * PI).contains(&sl),
                 "Sun longitude {} not in [0, 2π)",
                 sl
             );
@@ -94,7 +110,7 @@ mod tests {
         for day in test_days {
             let sl = get_sun_longitude(day, 7.0);
             assert!(
-                sl >= 0 && sl <= 11,
+                (0..=11).contains(&sl),
                 "Sun longitude index {} not in [0, 11]",
                 sl
             );
@@ -111,7 +127,7 @@ mod tests {
             let sl = sun_longitude(jd);
 
             // Sun longitude should be between 0 and 2π
-            assert!(sl >= 0.0 && sl < 2.0 * PI);
+            assert!((0.0..2.0 * PI).contains(&sl));
         }
     }
 
@@ -0,0 +1,245 @@
+/**
+ * Sunrise, Sunset and Twilight
+ *
+ * Rise/set times from the low-precision solar coordinates of
+ * "Astronomical Algorithms" by Jean Meeus, 1998 (chapters 15 and 25), as
+ * used by the NOAA solar calculator. Times are accurate to about a minute
+ * away from the polar circles.
+ */
+use crate::julian::{jd_from_date, jd_to_datetime, JulianDateTime};
+use crate::location::Location;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use core::f64::consts::PI;
+
+/// Altitude of the sun's centre at sunrise/sunset: refraction (34') plus
+/// the semi-diameter (16')
+const SUNRISE_ALTITUDE: f64 = -0.833;
+
+/// Altitude of the sun's centre at civil dawn/dusk
+const CIVIL_TWILIGHT_ALTITUDE: f64 = -6.0;
+
+/// Sun events of one local day at a location
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SunTimes {
+    pub location: Location,
+    /// `None` when the sun does not rise (polar night) or set (midnight sun)
+    pub sunrise: Option<JulianDateTime>,
+    pub sunset: Option<JulianDateTime>,
+    pub solar_noon: JulianDateTime,
+    /// Start of civil twilight (sun 6° below the horizon)
+    pub civil_dawn: Option<JulianDateTime>,
+    /// End of civil twilight
+    pub civil_dusk: Option<JulianDateTime>,
+    /// Minutes between sunrise and sunset (0 or 1440 without a sunrise)
+    pub day_length_minutes: i32,
+}
+
+/// Declination (radians) and equation of time (minutes) at a Julian day
+fn solar_coordinates(jd: f64) -> (f64, f64) {
+    let dr = PI / 180.0;
+    let t = (jd - 2451545.0) / 36525.0;
+
+    let l0 = (280.46646 + t * (36000.76983 + t * 0.0003032)).rem_euclid(360.0);
+    let m = 357.52911 + t * (35999.05029 - 0.0001537 * t);
+    let e = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+    let c = (m * dr).sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + (2.0 * m * dr).sin() * (0.019993 - 0.000101 * t)
+        + (3.0 * m * dr).sin() * 0.000289;
+
+    let omega = 125.04 - 1934.136 * t;
+    let lambda = l0 + c - 0.00569 - 0.00478 * (omega * dr).sin();
+    let seconds = 21.448 - t * (46.815 + t * (0.00059 - t * 0.001813));
+    let epsilon = 23.0 + (26.0 + seconds / 60.0) / 60.0 + 0.00256 * (omega * dr).cos();
+
+    let declination = ((epsilon * dr).sin() * (lambda * dr).sin()).asin();
+
+    let y = (epsilon * dr / 2.0).tan().powi(2);
+    let equation_of_time = y * (2.0 * l0 * dr).sin() - 2.0 * e * (m * dr).sin()
+        + 4.0 * e * y * (m * dr).sin() * (2.0 * l0 * dr).cos()
+        - 0.5 * y * y * (4.0 * l0 * dr).sin()
+        - 1.25 * e * e * (2.0 * m * dr).sin();
+
+    (declination, 4.0 * equation_of_time / dr)
+}
+
+/// Equation of time in minutes at a Julian day
+///
+/// Apparent minus mean solar time: positive when a sundial runs ahead of
+/// the clock (about +16 minutes in early November, -14 in mid February).
+pub fn equation_of_time(jd: f64) -> f64 {
+    solar_coordinates(jd).1
+}
+
+/// Hour angle (degrees) at which the sun's centre reaches `altitude`, or
+/// `None` if it stays above or below it all day
+fn hour_angle(latitude: f64, declination: f64, altitude: f64) -> Option<f64> {
+    let dr = PI / 180.0;
+    let phi = latitude * dr;
+    let cos_h =
+        ((altitude * dr).sin() - phi.sin() * declination.sin()) / (phi.cos() * declination.cos());
+    if (-1.0..=1.0).contains(&cos_h) {
+        Some(cos_h.acos() / dr)
+    } else {
+        None
+    }
+}
+
+/// Julian day of solar noon on the local date starting at `local_midnight`
+fn solar_noon_jd(local_midnight: f64, longitude: f64) -> f64 {
+    let utc_midnight = local_midnight.floor() + 0.5;
+    let mut noon = utc_midnight + (720.0 - 4.0 * longitude) / 1440.0;
+    for _ in 0..2 {
+        let (_, eot) = solar_coordinates(noon);
+        noon = utc_midnight + (720.0 - 4.0 * longitude - eot) / 1440.0;
+    }
+    // Keep the event on the requested local date
+    if noon < local_midnight {
+        noon += 1.0;
+    } else if noon >= local_midnight + 1.0 {
+        noon -= 1.0;
+    }
+    noon
+}
+
+/// Julian day when the sun's centre crosses `altitude`, rising or setting
+fn altitude_crossing_jd(
+    noon: f64,
+    location: &Location,
+    altitude: f64,
+    rising: bool,
+) -> Option<f64> {
+    let sign = if rising { -1.0 } else { 1.0 };
+    let mut event = noon;
+    for _ in 0..3 {
+        let (declination, _) = solar_coordinates(event);
+        let h = hour_angle(location.latitude, declination, altitude)?;
+        event = noon + sign * h / 360.0;
+    }
+    Some(event)
+}
+
+/// Compute sunrise, sunset, solar noon and civil twilight
+///
+/// # Arguments
+/// * `day` - Day (1-31)
+/// * `month` - Month (1-12)
+/// * `year` - Year
+/// * `time_zone` - Time zone offset of the returned times (7.0 for UTC+7:00)
+/// * `location` - Observer location; elevation lowers the apparent horizon
+///
+/// # Returns
+/// Sun events of the local date
+pub fn get_sun_times(
+    day: i32,
+    month: i32,
+    year: i32,
+    time_zone: f64,
+    location: &Location,
+) -> SunTimes {
+    let local_midnight = jd_from_date(day, month, year) as f64 - 0.5 - time_zone / 24.0;
+    let noon = solar_noon_jd(local_midnight, location.longitude);
+
+    let horizon = SUNRISE_ALTITUDE - 0.0347 * location.elevation.max(0.0).sqrt();
+    let sunrise = altitude_crossing_jd(noon, location, horizon, true);
+    let sunset = altitude_crossing_jd(noon, location, horizon, false);
+    let civil_dawn = altitude_crossing_jd(noon, location, CIVIL_TWILIGHT_ALTITUDE, true);
+    let civil_dusk = altitude_crossing_jd(noon, location, CIVIL_TWILIGHT_ALTITUDE, false);
+
+    let day_length_minutes = match (sunrise, sunset) {
+        (Some(rise), Some(set)) => ((set - rise) * 1440.0).round() as i32,
+        _ => {
+            // No crossing: the sun is either up or down all day
+            let (declination, _) = solar_coordinates(noon);
+            let noon_altitude = 90.0 - (location.latitude - declination.to_degrees()).abs();
+            if noon_altitude > horizon {
+                1440
+            } else {
+                0
+            }
+        }
+    };
+
+    let local = |jd: f64| jd_to_datetime(jd, time_zone);
+    SunTimes {
+        location: *location,
+        sunrise: sunrise.map(local),
+        sunset: sunset.map(local),
+        solar_noon: local(noon),
+        civil_dawn: civil_dawn.map(local),
+        civil_dusk: civil_dusk.map(local),
+        day_length_minutes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::location::{HANOI, HO_CHI_MINH_CITY};
+
+    fn minutes(t: &JulianDateTime) -> i32 {
+        t.hour * 60 + t.minute
+    }
+
+    fn assert_near(actual: &JulianDateTime, hour: i32, minute: i32, tolerance: i32) {
+        let diff = (minutes(actual) - (hour * 60 + minute)).abs();
+        assert!(
+            diff <= tolerance,
+            "expected {hour:02}:{minute:02}, got {:02}:{:02}",
+            actual.hour,
+            actual.minute
+        );
+    }
+
+    #[test]
+    fn test_hanoi_summer_solstice() {
+        // Hà Nội 2024-06-21 by hand (δ = 23.44°, EoT = -1.8 min, H = 100.6°):
+        // rise 05:16, noon 11:58, set 18:41
+        let times = get_sun_times(21, 6, 2024, 7.0, &HANOI);
+        assert_near(times.sunrise.as_ref().unwrap(), 5, 16, 1);
+        assert_near(&times.solar_noon, 11, 58, 1);
+        assert_near(times.sunset.as_ref().unwrap(), 18, 41, 1);
+        assert_eq!(times.civil_dawn.unwrap().hour, 4);
+        assert!((802..=807).contains(&times.day_length_minutes));
+    }
+
+    #[test]
+    fn test_solar_noon_follows_equation_of_time() {
+        // Early November the sun is about 16 minutes fast
+        let times = get_sun_times(3, 11, 2024, 7.0, &HANOI);
+        assert_near(&times.solar_noon, 11, 40, 1);
+        // Near the equator the day length stays close to 12 hours
+        let hcm = get_sun_times(20, 3, 2024, 7.0, &HO_CHI_MINH_CITY);
+        assert!((723..=731).contains(&hcm.day_length_minutes));
+    }
+
+    #[test]
+    fn test_equation_of_time_extremes() {
+        let nov = equation_of_time(jd_from_date(3, 11, 2024) as f64);
+        let feb = equation_of_time(jd_from_date(11, 2, 2024) as f64);
+        assert!((16.0..16.6).contains(&nov), "{nov}");
+        assert!((-14.4..-14.0).contains(&feb), "{feb}");
+    }
+
+    #[test]
+    fn test_elevation_lengthens_day() {
+        let sea = get_sun_times(1, 1, 2025, 7.0, &HANOI);
+        let high = get_sun_times(1, 1, 2025, 7.0, &HANOI.with_elevation(3000.0));
+        assert!(high.day_length_minutes > sea.day_length_minutes);
+    }
+
+    #[test]
+    fn test_polar_day_and_night() {
+        let tromso = Location::new(69.65, 18.96);
+        let summer = get_sun_times(21, 6, 2024, 2.0, &tromso);
+        assert!(summer.sunrise.is_none() && summer.sunset.is_none());
+        assert_eq!(summer.day_length_minutes, 1440);
+
+        let winter = get_sun_times(21, 12, 2024, 1.0, &tromso);
+        assert!(winter.sunrise.is_none());
+        assert_eq!(winter.day_length_minutes, 0);
+        // Civil twilight still occurs around noon
+        assert!(winter.civil_dawn.is_some());
+    }
+}
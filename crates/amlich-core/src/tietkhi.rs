@@ -1,4 +1,10 @@
-use crate::sun::sun_longitude;
+use crate::ephemeris::{sun_longitude_with_mode, EphemerisMode};
+use crate::julian::{jd_from_date, jd_to_datetime, JulianDateTime};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::types::{Locale, ParseNameError};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 /**
  * Tiết Khí (24 Solar Terms) Calculations
  *
@@ -12,11 +18,15 @@ use crate::sun::sun_longitude;
  * - Based on astronomical calculations from Jean Meeus
  * - Traditional Vietnamese naming
  */
-use std::f64::consts::PI;
+use core::f64::consts::PI;
+use core::fmt;
+use core::str::FromStr;
 
 /// Information about a solar term
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SolarTerm {
+    pub id: SolarTermId,
     pub index: usize,
     pub name: String,
     pub description: String,
@@ -157,6 +167,144 @@ pub const TIET_KHI: [SolarTermDef; 24] = [
     },
 ];
 
+const TIET_KHI_ZH: [&str; 24] = [
+    "春分", "清明", "谷雨", "立夏", "小满", "芒种", "夏至", "小暑", "大暑", "立秋", "处暑", "白露",
+    "秋分", "寒露", "霜降", "立冬", "小雪", "大雪", "冬至", "小寒", "大寒", "立春", "雨水", "惊蛰",
+];
+
+/// One of the 24 solar terms, in `TIET_KHI` order (Xuân Phân = 0)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SolarTermId {
+    XuanPhan,
+    ThanhMinh,
+    CocVu,
+    LapHa,
+    TieuMan,
+    MangChung,
+    HaChi,
+    TieuThu,
+    DaiThu,
+    LapThu,
+    XuThu,
+    BachLo,
+    ThuPhan,
+    HanLo,
+    SuongGiang,
+    LapDong,
+    TieuTuyet,
+    DaiTuyet,
+    DongChi,
+    TieuHan,
+    DaiHan,
+    LapXuan,
+    VuThuy,
+    KinhTrap,
+}
+
+impl SolarTermId {
+    /// All terms in `TIET_KHI` order, starting with Xuân Phân
+    pub const ALL: [SolarTermId; 24] = [
+        SolarTermId::XuanPhan,
+        SolarTermId::ThanhMinh,
+        SolarTermId::CocVu,
+        SolarTermId::LapHa,
+        SolarTermId::TieuMan,
+        SolarTermId::MangChung,
+        SolarTermId::HaChi,
+        SolarTermId::TieuThu,
+        SolarTermId::DaiThu,
+        SolarTermId::LapThu,
+        SolarTermId::XuThu,
+        SolarTermId::BachLo,
+        SolarTermId::ThuPhan,
+        SolarTermId::HanLo,
+        SolarTermId::SuongGiang,
+        SolarTermId::LapDong,
+        SolarTermId::TieuTuyet,
+        SolarTermId::DaiTuyet,
+        SolarTermId::DongChi,
+        SolarTermId::TieuHan,
+        SolarTermId::DaiHan,
+        SolarTermId::LapXuan,
+        SolarTermId::VuThuy,
+        SolarTermId::KinhTrap,
+    ];
+
+    /// Term for an index, wrapping modulo 24
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index % 24]
+    }
+
+    /// 0-based position in `TIET_KHI`
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Definition (name, description, longitude) of the term
+    pub fn def(self) -> &'static SolarTermDef {
+        &TIET_KHI[self.index()]
+    }
+
+    /// Vietnamese name
+    pub fn name(self) -> &'static str {
+        self.def().name
+    }
+
+    /// Name in the given locale
+    pub fn label(self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Vi => self.name(),
+            // Descriptions read "Spring Equinox (Xuân Phân)"
+            Locale::En => self
+                .def()
+                .description
+                .split(" (")
+                .next()
+                .unwrap_or(self.def().description),
+            Locale::Zh => TIET_KHI_ZH[self.index()],
+        }
+    }
+
+    /// Ecliptic longitude (degrees) at which the term begins
+    pub fn longitude(self) -> i32 {
+        self.def().longitude
+    }
+}
+
+impl fmt::Display for SolarTermId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for SolarTermId {
+    type Err = ParseNameError;
+
+    /// Parse a Vietnamese, English or Chinese term name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Self::ALL
+            .into_iter()
+            .find(|term| Locale::ALL.iter().any(|&locale| term.label(locale) == s))
+            .ok_or_else(|| ParseNameError::new("solar term", s))
+    }
+}
+
+// Serialized as the Vietnamese name, like `Can` and `Chi`
+#[cfg(feature = "serde")]
+impl serde::Serialize for SolarTermId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SolarTermId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::types::deserialize_name(deserializer)
+    }
+}
+
 /// Get season name from term index
 ///
 /// # Arguments
@@ -182,8 +330,21 @@ pub fn get_season(term_index: usize) -> &'static str {
 /// # Returns
 /// Solar term information
 pub fn get_tiet_khi(jd: i32, time_zone: f64) -> SolarTerm {
+    get_tiet_khi_with_mode(jd, time_zone, EphemerisMode::Fast)
+}
+
+/// Get Solar Term (Tiết Khí) for a given date with a selectable ephemeris
+///
+/// # Arguments
+/// * `jd` - Julian Day Number
+/// * `time_zone` - Timezone offset (default: 7 for Vietnam)
+/// * `mode` - Ephemeris mode
+///
+/// # Returns
+/// Solar term information
+pub fn get_tiet_khi_with_mode(jd: i32, time_zone: f64, mode: EphemerisMode) -> SolarTerm {
     // Calculate sun longitude at local midnight
-    let sun_long_rad = sun_longitude(jd as f64 - 0.5 - time_zone / 24.0);
+    let sun_long_rad = sun_longitude_with_mode(jd as f64 - 0.5 - time_zone / 24.0, mode);
 
     // Convert radians to degrees
     let sun_long_deg = (sun_long_rad * 180.0 / PI) % 360.0;
@@ -194,6 +355,7 @@ pub fn get_tiet_khi(jd: i32, time_zone: f64) -> SolarTerm {
     let term = &TIET_KHI[term_index];
 
     SolarTerm {
+        id: SolarTermId::from_index(term_index),
         index: term_index,
         name: term.name.to_string(),
         description: term.description.to_string(),
@@ -205,6 +367,7 @@ pub fn get_tiet_khi(jd: i32, time_zone: f64) -> SolarTerm {
 
 /// Solar term with date information
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SolarTermWithDate {
     pub jd: i32,
     pub index: usize,
@@ -225,8 +388,6 @@ pub struct SolarTermWithDate {
 /// # Returns
 /// Vector of solar terms with dates
 pub fn get_all_tiet_khi_for_year(year: i32, time_zone: f64) -> Vec<SolarTermWithDate> {
-    use crate::julian::jd_from_date;
-
     let mut terms = Vec::new();
     let start_jd = jd_from_date(1, 1, year);
     let end_jd = jd_from_date(31, 12, year);
@@ -254,10 +415,137 @@ pub fn get_all_tiet_khi_for_year(year: i32, time_zone: f64) -> Vec<SolarTermWith
     terms
 }
 
+/// Exact moment a solar term begins
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolarTermInstant {
+    pub index: usize,
+    pub name: String,
+    pub longitude: i32,
+    /// Julian day (fractional, UT) at which the sun reaches `longitude`
+    pub jd: f64,
+    pub utc: JulianDateTime,
+    pub local: JulianDateTime,
+}
+
+/// Mean tropical year in days, used to turn a longitude gap into a time step
+const TROPICAL_YEAR: f64 = 365.242189;
+
+/// Find the instant the sun's longitude crosses `target_deg`
+///
+/// Iterates from `jd_estimate` using the mean solar motion as the slope. The
+/// true motion stays within a few percent of the mean, so each step shrinks the
+/// error by more than an order of magnitude.
+fn find_sun_longitude_crossing(target_deg: f64, jd_estimate: f64, mode: EphemerisMode) -> f64 {
+    let mut jd = jd_estimate;
+
+    for _ in 0..20 {
+        let current_deg = sun_longitude_with_mode(jd, mode) * 180.0 / PI;
+        // Signed gap in (-180, 180] so the search can move either way
+        let gap = (target_deg - current_deg + 540.0).rem_euclid(360.0) - 180.0;
+        let step = gap * TROPICAL_YEAR / 360.0;
+        jd += step;
+
+        if step.abs() < 1e-6 {
+            break;
+        }
+    }
+
+    jd
+}
+
+/// Get the exact instant a solar term begins in the given year
+///
+/// Uses the same sun longitude as `get_tiet_khi`, so the day-level term
+/// reported there changes at the first local midnight after this instant.
+///
+/// # Arguments
+/// * `year` - Solar year
+/// * `term` - Solar term
+/// * `time_zone` - Timezone offset for the `local` field
+///
+/// # Returns
+/// Solar term instant in UT and local time, to the minute
+pub fn get_tiet_khi_instant(year: i32, term: SolarTermId, time_zone: f64) -> SolarTermInstant {
+    get_tiet_khi_instant_with_mode(year, term, time_zone, EphemerisMode::Fast)
+}
+
+/// Get the exact instant a solar term begins with a selectable ephemeris
+///
+/// # Arguments
+/// * `year` - Solar year
+/// * `term` - Solar term
+/// * `time_zone` - Timezone offset for the `local` field
+/// * `mode` - Ephemeris mode
+///
+/// # Returns
+/// Solar term instant in UT and local time, to the minute
+pub fn get_tiet_khi_instant_with_mode(
+    year: i32,
+    term_id: SolarTermId,
+    time_zone: f64,
+    mode: EphemerisMode,
+) -> SolarTermInstant {
+    let term = term_id.def();
+
+    // Xuân Phân falls around March 20; the terms from Tiểu Hàn (285°) to
+    // Kinh Trập (345°) come before it in the same solar year.
+    let equinox = jd_from_date(20, 3, year) as f64;
+    let mut offset = term.longitude as f64 / 360.0 * TROPICAL_YEAR;
+    if term.longitude >= 285 {
+        offset -= TROPICAL_YEAR;
+    }
+
+    let jd = find_sun_longitude_crossing(term.longitude as f64, equinox + offset, mode);
+
+    SolarTermInstant {
+        index: term_id.index(),
+        name: term.name.to_string(),
+        longitude: term.longitude,
+        jd,
+        utc: jd_to_datetime(jd, 0.0),
+        local: jd_to_datetime(jd, time_zone),
+    }
+}
+
+/// Get the exact instants of all 24 solar terms in a year
+///
+/// # Arguments
+/// * `year` - Solar year
+/// * `time_zone` - Timezone offset for the `local` field
+///
+/// # Returns
+/// Solar term instants in chronological order, from Tiểu Hàn to Đông Chí
+pub fn get_tiet_khi_instants_for_year(year: i32, time_zone: f64) -> Vec<SolarTermInstant> {
+    get_tiet_khi_instants_for_year_with_mode(year, time_zone, EphemerisMode::Fast)
+}
+
+/// Get the exact instants of all 24 solar terms with a selectable ephemeris
+///
+/// # Arguments
+/// * `year` - Solar year
+/// * `time_zone` - Timezone offset for the `local` field
+/// * `mode` - Ephemeris mode
+///
+/// # Returns
+/// Solar term instants in chronological order, from Tiểu Hàn to Đông Chí
+pub fn get_tiet_khi_instants_for_year_with_mode(
+    year: i32,
+    time_zone: f64,
+    mode: EphemerisMode,
+) -> Vec<SolarTermInstant> {
+    (19..24)
+        .chain(0..19)
+        .map(|index| {
+            get_tiet_khi_instant_with_mode(year, SolarTermId::from_index(index), time_zone, mode)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::julian::jd_from_date;
+    use crate::sun::sun_longitude;
 
     #[test]
     fn test_tiet_khi_constants() {
@@ -268,6 +556,19 @@ mod tests {
         assert_eq!(TIET_KHI[12].longitude, 180);
     }
 
+    #[test]
+    fn test_solar_term_id_matches_table() {
+        for (i, id) in SolarTermId::ALL.iter().enumerate() {
+            assert_eq!(id.index(), i);
+            assert_eq!(id.name(), TIET_KHI[i].name);
+            assert_eq!(id.longitude(), TIET_KHI[i].longitude);
+            assert_eq!(id.name().parse::<SolarTermId>(), Ok(*id));
+        }
+        assert_eq!(SolarTermId::LapXuan.label(Locale::En), "Start of Spring");
+        assert_eq!(SolarTermId::DongChi.label(Locale::Zh), "冬至");
+        assert!("Lap Xuan".parse::<SolarTermId>().is_err());
+    }
+
     #[test]
     fn test_get_season() {
         assert_eq!(get_season(0), "Xuân (Spring)");
@@ -373,4 +674,97 @@ mod tests {
         assert!(term_autumn.season.contains("Autumn"));
         assert!(term_winter.season.contains("Winter") || term_winter.season.contains("Spring"));
     }
+
+    #[test]
+    fn test_tiet_khi_instants_for_year() {
+        let instants = get_tiet_khi_instants_for_year(2024, 7.0);
+
+        assert_eq!(instants.len(), 24);
+        assert_eq!(instants[0].name, "Tiểu Hàn");
+        assert_eq!(instants[23].name, "Đông Chí");
+
+        for pair in instants.windows(2) {
+            assert!(
+                pair[1].jd > pair[0].jd,
+                "Instants not in chronological order"
+            );
+        }
+
+        for instant in &instants {
+            assert_eq!(instant.local.year, 2024);
+            let longitude = sun_longitude(instant.jd) * 180.0 / PI;
+            let gap = (longitude - instant.longitude as f64 + 540.0).rem_euclid(360.0) - 180.0;
+            assert!(gap.abs() < 1e-5, "{} off by {}°", instant.name, gap);
+        }
+    }
+
+    #[test]
+    fn test_tiet_khi_instant_lap_xuan_2024() {
+        // Lập Xuân 2024 began on 2024-02-04 around 08:27 UTC (15:27 in Vietnam).
+        // The truncated Meeus series is good to roughly a quarter of an hour.
+        let instant = get_tiet_khi_instant(2024, SolarTermId::LapXuan, 7.0);
+
+        assert_eq!(instant.name, "Lập Xuân");
+        assert_eq!(
+            (instant.utc.day, instant.utc.month, instant.utc.year),
+            (4, 2, 2024)
+        );
+        assert_eq!((instant.local.day, instant.local.month), (4, 2));
+        let local_minutes = instant.local.hour * 60 + instant.local.minute;
+        assert!(
+            (local_minutes - (15 * 60 + 27)).abs() <= 20,
+            "Lập Xuân at {:02}:{:02}",
+            instant.local.hour,
+            instant.local.minute
+        );
+    }
+
+    #[test]
+    fn test_tiet_khi_instants_match_day_scan() {
+        // The day scan switches terms at the first local midnight after the instant
+        let scanned = get_all_tiet_khi_for_year(2024, 7.0);
+        let instants = get_tiet_khi_instants_for_year(2024, 7.0);
+
+        for term in scanned.iter().skip(1) {
+            let instant = instants
+                .iter()
+                .find(|instant| instant.index == term.index)
+                .expect("every scanned term has an instant");
+            let local_day =
+                jd_from_date(instant.local.day, instant.local.month, instant.local.year);
+            let midnight_after = (instant.jd + 0.5 + 7.0 / 24.0).floor() as i32 + 1;
+            assert_eq!(term.jd, midnight_after, "{}", term.name);
+            assert!(term.jd - local_day <= 1);
+        }
+    }
+
+    #[test]
+    fn test_tiet_khi_instant_accurate_mode() {
+        // Lập Xuân 2024: 2024-02-04 08:27 UTC
+        let instant = get_tiet_khi_instant_with_mode(
+            2024,
+            SolarTermId::LapXuan,
+            7.0,
+            EphemerisMode::Accurate,
+        );
+        assert_eq!((instant.utc.day, instant.utc.month), (4, 2));
+        let utc_minutes = instant.utc.hour * 60 + instant.utc.minute;
+        assert!(
+            (utc_minutes - (8 * 60 + 27)).abs() <= 1,
+            "Lập Xuân at {:02}:{:02} UTC",
+            instant.utc.hour,
+            instant.utc.minute
+        );
+
+        // Đông Chí 2024: 2024-12-21 09:20 UTC
+        let instant = get_tiet_khi_instant_with_mode(
+            2024,
+            SolarTermId::DongChi,
+            7.0,
+            EphemerisMode::Accurate,
+        );
+        assert_eq!((instant.utc.day, instant.utc.month), (21, 12));
+        let utc_minutes = instant.utc.hour * 60 + instant.utc.minute;
+        assert!((utc_minutes - (9 * 60 + 20)).abs() <= 1);
+    }
 }
@@ -0,0 +1,337 @@
+/**
+ * Time Zones
+ *
+ * The lunar date of a solar day depends on where it is observed: new moons
+ * and solar terms fall on the local calendar day. The `time_zone: f64` hour
+ * offsets used across the crate cannot express daylight saving time, so
+ * `TimeZone` is either a fixed offset in whole minutes or (with the `tz`
+ * feature) an IANA zone whose offset is resolved for each date.
+ *
+ * The offset for a date is the one in effect at local noon, which no DST
+ * transition skips or repeats. Month boundaries use the offset in effect at
+ * each new moon instead (`offset_minutes_at`).
+ */
+#[cfg(feature = "tz")]
+use crate::chrono_interop::naive_date_from_jd;
+#[cfg(feature = "tz")]
+use crate::julian::jd_from_date;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use alloc::string::{String, ToString};
+#[cfg(feature = "chrono")]
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+#[cfg(feature = "tz")]
+use chrono::{NaiveTime, Offset, TimeZone as _};
+use core::fmt;
+use core::str::FromStr;
+
+/// Largest accepted fixed offset, in minutes (UTC+14:00 in Kiribati)
+const MAX_OFFSET_MINUTES: i32 = 14 * 60;
+
+/// A fixed UTC offset or an IANA time zone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeZone {
+    /// Minutes east of UTC (420 for UTC+7, -210 for UTC-3:30)
+    Fixed(i32),
+    /// IANA zone such as `Asia/Ho_Chi_Minh` or `Australia/Sydney`
+    #[cfg(feature = "tz")]
+    Iana(chrono_tz::Tz),
+}
+
+impl TimeZone {
+    /// Vietnam, UTC+7
+    pub const VIETNAM: TimeZone = TimeZone::Fixed(420);
+
+    /// Fixed offset from hours, as taken by the `time_zone: f64` functions
+    ///
+    /// Rounded to the minute.
+    pub fn from_hours(hours: f64) -> Self {
+        TimeZone::Fixed((hours * 60.0).round() as i32)
+    }
+
+    /// UTC offset in minutes in effect on a solar date
+    #[cfg_attr(not(feature = "tz"), allow(unused_variables))]
+    pub fn offset_minutes(&self, day: i32, month: i32, year: i32) -> i32 {
+        match self {
+            TimeZone::Fixed(minutes) => *minutes,
+            #[cfg(feature = "tz")]
+            TimeZone::Iana(tz) => {
+                let date = clamped_date(jd_from_date(day, month, year));
+                let noon = date
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap_or_else(|| date.and_time(NaiveTime::MIN));
+                let offset = tz
+                    .offset_from_local_datetime(&noon)
+                    .earliest()
+                    .unwrap_or_else(|| tz.offset_from_utc_datetime(&noon));
+                offset.fix().local_minus_utc() / 60
+            }
+        }
+    }
+
+    /// UTC offset in minutes in effect at an instant, given as a
+    /// (fractional) Julian day in UT
+    #[cfg_attr(not(feature = "tz"), allow(unused_variables))]
+    pub fn offset_minutes_at(&self, jd: f64) -> i32 {
+        match self {
+            TimeZone::Fixed(minutes) => *minutes,
+            #[cfg(feature = "tz")]
+            TimeZone::Iana(tz) => {
+                let day = (jd + 0.5).floor();
+                let seconds = ((jd + 0.5 - day) * 86400.0).round() as i64;
+                let midnight = clamped_date(day as i32).and_time(NaiveTime::MIN);
+                let utc = midnight
+                    .checked_add_signed(Duration::seconds(seconds))
+                    .unwrap_or(midnight);
+                tz.offset_from_utc_datetime(&utc).fix().local_minus_utc() / 60
+            }
+        }
+    }
+
+    /// UTC offset in hours in effect on a solar date, for the
+    /// `time_zone: f64` functions
+    pub fn offset_hours(&self, day: i32, month: i32, year: i32) -> f64 {
+        self.offset_minutes(day, month, year) as f64 / 60.0
+    }
+
+    /// Local calendar date at a UTC instant (e.g. "today" for a user)
+    #[cfg(feature = "chrono")]
+    pub fn local_date(&self, utc: NaiveDateTime) -> NaiveDate {
+        self.local_datetime(utc).date()
+    }
+
+    /// Local wall-clock time at a UTC instant
+    #[cfg(feature = "chrono")]
+    pub fn local_datetime(&self, utc: NaiveDateTime) -> NaiveDateTime {
+        match self {
+            TimeZone::Fixed(minutes) => utc + Duration::minutes(*minutes as i64),
+            #[cfg(feature = "tz")]
+            TimeZone::Iana(tz) => tz.from_utc_datetime(&utc).naive_local(),
+        }
+    }
+}
+
+/// Chrono date for a Julian day, clamped to chrono's range
+///
+/// That far out IANA zones only follow their local mean time (before the
+/// first rule) or their last, recurring rule, so the clamped date gives
+/// the same kind of offset instead of a panic.
+#[cfg(feature = "tz")]
+fn clamped_date(jd: i32) -> NaiveDate {
+    naive_date_from_jd(jd).unwrap_or(if jd < jd_from_date(1, 1, 1) {
+        NaiveDate::MIN
+    } else {
+        NaiveDate::MAX
+    })
+}
+
+impl Default for TimeZone {
+    fn default() -> Self {
+        TimeZone::VIETNAM
+    }
+}
+
+#[cfg(feature = "tz")]
+impl From<chrono_tz::Tz> for TimeZone {
+    fn from(tz: chrono_tz::Tz) -> Self {
+        TimeZone::Iana(tz)
+    }
+}
+
+impl fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeZone::Fixed(minutes) => {
+                let sign = if *minutes < 0 { '-' } else { '+' };
+                let abs = minutes.abs();
+                write!(f, "UTC{sign}{:02}:{:02}", abs / 60, abs % 60)
+            }
+            #[cfg(feature = "tz")]
+            TimeZone::Iana(tz) => f.write_str(tz.name()),
+        }
+    }
+}
+
+/// Error returned for text that is neither a UTC offset nor a known IANA zone
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTimeZoneError {
+    value: String,
+}
+
+impl fmt::Display for ParseTimeZoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown time zone: {:?} (expected an offset such as +07:00 or an IANA name such as Asia/Ho_Chi_Minh)",
+            self.value
+        )
+    }
+}
+
+impl core::error::Error for ParseTimeZoneError {}
+
+impl FromStr for TimeZone {
+    type Err = ParseTimeZoneError;
+
+    /// Parse a UTC offset (`+07:00`, `UTC+7`, `-0330`, `5.5`) or, with the
+    /// `tz` feature, an IANA zone name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(minutes) = parse_offset_minutes(s.trim()) {
+            return Ok(TimeZone::Fixed(minutes));
+        }
+        #[cfg(feature = "tz")]
+        if let Ok(tz) = s.trim().parse::<chrono_tz::Tz>() {
+            return Ok(TimeZone::Iana(tz));
+        }
+        Err(ParseTimeZoneError {
+            value: s.to_string(),
+        })
+    }
+}
+
+/// Minutes east of UTC for `Z`, `UTC`, `UTC+7`, `+07:00`, `-0330` or
+/// decimal hours such as `7` and `5.5`
+fn parse_offset_minutes(s: &str) -> Option<i32> {
+    let rest = ["UTC", "GMT", "utc", "gmt"]
+        .iter()
+        .find_map(|prefix| s.strip_prefix(prefix));
+    let rest = match rest {
+        Some("") => return Some(0),
+        Some(rest) => rest,
+        None if s == "Z" => return Some(0),
+        None => s,
+    };
+    let (sign, digits) = match rest.as_bytes().first()? {
+        b'+' => (1, &rest[1..]),
+        b'-' => (-1, &rest[1..]),
+        _ => (1, rest),
+    };
+    let is_number = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+    let minutes = if let Some((hours, minutes)) = digits.split_once(':') {
+        if !is_number(hours) || minutes.len() != 2 || !is_number(minutes) {
+            return None;
+        }
+        let minutes: i32 = minutes.parse().ok()?;
+        if minutes >= 60 {
+            return None;
+        }
+        hours.parse::<i32>().ok()? * 60 + minutes
+    } else if digits.len() == 4 && is_number(digits) {
+        let (hours, minutes) = digits.split_at(2);
+        let minutes: i32 = minutes.parse().ok()?;
+        if minutes >= 60 {
+            return None;
+        }
+        hours.parse::<i32>().ok()? * 60 + minutes
+    } else if digits.split('.').all(is_number) && digits.split('.').count() <= 2 {
+        let hours: f64 = digits.parse().ok()?;
+        (hours * 60.0).round() as i32
+    } else {
+        return None;
+    };
+    (minutes <= MAX_OFFSET_MINUTES).then_some(sign * minutes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_fixed_offsets() {
+        for (text, minutes) in [
+            ("+07:00", 420),
+            ("UTC+7", 420),
+            ("7", 420),
+            ("GMT", 0),
+            ("Z", 0),
+            ("-03:30", -210),
+            ("+0545", 345),
+            ("5.5", 330),
+            ("UTC-8", -480),
+        ] {
+            assert_eq!(text.parse(), Ok(TimeZone::Fixed(minutes)), "{text}");
+        }
+        for bad in [
+            "",
+            "+",
+            "+7:5",
+            "+25",
+            "07:60",
+            "UTC+",
+            "7.5.1",
+            "Mars/Olympus",
+        ] {
+            assert!(bad.parse::<TimeZone>().is_err(), "{bad:?} should not parse");
+        }
+    }
+
+    #[test]
+    fn test_fixed_display_round_trips() {
+        for zone in [TimeZone::VIETNAM, TimeZone::Fixed(-210), TimeZone::Fixed(0)] {
+            assert_eq!(zone.to_string().parse(), Ok(zone));
+        }
+        assert_eq!(TimeZone::Fixed(-210).to_string(), "UTC-03:30");
+        assert_eq!(TimeZone::from_hours(9.5), TimeZone::Fixed(570));
+        assert_eq!(TimeZone::VIETNAM.offset_hours(10, 2, 2024), 7.0);
+    }
+
+    #[test]
+    #[cfg(feature = "tz")]
+    fn test_iana_zone_follows_daylight_saving() {
+        let sydney: TimeZone = "Australia/Sydney".parse().unwrap();
+        assert_eq!(sydney.to_string(), "Australia/Sydney");
+        // AEDT (UTC+11) in January, AEST (UTC+10) in July
+        assert_eq!(sydney.offset_minutes(29, 1, 2025), 660);
+        assert_eq!(sydney.offset_minutes(15, 7, 2025), 600);
+
+        let los_angeles: TimeZone = "America/Los_Angeles".parse().unwrap();
+        // DST starts 2025-03-09; the offset at local noon is already PDT
+        assert_eq!(los_angeles.offset_minutes(8, 3, 2025), -480);
+        assert_eq!(los_angeles.offset_minutes(9, 3, 2025), -420);
+        // At an instant the switch is at 10:00 UTC (2:00 PST)
+        let midnight_utc = jd_from_date(9, 3, 2025) as f64 - 0.5;
+        assert_eq!(
+            los_angeles.offset_minutes_at(midnight_utc + 9.5 / 24.0),
+            -480
+        );
+        assert_eq!(
+            los_angeles.offset_minutes_at(midnight_utc + 10.5 / 24.0),
+            -420
+        );
+
+        // Saigon kept UTC+8 until 1975
+        let saigon: TimeZone = "Asia/Ho_Chi_Minh".parse().unwrap();
+        assert_eq!(saigon.offset_minutes(1, 1, 1970), 480);
+        assert_eq!(saigon.offset_minutes(1, 1, 2024), 420);
+
+        // Beyond chrono's range the zone keeps its outermost rules
+        assert_eq!(sydney.offset_minutes(1, 1, 300_000), 660);
+        assert_eq!(saigon.offset_minutes(1, 1, -300_000), 426);
+        assert_eq!(
+            los_angeles.offset_minutes_at(jd_from_date(1, 1, 300_000) as f64),
+            -480
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tz")]
+    fn test_local_date() {
+        let utc = NaiveDate::from_ymd_opt(2025, 1, 28)
+            .unwrap()
+            .and_hms_opt(14, 0, 0)
+            .unwrap();
+        let sydney: TimeZone = "Australia/Sydney".parse().unwrap();
+        assert_eq!(
+            sydney.local_date(utc),
+            NaiveDate::from_ymd_opt(2025, 1, 29).unwrap()
+        );
+        assert_eq!(
+            TimeZone::Fixed(-480).local_date(utc),
+            NaiveDate::from_ymd_opt(2025, 1, 28).unwrap()
+        );
+        assert_eq!(
+            sydney.local_datetime(utc).time(),
+            chrono::NaiveTime::from_hms_opt(1, 0, 0).unwrap()
+        );
+    }
+}
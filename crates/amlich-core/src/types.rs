@@ -4,6 +4,17 @@
 //! - Indices are 0-based
 //! - Timezone: UTC+7 (Vietnam)
 //! - Lunar month 1 branch = Dần (index 2)
+//! - `Can`, `Chi` and `Element` are the typed forms; the string tables below
+//!   hold their Vietnamese names
+
+use core::fmt;
+use core::ops::{Add, Sub};
+use core::str::FromStr;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+#[cfg(any(feature = "serde", feature = "almanac"))]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Vietnam timezone offset (UTC+7)
 pub const VIETNAM_TIMEZONE: f64 = 7.0;
@@ -34,6 +45,40 @@ pub const CON_GIAP: [&str; 12] = [
     "Hợi (Lợn)",
 ];
 
+// Nạp Âm - one name per pair of the 60 Can Chi cycle, from Giáp Tý / Ất Sửu
+pub const NA_AM: [&str; 30] = [
+    "Hải Trung Kim",
+    "Lư Trung Hỏa",
+    "Đại Lâm Mộc",
+    "Lộ Bàng Thổ",
+    "Kiếm Phong Kim",
+    "Sơn Đầu Hỏa",
+    "Giản Hạ Thủy",
+    "Thành Đầu Thổ",
+    "Bạch Lạp Kim",
+    "Dương Liễu Mộc",
+    "Tuyền Trung Thủy",
+    "Ốc Thượng Thổ",
+    "Tích Lịch Hỏa",
+    "Tùng Bách Mộc",
+    "Trường Lưu Thủy",
+    "Sa Trung Kim",
+    "Sơn Hạ Hỏa",
+    "Bình Địa Mộc",
+    "Bích Thượng Thổ",
+    "Kim Bạc Kim",
+    "Phúc Đăng Hỏa",
+    "Thiên Hà Thủy",
+    "Đại Dịch Thổ",
+    "Thoa Xuyến Kim",
+    "Tang Đố Mộc",
+    "Đại Khê Thủy",
+    "Sa Trung Thổ",
+    "Thiên Thượng Hỏa",
+    "Thạch Lựu Mộc",
+    "Đại Hải Thủy",
+];
+
 // Ngũ Hành (Five Elements) - aligned with CAN (2 stems per element)
 pub const NGU_HANH_CAN: [&str; 10] = [
     "Mộc", "Mộc", "Hỏa", "Hỏa", "Thổ", "Thổ", "Kim", "Kim", "Thủy", "Thủy",
@@ -55,20 +100,432 @@ pub const THU: [&str; 7] = [
     "Thứ Bảy",
 ];
 
+/// Language used for stem, branch, element and solar term labels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Locale {
+    /// Vietnamese (e.g. "Giáp", "Tý", "Mộc")
+    #[default]
+    Vi,
+    /// English; pinyin for stems and branches (e.g. "Jia", "Zi", "Wood")
+    En,
+    /// Chinese characters (e.g. "甲", "子", "木")
+    Zh,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 3] = [Locale::Vi, Locale::En, Locale::Zh];
+}
+
+/// Error returned when a stem, branch, element or solar term name is unknown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseNameError {
+    kind: &'static str,
+    value: String,
+}
+
+impl ParseNameError {
+    pub(crate) fn new(kind: &'static str, value: &str) -> Self {
+        Self {
+            kind,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {}: {}", self.kind, self.value)
+    }
+}
+
+impl core::error::Error for ParseNameError {}
+
+/// Thiên Can (Heavenly Stem)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Can {
+    Giap,
+    At,
+    Binh,
+    Dinh,
+    Mau,
+    Ky,
+    Canh,
+    Tan,
+    Nham,
+    Quy,
+}
+
+const CAN_EN: [&str; 10] = [
+    "Jia", "Yi", "Bing", "Ding", "Wu", "Ji", "Geng", "Xin", "Ren", "Gui",
+];
+const CAN_ZH: [&str; 10] = ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"];
+
+impl Can {
+    /// All stems in cycle order, starting with Giáp
+    pub const ALL: [Can; 10] = [
+        Can::Giap,
+        Can::At,
+        Can::Binh,
+        Can::Dinh,
+        Can::Mau,
+        Can::Ky,
+        Can::Canh,
+        Can::Tan,
+        Can::Nham,
+        Can::Quy,
+    ];
+
+    /// Stem for an index, wrapping modulo 10
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index % 10]
+    }
+
+    /// 0-based position in the cycle (Giáp = 0)
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Vietnamese name
+    pub fn name(self) -> &'static str {
+        CAN[self.index()]
+    }
+
+    /// Name in the given locale
+    pub fn label(self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Vi => self.name(),
+            Locale::En => CAN_EN[self.index()],
+            Locale::Zh => CAN_ZH[self.index()],
+        }
+    }
+
+    /// Ngũ Hành of the stem
+    pub fn element(self) -> Element {
+        Element::ALL[self.index() / 2]
+    }
+}
+
+impl fmt::Display for Can {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Can {
+    type Err = ParseNameError;
+
+    /// Parse a Vietnamese, pinyin or Chinese stem name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Self::ALL
+            .into_iter()
+            .find(|can| Locale::ALL.iter().any(|&locale| can.label(locale) == s))
+            .ok_or_else(|| ParseNameError::new("can", s))
+    }
+}
+
+impl Add<i32> for Can {
+    type Output = Can;
+
+    fn add(self, rhs: i32) -> Can {
+        Can::from_index(normalize_index(self.index() as i32 + rhs, 10))
+    }
+}
+
+impl Sub<i32> for Can {
+    type Output = Can;
+
+    fn sub(self, rhs: i32) -> Can {
+        self + -rhs
+    }
+}
+
+impl Sub for Can {
+    type Output = usize;
+
+    /// Steps forward from `rhs` to `self` (0-9)
+    fn sub(self, rhs: Can) -> usize {
+        (self.index() + 10 - rhs.index()) % 10
+    }
+}
+
+/// Địa Chi (Earthly Branch)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Chi {
+    Ty,
+    Suu,
+    Dan,
+    Mao,
+    Thin,
+    Ti,
+    Ngo,
+    Mui,
+    Than,
+    Dau,
+    Tuat,
+    Hoi,
+}
+
+const CHI_EN: [&str; 12] = [
+    "Zi", "Chou", "Yin", "Mao", "Chen", "Si", "Wu", "Wei", "Shen", "You", "Xu", "Hai",
+];
+const CHI_ZH: [&str; 12] = [
+    "子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌", "亥",
+];
+
+impl Chi {
+    /// All branches in cycle order, starting with Tý
+    pub const ALL: [Chi; 12] = [
+        Chi::Ty,
+        Chi::Suu,
+        Chi::Dan,
+        Chi::Mao,
+        Chi::Thin,
+        Chi::Ti,
+        Chi::Ngo,
+        Chi::Mui,
+        Chi::Than,
+        Chi::Dau,
+        Chi::Tuat,
+        Chi::Hoi,
+    ];
+
+    /// Branch for an index, wrapping modulo 12
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index % 12]
+    }
+
+    /// 0-based position in the cycle (Tý = 0)
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Vietnamese name
+    pub fn name(self) -> &'static str {
+        CHI[self.index()]
+    }
+
+    /// Name in the given locale
+    pub fn label(self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Vi => self.name(),
+            Locale::En => CHI_EN[self.index()],
+            Locale::Zh => CHI_ZH[self.index()],
+        }
+    }
+
+    /// Branch with its zodiac animal, e.g. "Tý (Chuột)"
+    pub fn con_giap(self) -> &'static str {
+        CON_GIAP[self.index()]
+    }
+
+    /// Ngũ Hành of the branch
+    pub fn element(self) -> Element {
+        match self {
+            Chi::Ty | Chi::Hoi => Element::Thuy,
+            Chi::Dan | Chi::Mao => Element::Moc,
+            Chi::Ti | Chi::Ngo => Element::Hoa,
+            Chi::Than | Chi::Dau => Element::Kim,
+            Chi::Suu | Chi::Thin | Chi::Mui | Chi::Tuat => Element::Tho,
+        }
+    }
+
+    /// The branch in direct conflict (lục xung), six steps away
+    pub fn opposing(self) -> Chi {
+        self + 6
+    }
+}
+
+impl fmt::Display for Chi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Chi {
+    type Err = ParseNameError;
+
+    /// Parse a Vietnamese, pinyin or Chinese branch name
+    ///
+    /// "Tị" is accepted as a spelling of "Tỵ".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s == "Tị" {
+            return Ok(Chi::Ti);
+        }
+        Self::ALL
+            .into_iter()
+            .find(|chi| Locale::ALL.iter().any(|&locale| chi.label(locale) == s))
+            .ok_or_else(|| ParseNameError::new("chi", s))
+    }
+}
+
+impl Add<i32> for Chi {
+    type Output = Chi;
+
+    fn add(self, rhs: i32) -> Chi {
+        Chi::from_index(normalize_index(self.index() as i32 + rhs, 12))
+    }
+}
+
+impl Sub<i32> for Chi {
+    type Output = Chi;
+
+    fn sub(self, rhs: i32) -> Chi {
+        self + -rhs
+    }
+}
+
+impl Sub for Chi {
+    type Output = usize;
+
+    /// Steps forward from `rhs` to `self` (0-11)
+    fn sub(self, rhs: Chi) -> usize {
+        (self.index() + 12 - rhs.index()) % 12
+    }
+}
+
+/// Ngũ Hành (Five Elements), in generating order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Element {
+    Moc,
+    Hoa,
+    Tho,
+    Kim,
+    Thuy,
+}
+
+const ELEMENT_VI: [&str; 5] = ["Mộc", "Hỏa", "Thổ", "Kim", "Thủy"];
+const ELEMENT_EN: [&str; 5] = ["Wood", "Fire", "Earth", "Metal", "Water"];
+const ELEMENT_ZH: [&str; 5] = ["木", "火", "土", "金", "水"];
+
+impl Element {
+    /// All elements in generating order (Mộc → Hỏa → Thổ → Kim → Thủy)
+    pub const ALL: [Element; 5] = [
+        Element::Moc,
+        Element::Hoa,
+        Element::Tho,
+        Element::Kim,
+        Element::Thuy,
+    ];
+
+    /// 0-based position in generating order (Mộc = 0)
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Vietnamese name
+    pub fn name(self) -> &'static str {
+        ELEMENT_VI[self.index()]
+    }
+
+    /// Name in the given locale
+    pub fn label(self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Vi => self.name(),
+            Locale::En => ELEMENT_EN[self.index()],
+            Locale::Zh => ELEMENT_ZH[self.index()],
+        }
+    }
+}
+
+impl fmt::Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Element {
+    type Err = ParseNameError;
+
+    /// Parse a Vietnamese, English or Chinese element name
+    ///
+    /// The older tone placements "Hoả" and "Thuỷ" are accepted too.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s {
+            "Hoả" => return Ok(Element::Hoa),
+            "Thuỷ" => return Ok(Element::Thuy),
+            _ => {}
+        }
+        Self::ALL
+            .into_iter()
+            .find(|element| Locale::ALL.iter().any(|&locale| element.label(locale) == s))
+            .ok_or_else(|| ParseNameError::new("element", s))
+    }
+}
+
+// Stems, branches and elements serialize as their Vietnamese names so that
+// JSON built from core types reads the same as before they were typed. The
+// almanac's DayFortune always serializes, so it needs these too.
+#[cfg(any(feature = "serde", feature = "almanac"))]
+pub(crate) fn deserialize_name<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr<Err = ParseNameError>,
+{
+    let name = String::deserialize(deserializer)?;
+    name.parse().map_err(de::Error::custom)
+}
+
+#[cfg(any(feature = "serde", feature = "almanac"))]
+impl Serialize for Can {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(any(feature = "serde", feature = "almanac"))]
+impl<'de> Deserialize<'de> for Can {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_name(deserializer)
+    }
+}
+
+#[cfg(any(feature = "serde", feature = "almanac"))]
+impl Serialize for Chi {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(any(feature = "serde", feature = "almanac"))]
+impl<'de> Deserialize<'de> for Chi {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_name(deserializer)
+    }
+}
+
+#[cfg(any(feature = "serde", feature = "almanac"))]
+impl Serialize for Element {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(any(feature = "serde", feature = "almanac"))]
+impl<'de> Deserialize<'de> for Element {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_name(deserializer)
+    }
+}
+
 /// Represents the Ngũ Hành (Five Elements) for both Can and Chi
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NguHanh {
-    pub can: String,
-    pub chi: String,
+    pub can: Element,
+    pub chi: Element,
 }
 
 /// Represents a Can Chi combination with full information
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CanChi {
-    pub can_index: usize,
-    pub chi_index: usize,
-    pub can: String,
-    pub chi: String,
+    pub can: Can,
+    pub chi: Chi,
+    /// Display label, e.g. "Giáp Tý" (month labels may add " (nhuận)")
     pub full: String,
     pub con_giap: String,
     pub ngu_hanh: NguHanh,
@@ -85,32 +542,33 @@ impl CanChi {
     /// # Returns
     /// A CanChi struct with all information populated
     pub fn new(can_index: usize, chi_index: usize) -> Self {
-        let can_idx = can_index % 10;
-        let chi_idx = chi_index % 12;
+        Self::from_parts(Can::from_index(can_index), Chi::from_index(chi_index))
+    }
 
+    /// Create a CanChi from a typed stem and branch
+    pub fn from_parts(can: Can, chi: Chi) -> Self {
         // Calculate sexagenary cycle index (0-59)
-        let sexagenary_index = ((can_idx * 6) + (chi_idx / 2)) % 60;
-
-        let can = CAN[can_idx].to_string();
-        let chi = CHI[chi_idx].to_string();
-        let full = format!("{} {}", can, chi);
-        let con_giap = CON_GIAP[chi_idx].to_string();
-        let ngu_hanh = NguHanh {
-            can: NGU_HANH_CAN[can_idx].to_string(),
-            chi: NGU_HANH_CHI[chi_idx].to_string(),
-        };
+        let sexagenary_index = ((can.index() * 6) + (chi.index() / 2)) % 60;
 
         CanChi {
-            can_index: can_idx,
-            chi_index: chi_idx,
             can,
             chi,
-            full,
-            con_giap,
-            ngu_hanh,
+            full: format!("{} {}", can, chi),
+            con_giap: chi.con_giap().to_string(),
+            ngu_hanh: NguHanh {
+                can: can.element(),
+                chi: chi.element(),
+            },
             sexagenary_index,
         }
     }
+
+    /// Nạp Âm of the pair (e.g. "Hải Trung Kim" for Giáp Tý)
+    pub fn na_am(&self) -> &'static str {
+        // Position in the 60 cycle: the i with i % 10 == can and i % 12 == chi
+        let cycle = (6 * self.can.index() + 60 - 5 * self.chi.index()) % 60;
+        NA_AM[cycle / 2]
+    }
 }
 
 /// Normalize an index to 0-based range
@@ -143,12 +601,12 @@ mod tests {
     fn test_canchi_creation() {
         // Test Giáp Tý (first of 60-year cycle)
         let cc = CanChi::new(0, 0);
-        assert_eq!(cc.can, "Giáp");
-        assert_eq!(cc.chi, "Tý");
+        assert_eq!(cc.can, Can::Giap);
+        assert_eq!(cc.chi, Chi::Ty);
         assert_eq!(cc.full, "Giáp Tý");
         assert_eq!(cc.con_giap, "Tý (Chuột)");
-        assert_eq!(cc.ngu_hanh.can, "Mộc");
-        assert_eq!(cc.ngu_hanh.chi, "Thủy");
+        assert_eq!(cc.ngu_hanh.can, Element::Moc);
+        assert_eq!(cc.ngu_hanh.chi, Element::Thuy);
         assert_eq!(cc.sexagenary_index, 0);
     }
 
@@ -156,24 +614,24 @@ mod tests {
     fn test_canchi_giap_thin() {
         // Tết 2024: Giáp Thìn
         let cc = CanChi::new(0, 4);
-        assert_eq!(cc.can, "Giáp");
-        assert_eq!(cc.chi, "Thìn");
+        assert_eq!(cc.can, Can::Giap);
+        assert_eq!(cc.chi, Chi::Thin);
         assert_eq!(cc.full, "Giáp Thìn");
         assert_eq!(cc.con_giap, "Thìn (Rồng)");
-        assert_eq!(cc.ngu_hanh.can, "Mộc");
-        assert_eq!(cc.ngu_hanh.chi, "Thổ");
+        assert_eq!(cc.ngu_hanh.can, Element::Moc);
+        assert_eq!(cc.ngu_hanh.chi, Element::Tho);
     }
 
     #[test]
     fn test_canchi_modulo_wrapping() {
         // Test that indices wrap correctly
         let cc1 = CanChi::new(10, 12); // Should wrap to 0, 0
-        assert_eq!(cc1.can_index, 0);
-        assert_eq!(cc1.chi_index, 0);
+        assert_eq!(cc1.can.index(), 0);
+        assert_eq!(cc1.chi.index(), 0);
 
         let cc2 = CanChi::new(13, 25); // Should wrap to 3, 1
-        assert_eq!(cc2.can_index, 3);
-        assert_eq!(cc2.chi_index, 1);
+        assert_eq!(cc2.can.index(), 3);
+        assert_eq!(cc2.chi.index(), 1);
     }
 
     #[test]
@@ -199,4 +657,84 @@ mod tests {
         let cc1 = CanChi::new(1, 1);
         assert_eq!(cc1.sexagenary_index, 6);
     }
+
+    #[test]
+    fn test_typed_names_match_tables() {
+        for (i, can) in Can::ALL.iter().enumerate() {
+            assert_eq!(can.index(), i);
+            assert_eq!(can.name(), CAN[i]);
+            assert_eq!(can.element().name(), NGU_HANH_CAN[i]);
+        }
+        for (i, chi) in Chi::ALL.iter().enumerate() {
+            assert_eq!(chi.index(), i);
+            assert_eq!(chi.name(), CHI[i]);
+            assert_eq!(chi.con_giap(), CON_GIAP[i]);
+            assert_eq!(chi.element().name(), NGU_HANH_CHI[i]);
+        }
+    }
+
+    #[test]
+    fn test_branch_arithmetic() {
+        assert_eq!(Chi::Ty + 6, Chi::Ngo);
+        assert_eq!(Chi::Tuat + 6, Chi::Thin);
+        assert_eq!(Chi::Ty - 1, Chi::Hoi);
+        assert_eq!(Chi::Dan.opposing(), Chi::Than);
+        assert_eq!(Chi::Suu - Chi::Dan, 11);
+        assert_eq!(Can::Quy + 1, Can::Giap);
+        assert_eq!(Can::Giap - 3, Can::Tan);
+        for chi in Chi::ALL {
+            assert_eq!(chi.opposing().opposing(), chi);
+        }
+    }
+
+    #[test]
+    fn test_parse_names() {
+        assert_eq!("Tỵ".parse::<Chi>(), Ok(Chi::Ti));
+        assert_eq!("Tị".parse::<Chi>(), Ok(Chi::Ti));
+        assert_eq!("巳".parse::<Chi>(), Ok(Chi::Ti));
+        assert_eq!("Giáp".parse::<Can>(), Ok(Can::Giap));
+        assert_eq!("Gui".parse::<Can>(), Ok(Can::Quy));
+        assert_eq!("Thuỷ".parse::<Element>(), Ok(Element::Thuy));
+        assert_eq!("Metal".parse::<Element>(), Ok(Element::Kim));
+
+        let err = "Tyy".parse::<Chi>().unwrap_err();
+        assert_eq!(err.to_string(), "unknown chi: Tyy");
+    }
+
+    #[test]
+    fn test_localized_labels() {
+        assert_eq!(Can::Binh.label(Locale::Zh), "丙");
+        assert_eq!(Chi::Mao.label(Locale::En), "Mao");
+        assert_eq!(Element::Hoa.label(Locale::En), "Fire");
+        assert_eq!(Chi::Dau.to_string(), "Dậu");
+    }
+
+    #[test]
+    fn test_na_am() {
+        assert_eq!(
+            CanChi::from_parts(Can::Giap, Chi::Ty).na_am(),
+            "Hải Trung Kim"
+        );
+        assert_eq!(
+            CanChi::from_parts(Can::At, Chi::Suu).na_am(),
+            "Hải Trung Kim"
+        );
+        assert_eq!(
+            CanChi::from_parts(Can::Giap, Chi::Tuat).na_am(),
+            "Sơn Đầu Hỏa"
+        );
+        assert_eq!(
+            CanChi::from_parts(Can::Quy, Chi::Hoi).na_am(),
+            "Đại Hải Thủy"
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "serde", feature = "almanac"))]
+    fn test_serializes_as_vietnamese_name() {
+        let json = serde_json::to_string(&(Can::At, Chi::Ti, Element::Tho)).unwrap();
+        assert_eq!(json, r#"["Ất","Tỵ","Thổ"]"#);
+        let back: (Can, Chi, Element) = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, (Can::At, Chi::Ti, Element::Tho));
+    }
 }
@@ -0,0 +1,335 @@
+/**
+ * Calendar Variants
+ *
+ * The Vietnamese, Chinese, Korean and Japanese lunisolar calendars share
+ * the same rules (month 11 holds the winter solstice, the leap month is the
+ * first month without a major solar term) but are computed for different
+ * meridians, so new moons near midnight can fall on different days. Each
+ * variant also has its own month names and zodiac animals (Mão is the Cat
+ * in Vietnam and the Rabbit elsewhere).
+ */
+use crate::canchi::get_year_canchi;
+use crate::lunar::{convert_solar_to_lunar, LunarDate};
+use crate::types::CanChi;
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// A lunisolar calendar tradition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CalendarVariant {
+    /// Âm lịch Việt Nam, UTC+7
+    Vietnamese,
+    /// Nónglì (农历), UTC+8
+    Chinese,
+    /// Eumnyeok (음력), UTC+9
+    Korean,
+    /// Tenpō reki (天保暦), approximated with UTC+9
+    ///
+    /// The historical Tenpō calendar (1844-1872) was computed for Kyoto
+    /// apparent time, about 9h03m east of Greenwich; using UTC+9 can shift
+    /// a month start by a day when the new moon falls within minutes of
+    /// midnight.
+    JapaneseTenpo,
+}
+
+impl CalendarVariant {
+    /// All variants
+    pub const ALL: [CalendarVariant; 4] = [
+        CalendarVariant::Vietnamese,
+        CalendarVariant::Chinese,
+        CalendarVariant::Korean,
+        CalendarVariant::JapaneseTenpo,
+    ];
+
+    /// Stable identifier
+    pub fn id(self) -> &'static str {
+        match self {
+            CalendarVariant::Vietnamese => "vn",
+            CalendarVariant::Chinese => "cn",
+            CalendarVariant::Korean => "kr",
+            CalendarVariant::JapaneseTenpo => "jp_tenpo",
+        }
+    }
+
+    /// Vietnamese display name
+    pub fn name(self) -> &'static str {
+        match self {
+            CalendarVariant::Vietnamese => "Âm lịch Việt Nam",
+            CalendarVariant::Chinese => "Âm lịch Trung Quốc",
+            CalendarVariant::Korean => "Âm lịch Hàn Quốc",
+            CalendarVariant::JapaneseTenpo => "Lịch Thiên Bảo (Nhật Bản)",
+        }
+    }
+
+    /// Time zone offset the calendar is computed for
+    pub fn time_zone(self) -> f64 {
+        match self {
+            CalendarVariant::Vietnamese => 7.0,
+            CalendarVariant::Chinese => 8.0,
+            CalendarVariant::Korean | CalendarVariant::JapaneseTenpo => 9.0,
+        }
+    }
+
+    /// Zodiac label for an earthly branch, in the `CON_GIAP` format
+    pub fn zodiac_label(self, chi_index: usize) -> &'static str {
+        let labels = match self {
+            CalendarVariant::Vietnamese => &crate::types::CON_GIAP,
+            CalendarVariant::Chinese => &CON_GIAP_CN,
+            CalendarVariant::Korean => &CON_GIAP_KR,
+            CalendarVariant::JapaneseTenpo => &CON_GIAP_JP,
+        };
+        labels[chi_index % 12]
+    }
+
+    /// Native name of a lunar month, with the variant's leap prefix
+    pub fn month_name(self, month: i32, is_leap: bool) -> String {
+        let index = (month - 1).rem_euclid(12) as usize;
+        match self {
+            CalendarVariant::Vietnamese => {
+                let name = MONTH_NAMES_VN[index];
+                if is_leap {
+                    format!("{name} nhuận")
+                } else {
+                    name.to_string()
+                }
+            }
+            CalendarVariant::Chinese => {
+                format!(
+                    "{}{}",
+                    if is_leap { "闰" } else { "" },
+                    MONTH_NAMES_CN[index]
+                )
+            }
+            CalendarVariant::Korean => {
+                format!(
+                    "{}{}",
+                    if is_leap { "윤" } else { "" },
+                    MONTH_NAMES_KR[index]
+                )
+            }
+            CalendarVariant::JapaneseTenpo => {
+                format!(
+                    "{}{}",
+                    if is_leap { "閏" } else { "" },
+                    MONTH_NAMES_JP[index]
+                )
+            }
+        }
+    }
+}
+
+const CON_GIAP_CN: [&str; 12] = [
+    "Tý (Chuột)",
+    "Sửu (Trâu)",
+    "Dần (Hổ)",
+    "Mão (Thỏ)",
+    "Thìn (Rồng)",
+    "Tỵ (Rắn)",
+    "Ngọ (Ngựa)",
+    "Mùi (Dê)",
+    "Thân (Khỉ)",
+    "Dậu (Gà)",
+    "Tuất (Chó)",
+    "Hợi (Lợn)",
+];
+
+const CON_GIAP_KR: [&str; 12] = [
+    "Tý (Chuột)",
+    "Sửu (Trâu)",
+    "Dần (Hổ)",
+    "Mão (Thỏ)",
+    "Thìn (Rồng)",
+    "Tỵ (Rắn)",
+    "Ngọ (Ngựa)",
+    "Mùi (Cừu)",
+    "Thân (Khỉ)",
+    "Dậu (Gà)",
+    "Tuất (Chó)",
+    "Hợi (Lợn)",
+];
+
+const CON_GIAP_JP: [&str; 12] = [
+    "Tý (Chuột)",
+    "Sửu (Trâu)",
+    "Dần (Hổ)",
+    "Mão (Thỏ)",
+    "Thìn (Rồng)",
+    "Tỵ (Rắn)",
+    "Ngọ (Ngựa)",
+    "Mùi (Cừu)",
+    "Thân (Khỉ)",
+    "Dậu (Gà)",
+    "Tuất (Chó)",
+    "Hợi (Lợn rừng)",
+];
+
+const MONTH_NAMES_VN: [&str; 12] = [
+    "Tháng Giêng",
+    "Tháng Hai",
+    "Tháng Ba",
+    "Tháng Tư",
+    "Tháng Năm",
+    "Tháng Sáu",
+    "Tháng Bảy",
+    "Tháng Tám",
+    "Tháng Chín",
+    "Tháng Mười",
+    "Tháng Một",
+    "Tháng Chạp",
+];
+
+const MONTH_NAMES_CN: [&str; 12] = [
+    "正月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "冬月", "腊月",
+];
+
+const MONTH_NAMES_KR: [&str; 12] = [
+    "정월",
+    "이월",
+    "삼월",
+    "사월",
+    "오월",
+    "유월",
+    "칠월",
+    "팔월",
+    "구월",
+    "시월",
+    "동짓달",
+    "섣달",
+];
+
+const MONTH_NAMES_JP: [&str; 12] = [
+    "睦月",
+    "如月",
+    "弥生",
+    "卯月",
+    "皐月",
+    "水無月",
+    "文月",
+    "葉月",
+    "長月",
+    "神無月",
+    "霜月",
+    "師走",
+];
+
+/// A solar date expressed in one calendar variant
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VariantDate {
+    pub variant: CalendarVariant,
+    pub lunar: LunarDate,
+    pub month_name: String,
+    pub year_canchi: CanChi,
+    /// Zodiac label of the lunar year in this variant
+    pub zodiac: String,
+}
+
+/// Vietnamese and Chinese dates of the same solar day
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VariantComparison {
+    pub vietnamese: VariantDate,
+    pub chinese: VariantDate,
+    /// Whether the lunar dates differ (day, month, year or leap flag)
+    pub differs: bool,
+}
+
+/// Convert a solar date to a calendar variant
+///
+/// # Arguments
+/// * `day` - Solar day (1-31)
+/// * `month` - Solar month (1-12)
+/// * `year` - Solar year
+/// * `variant` - Calendar variant
+///
+/// # Returns
+/// The lunar date with the variant's month name and zodiac label
+pub fn get_variant_date(day: i32, month: i32, year: i32, variant: CalendarVariant) -> VariantDate {
+    let lunar = convert_solar_to_lunar(day, month, year, variant.time_zone());
+    let year_canchi = get_year_canchi(lunar.year);
+    VariantDate {
+        variant,
+        month_name: variant.month_name(lunar.month, lunar.is_leap),
+        zodiac: variant.zodiac_label(year_canchi.chi.index()).to_string(),
+        year_canchi,
+        lunar,
+    }
+}
+
+/// Compare the Vietnamese and Chinese lunar dates of a solar date
+///
+/// The calendars disagree when a new moon falls between 16:00 and 17:00 UTC
+/// (before midnight in Vietnam, after midnight in China), or when a solar
+/// term near midnight moves month 11 or the leap month. Tết 1985 and 2007
+/// are well-known examples.
+pub fn compare_vn_cn(day: i32, month: i32, year: i32) -> VariantComparison {
+    let vietnamese = get_variant_date(day, month, year, CalendarVariant::Vietnamese);
+    let chinese = get_variant_date(day, month, year, CalendarVariant::Chinese);
+    let differs = vietnamese.lunar != chinese.lunar;
+    VariantComparison {
+        vietnamese,
+        chinese,
+        differs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lunar::convert_lunar_to_solar;
+
+    #[test]
+    fn test_tet_1985_differs() {
+        // Tết Ất Sửu: Vietnam 1985-01-21, China 1985-02-20
+        let comparison = compare_vn_cn(21, 1, 1985);
+        assert!(comparison.differs);
+        assert_eq!(comparison.vietnamese.lunar.day, 1);
+        assert_eq!(comparison.vietnamese.lunar.month, 1);
+        assert_eq!(comparison.vietnamese.lunar.year, 1985);
+        assert_eq!(comparison.chinese.lunar.year, 1984);
+        assert_eq!(comparison.chinese.month_name, "腊月");
+
+        let cn_tet = convert_lunar_to_solar(1, 1, 1985, false, 8.0).unwrap();
+        assert_eq!((cn_tet.day, cn_tet.month), (20, 2));
+    }
+
+    #[test]
+    fn test_tet_2007_differs() {
+        // Tết Đinh Hợi: Vietnam 2007-02-17, China 2007-02-18
+        let comparison = compare_vn_cn(17, 2, 2007);
+        assert!(comparison.differs);
+        assert_eq!(comparison.vietnamese.month_name, "Tháng Giêng");
+        assert_eq!(comparison.chinese.lunar.day, 30);
+        assert_eq!(comparison.chinese.lunar.month, 12);
+    }
+
+    #[test]
+    fn test_same_day_and_zodiac_labels() {
+        // Tết Quý Mão 2023: both calendars agree, but the animal differs
+        let comparison = compare_vn_cn(22, 1, 2023);
+        assert!(!comparison.differs);
+        assert_eq!(comparison.vietnamese.zodiac, "Mão (Mèo)");
+        assert_eq!(comparison.chinese.zodiac, "Mão (Thỏ)");
+        assert_eq!(comparison.vietnamese.year_canchi.full, "Quý Mão");
+
+        let korean = get_variant_date(10, 2, 2024, CalendarVariant::Korean);
+        assert_eq!((korean.lunar.day, korean.lunar.month), (1, 1));
+        assert_eq!(korean.month_name, "정월");
+    }
+
+    #[test]
+    fn test_month_names() {
+        assert_eq!(
+            CalendarVariant::Vietnamese.month_name(6, true),
+            "Tháng Sáu nhuận"
+        );
+        assert_eq!(CalendarVariant::Chinese.month_name(6, true), "闰六月");
+        assert_eq!(CalendarVariant::Korean.month_name(11, false), "동짓달");
+        assert_eq!(CalendarVariant::JapaneseTenpo.month_name(12, false), "師走");
+        assert_eq!(
+            CalendarVariant::JapaneseTenpo.zodiac_label(11),
+            "Hợi (Lợn rừng)"
+        );
+    }
+}
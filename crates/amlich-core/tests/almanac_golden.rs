@@ -1,6 +1,8 @@
+#![cfg(feature = "almanac")]
+
 use amlich_core::almanac::calc::calculate_day_fortune;
 use amlich_core::almanac::types::DayDeityClassification;
-use amlich_core::get_day_info;
+use amlich_core::{get_day_info, Chi};
 
 /// Tết 2024 (2024-02-10): Giáp Thìn, lunar 1/1/2024
 /// chi_index=4, lunar_month=1 → trực=Mãn(2,hung), lục_xung=Tuất, tam_hợp={Tý,Thìn,Thân}
@@ -12,11 +14,11 @@ fn golden_tet_2024_truc_and_xung_hop() {
         &info.canchi.day,
         info.lunar.day,
         info.lunar.month,
-        &info.canchi.year.can,
-        &info.tiet_khi.name,
+        info.canchi.year.can,
+        info.tiet_khi.id,
     );
 
-    assert_eq!(info.canchi.day.chi, "Thìn");
+    assert_eq!(info.canchi.day.chi, Chi::Thin);
     assert_eq!(info.lunar.day, 1);
     assert_eq!(info.lunar.month, 1);
 
@@ -24,11 +26,11 @@ fn golden_tet_2024_truc_and_xung_hop() {
     assert_eq!(fortune.truc.index, 2);
     assert_eq!(fortune.truc.quality, "hung");
 
-    assert_eq!(fortune.xung_hop.luc_xung, "Tuất");
+    assert_eq!(fortune.xung_hop.luc_xung, Chi::Tuat);
     assert_eq!(fortune.xung_hop.tam_hop.len(), 3);
-    assert!(fortune.xung_hop.tam_hop.contains(&"Tý".to_string()));
-    assert!(fortune.xung_hop.tam_hop.contains(&"Thìn".to_string()));
-    assert!(fortune.xung_hop.tam_hop.contains(&"Thân".to_string()));
+    assert!(fortune.xung_hop.tam_hop.contains(&Chi::Ty));
+    assert!(fortune.xung_hop.tam_hop.contains(&Chi::Thin));
+    assert!(fortune.xung_hop.tam_hop.contains(&Chi::Than));
     assert_eq!(fortune.xung_hop.tu_hanh_xung.len(), 4);
 }
 
@@ -42,11 +44,11 @@ fn golden_tet_2025_truc_and_xung_hop() {
         &info.canchi.day,
         info.lunar.day,
         info.lunar.month,
-        &info.canchi.year.can,
-        &info.tiet_khi.name,
+        info.canchi.year.can,
+        info.tiet_khi.id,
     );
 
-    assert_eq!(info.canchi.day.chi, "Tuất");
+    assert_eq!(info.canchi.day.chi, Chi::Tuat);
     assert_eq!(info.lunar.day, 1);
     assert_eq!(info.lunar.month, 1);
 
@@ -54,11 +56,11 @@ fn golden_tet_2025_truc_and_xung_hop() {
     assert_eq!(fortune.truc.index, 8);
     assert_eq!(fortune.truc.quality, "cat");
 
-    assert_eq!(fortune.xung_hop.luc_xung, "Thìn");
+    assert_eq!(fortune.xung_hop.luc_xung, Chi::Thin);
     assert_eq!(fortune.xung_hop.tam_hop.len(), 3);
-    assert!(fortune.xung_hop.tam_hop.contains(&"Dần".to_string()));
-    assert!(fortune.xung_hop.tam_hop.contains(&"Ngọ".to_string()));
-    assert!(fortune.xung_hop.tam_hop.contains(&"Tuất".to_string()));
+    assert!(fortune.xung_hop.tam_hop.contains(&Chi::Dan));
+    assert!(fortune.xung_hop.tam_hop.contains(&Chi::Ngo));
+    assert!(fortune.xung_hop.tam_hop.contains(&Chi::Tuat));
 }
 
 /// New Year 2024 (2024-01-01): Giáp Tý, lunar 20/11/2023
@@ -72,21 +74,21 @@ fn golden_new_year_2024_truc_and_xung_hop() {
         &info.canchi.day,
         info.lunar.day,
         info.lunar.month,
-        &info.canchi.year.can,
-        &info.tiet_khi.name,
+        info.canchi.year.can,
+        info.tiet_khi.id,
     );
 
-    assert_eq!(info.canchi.day.chi, "Tý");
+    assert_eq!(info.canchi.day.chi, Chi::Ty);
     assert_eq!(info.lunar.month, 11);
 
     assert_eq!(fortune.truc.name, "Kiến");
     assert_eq!(fortune.truc.index, 0);
     assert_eq!(fortune.truc.quality, "cat");
 
-    assert_eq!(fortune.xung_hop.luc_xung, "Ngọ");
-    assert!(fortune.xung_hop.tam_hop.contains(&"Tý".to_string()));
-    assert!(fortune.xung_hop.tam_hop.contains(&"Thìn".to_string()));
-    assert!(fortune.xung_hop.tam_hop.contains(&"Thân".to_string()));
+    assert_eq!(fortune.xung_hop.luc_xung, Chi::Ngo);
+    assert!(fortune.xung_hop.tam_hop.contains(&Chi::Ty));
+    assert!(fortune.xung_hop.tam_hop.contains(&Chi::Thin));
+    assert!(fortune.xung_hop.tam_hop.contains(&Chi::Than));
 }
 
 /// Structural invariant: when day chi == month chi, trực must be Kiến (index 0)
@@ -104,13 +106,14 @@ fn golden_truc_kien_when_day_chi_equals_month_chi() {
         &info.canchi.day,
         info.lunar.day,
         info.lunar.month,
-        &info.canchi.year.can,
-        &info.tiet_khi.name,
+        info.canchi.year.can,
+        info.tiet_khi.id,
     );
 
     // day chi should be Dần, lunar month should be 1
     assert_eq!(
-        info.canchi.day.chi, "Dần",
+        info.canchi.day.chi,
+        Chi::Dan,
         "expected day chi Dần for 2024-02-20"
     );
     assert_eq!(info.lunar.month, 1, "expected lunar month 1");
@@ -136,7 +139,7 @@ fn golden_day_deity_mapping_ty_day_across_lunar_months() {
     ];
 
     for (month, expected_name, expected_classification) in cases {
-        let deity = amlich_core::almanac::day_deity::resolve_day_deity(month, "Tý");
+        let deity = amlich_core::almanac::day_deity::resolve_day_deity(month, Chi::Ty);
         assert_eq!(deity.name, expected_name, "lunar month {month}");
         assert_eq!(
             deity.classification, expected_classification,
@@ -163,7 +166,7 @@ fn golden_day_deity_mapping_tuat_day_across_lunar_months() {
     ];
 
     for (month, expected_name, expected_classification) in cases {
-        let deity = amlich_core::almanac::day_deity::resolve_day_deity(month, "Tuất");
+        let deity = amlich_core::almanac::day_deity::resolve_day_deity(month, Chi::Tuat);
         assert_eq!(deity.name, expected_name, "lunar month {month}");
         assert_eq!(
             deity.classification, expected_classification,
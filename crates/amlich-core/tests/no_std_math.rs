@@ -0,0 +1,107 @@
+//! Known calendar and astronomy values, checked against the library as built
+//! for this test run.
+//!
+//! Integration tests link the library without `cfg(test)`, so under
+//! `cargo test -p amlich-core --no-default-features` these run on the no_std
+//! build, whose float math comes from libm through `math::FloatExt`. The unit
+//! tests always link std and never reach that path.
+
+use amlich_core::ephemeris::EphemerisMode;
+use amlich_core::julian::{jd_from_date, jd_to_date};
+use amlich_core::location::HANOI;
+use amlich_core::lunar::{
+    convert_lunar_to_solar, convert_lunar_to_solar_with_mode, convert_solar_to_lunar,
+    convert_solar_to_lunar_with_mode, SolarDate,
+};
+use amlich_core::moon_phase::get_next_full_moon;
+use amlich_core::sun_times::get_sun_times;
+use amlich_core::tietkhi::{get_tiet_khi_instant_with_mode, SolarTermId};
+
+const VN: f64 = 7.0;
+
+#[test]
+fn tet_dates_2020_to_2026() {
+    let expected = [
+        (2020, 25, 1),
+        (2021, 12, 2),
+        (2022, 1, 2),
+        (2023, 22, 1),
+        (2024, 10, 2),
+        (2025, 29, 1),
+        (2026, 17, 2),
+    ];
+    for (year, day, month) in expected {
+        let tet = convert_lunar_to_solar(1, 1, year, false, VN).expect("Tết exists");
+        assert_eq!(tet, SolarDate { day, month, year }, "Tết {year}");
+    }
+}
+
+#[test]
+fn every_day_of_2024_round_trips_in_both_modes() {
+    for mode in [EphemerisMode::Fast, EphemerisMode::Accurate] {
+        for jd in jd_from_date(1, 1, 2024)..=jd_from_date(31, 12, 2024) {
+            let (day, month, year) = jd_to_date(jd);
+            let lunar = convert_solar_to_lunar_with_mode(day, month, year, VN, mode);
+            let back = convert_lunar_to_solar_with_mode(
+                lunar.day,
+                lunar.month,
+                lunar.year,
+                lunar.is_leap,
+                VN,
+                mode,
+            )
+            .expect("lunar date from a conversion exists");
+            assert_eq!(back, SolarDate { day, month, year }, "{mode:?}");
+        }
+    }
+}
+
+#[test]
+fn leap_month_4_of_2020() {
+    // The new moon fell at 00:39 on May 23 in UTC+7; Fast places it a day early
+    let lunar = convert_solar_to_lunar_with_mode(23, 5, 2020, VN, EphemerisMode::Accurate);
+    assert_eq!((lunar.day, lunar.month, lunar.year), (1, 4, 2020));
+    assert!(lunar.is_leap);
+
+    let lunar = convert_solar_to_lunar(22, 5, 2020, VN);
+    assert_eq!((lunar.day, lunar.month, lunar.year), (1, 4, 2020));
+    assert!(lunar.is_leap);
+}
+
+#[test]
+fn solar_term_instants_of_2024() {
+    // UT instants: 02-04 08:27, 03-20 03:06, 12-21 09:20
+    let expected = [
+        (SolarTermId::LapXuan, (4, 2), 15 * 60 + 27),
+        (SolarTermId::XuanPhan, (20, 3), 10 * 60 + 6),
+        (SolarTermId::DongChi, (21, 12), 16 * 60 + 20),
+    ];
+    for (term, (day, month), minutes) in expected {
+        let local = get_tiet_khi_instant_with_mode(2024, term, VN, EphemerisMode::Accurate).local;
+        assert_eq!((local.day, local.month, local.year), (day, month, 2024));
+        let actual = local.hour * 60 + local.minute;
+        assert!((actual - minutes).abs() <= 2, "{term:?}: {local:?}");
+    }
+}
+
+#[test]
+fn full_moon_of_the_september_2024_eclipse() {
+    // 2024-09-18 02:34 UT, the partial lunar eclipse
+    let event = get_next_full_moon(1, 9, 2024, VN);
+    let local = event.local;
+    assert_eq!((local.day, local.month, local.year), (18, 9, 2024));
+    let minutes = local.hour * 60 + local.minute;
+    assert!((9 * 60 + 32..=9 * 60 + 36).contains(&minutes), "{local:?}");
+}
+
+#[test]
+fn hanoi_sunrise_at_the_june_solstice() {
+    let times = get_sun_times(21, 6, 2024, VN, &HANOI);
+    let sunrise = times.sunrise.expect("the sun rises in Hanoi");
+    let minutes = sunrise.hour * 60 + sunrise.minute;
+    // About 05:14 local time
+    assert!(
+        (5 * 60 + 11..=5 * 60 + 17).contains(&minutes),
+        "{sunrise:?}"
+    );
+}
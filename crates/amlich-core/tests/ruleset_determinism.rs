@@ -1,3 +1,5 @@
+#![cfg(feature = "almanac")]
+
 use amlich_core::almanac::data::{
     get_ruleset_data, get_ruleset_descriptor_doc, DEFAULT_RULESET_ID,
 };
@@ -0,0 +1,58 @@
+#![cfg(all(feature = "serde", feature = "almanac", feature = "holidays"))]
+
+use amlich_core::holidays::{get_vietnamese_holidays, Holiday};
+use amlich_core::lunar::LunarDate;
+use amlich_core::tietkhi::{SolarTerm, SolarTermId};
+use amlich_core::{get_day_info, DayInfo};
+
+#[test]
+fn day_info_round_trips_with_stable_field_names() {
+    let info = get_day_info(10, 2, 2024);
+    let value = serde_json::to_value(&info).expect("serialize");
+
+    assert_eq!(value["solar"]["day"], 10);
+    assert_eq!(value["lunar"]["is_leap_month"], false);
+    assert_eq!(value["canchi"]["day"]["can"], "Giáp");
+    assert_eq!(value["canchi"]["day"]["chi"], "Thìn");
+    assert_eq!(value["canchi"]["day"]["ngu_hanh"]["chi"], "Thổ");
+    assert_eq!(value["tiet_khi"]["id"], "Lập Xuân");
+    assert_eq!(value["gio_hoang_dao"]["day_chi"], "Thìn");
+    assert_eq!(value["gio_hoang_dao"]["all_hours"][0]["star_type"], "Bad");
+    assert!(value["sun_times"].is_null());
+
+    let decoded: DayInfo = serde_json::from_value(value).expect("deserialize");
+    assert_eq!(decoded.canchi.day, info.canchi.day);
+    assert_eq!(decoded.tiet_khi, info.tiet_khi);
+    assert_eq!(decoded.day_fortune, info.day_fortune);
+}
+
+#[test]
+fn solar_term_and_lunar_date_round_trip() {
+    let term: SolarTerm = get_day_info(22, 6, 2024).tiet_khi;
+    let json = serde_json::to_string(&term).expect("serialize");
+    let decoded: SolarTerm = serde_json::from_str(&json).expect("deserialize");
+    assert_eq!(decoded, term);
+    assert_eq!(decoded.id, SolarTermId::HaChi);
+
+    let date = LunarDate {
+        day: 15,
+        month: 8,
+        year: 2024,
+        is_leap: false,
+    };
+    let json = serde_json::to_string(&date).expect("serialize");
+    assert_eq!(json, r#"{"day":15,"month":8,"year":2024,"is_leap":false}"#);
+    assert_eq!(
+        serde_json::from_str::<LunarDate>(&json).expect("deserialize"),
+        date
+    );
+}
+
+#[test]
+fn holidays_serialize() {
+    let holidays = get_vietnamese_holidays(2024);
+    let json = serde_json::to_string(&holidays).expect("serialize");
+    let decoded: Vec<Holiday> = serde_json::from_str(&json).expect("deserialize");
+    assert_eq!(decoded.len(), holidays.len());
+    assert_eq!(decoded[0].name, holidays[0].name);
+}
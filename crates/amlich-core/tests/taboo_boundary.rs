@@ -1,4 +1,6 @@
-use amlich_core::{get_day_info, get_day_info_with_timezone};
+#![cfg(feature = "almanac")]
+
+use amlich_core::{get_day_info, get_day_info_with_timezone, Chi};
 
 fn taboo_ids(info: &amlich_core::DayInfo) -> Vec<&str> {
     info.day_fortune
@@ -46,7 +48,7 @@ fn boundary_nguyet_ky_hits_on_lunar_day_five() {
 
 #[test]
 fn boundary_sat_chu_rep_month_one_branch_ty() {
-    let info = find_day(|info| info.lunar.month == 11 && info.canchi.day.chi == "Dậu")
+    let info = find_day(|info| info.lunar.month == 11 && info.canchi.day.chi == Chi::Dau)
         .expect("must find representative date for month-11 Sat Chu branch");
     let ids = taboo_ids(&info);
     assert!(ids.contains(&"sat_chu"));
@@ -54,7 +56,7 @@ fn boundary_sat_chu_rep_month_one_branch_ty() {
 
 #[test]
 fn boundary_tho_tu_rep_month_twelve_branch_mui() {
-    let info = find_day(|info| info.lunar.month == 12 && info.canchi.day.chi == "Mùi")
+    let info = find_day(|info| info.lunar.month == 12 && info.canchi.day.chi == Chi::Mui)
         .expect("must find representative date for month-12 Tho Tu branch");
     let ids = taboo_ids(&info);
     assert!(ids.contains(&"tho_tu"));
@@ -4,6 +4,7 @@ use amlich_api::DayInfoDto;
 use amlich_api::{get_day_info, get_holidays, DateQuery};
 use amlich_core::{
     lunar::{convert_lunar_to_solar, convert_solar_to_lunar},
+    timezone::TimeZone,
     VIETNAM_TIMEZONE,
 };
 use serde::Serialize;
@@ -77,7 +78,9 @@ pub fn get_day_info_js(day: i32, month: i32, year: i32) -> JsValue {
         day,
         month,
         year,
-        timezone: Some(VIETNAM_TIMEZONE),
+        timezone: None,
+        location: None,
+        hour_reckoning: None,
     };
     match get_day_info(&query) {
         Ok(info) => serde_wasm_bindgen::to_value(&info).unwrap_or(JsValue::NULL),
@@ -92,7 +95,9 @@ pub fn get_day_info_with_timezone_js(day: i32, month: i32, year: i32, time_zone:
         day,
         month,
         year,
-        timezone: Some(time_zone),
+        timezone: Some(TimeZone::from_hours(time_zone).to_string()),
+        location: None,
+        hour_reckoning: None,
     };
     match get_day_info(&query) {
         Ok(info) => serde_wasm_bindgen::to_value(&info).unwrap_or(JsValue::NULL),
@@ -107,7 +112,9 @@ pub fn format_day_info_js(day: i32, month: i32, year: i32) -> String {
         day,
         month,
         year,
-        timezone: Some(VIETNAM_TIMEZONE),
+        timezone: None,
+        location: None,
+        hour_reckoning: None,
     };
 
     match get_day_info(&query) {
@@ -145,14 +152,13 @@ pub fn solar_to_lunar_with_timezone(day: i32, month: i32, year: i32, time_zone:
 /// Convert lunar date to solar date.
 #[wasm_bindgen]
 pub fn lunar_to_solar(day: i32, month: i32, year: i32, is_leap: bool) -> JsValue {
-    let (d, m, y) = convert_lunar_to_solar(day, month, year, is_leap, VIETNAM_TIMEZONE);
-    if d == 0 {
+    let Ok(solar) = convert_lunar_to_solar(day, month, year, is_leap, VIETNAM_TIMEZONE) else {
         return JsValue::NULL;
-    }
+    };
     let result = WasmSolarDate {
-        day: d,
-        month: m,
-        year: y,
+        day: solar.day,
+        month: solar.month,
+        year: solar.year,
     };
     serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
 }
@@ -166,14 +172,13 @@ pub fn lunar_to_solar_with_timezone(
     is_leap: bool,
     time_zone: f64,
 ) -> JsValue {
-    let (d, m, y) = convert_lunar_to_solar(day, month, year, is_leap, time_zone);
-    if d == 0 {
+    let Ok(solar) = convert_lunar_to_solar(day, month, year, is_leap, time_zone) else {
         return JsValue::NULL;
-    }
+    };
     let result = WasmSolarDate {
-        day: d,
-        month: m,
-        year: y,
+        day: solar.day,
+        month: solar.month,
+        year: solar.year,
     };
     serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
 }
@@ -212,7 +217,7 @@ mod tests {
 
     #[test]
     fn test_lunar_to_solar_conversion() {
-        let (d, m, y) = convert_lunar_to_solar(1, 1, 2024, false, VIETNAM_TIMEZONE);
-        assert_eq!((d, m, y), (10, 2, 2024));
+        let solar = convert_lunar_to_solar(1, 1, 2024, false, VIETNAM_TIMEZONE).unwrap();
+        assert_eq!((solar.day, solar.month, solar.year), (10, 2, 2024));
     }
 }
@@ -1,4 +1,6 @@
-use amlich_api::{get_day_insight_for_date, DayInfoDto, DayInsightDto, HolidayDto};
+use amlich_api::{
+    get_day_insight_for_date, DayInfoDto, DayInsightDto, HolidayDto, HourReckoningDto, LocationDto,
+};
 use chrono::{Datelike, Local, NaiveDate};
 use serde::{Deserialize, Serialize};
 
@@ -8,6 +10,9 @@ use crate::{
     search::{self, SearchResult},
 };
 
+/// Holiday category used for solar and lunar eclipses
+const ECLIPSE_CATEGORY: &str = "astronomy";
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum InsightLang {
     Vi,
@@ -101,6 +106,10 @@ pub struct App {
     pub selected_day: u32,
     pub today: NaiveDate,
 
+    // Hour branch placement (solar reckonings use the location's longitude)
+    pub location: Option<LocationDto>,
+    pub hour_reckoning: Option<HourReckoningDto>,
+
     // Cached data for current view
     pub month_days: Vec<DayInfoDto>,
     pub month_holidays: Vec<HolidayDto>,
@@ -155,6 +164,8 @@ impl App {
             view_month: selected.month(),
             selected_day: selected.day(),
             today,
+            location: None,
+            hour_reckoning: None,
             month_days: Vec::new(),
             month_holidays: Vec::new(),
             days_in_month: 0,
@@ -186,6 +197,18 @@ impl App {
         app
     }
 
+    /// Place hour branches by `hour_reckoning` and reload the month
+    pub fn with_hours(
+        mut self,
+        location: Option<LocationDto>,
+        hour_reckoning: Option<HourReckoningDto>,
+    ) -> Self {
+        self.location = location;
+        self.hour_reckoning = hour_reckoning;
+        self.load_month();
+        self
+    }
+
     pub fn load_month(&mut self) {
         if !(1..=12).contains(&self.view_month) {
             self.view_month = self.today.month();
@@ -215,12 +238,14 @@ impl App {
         self.selected_day = self.selected_day.clamp(1, self.days_in_month.max(1));
 
         // Load day info for each day
-        self.month_days.clear();
-        for day in 1..=self.days_in_month {
-            if let Ok(info) = amlich_api::get_day_info_for_date(day as i32, month, year) {
-                self.month_days.push(info);
-            }
-        }
+        self.month_days = amlich_api::get_month_days_with_hours(
+            month,
+            year,
+            None,
+            self.location.as_ref(),
+            self.hour_reckoning,
+        )
+        .unwrap_or_default();
 
         // Load holidays
         self.month_holidays = amlich_api::get_holidays(year, false)
@@ -239,7 +264,13 @@ impl App {
     pub fn holiday_for_day(&self, day: u32) -> Option<&HolidayDto> {
         self.month_holidays
             .iter()
-            .find(|h| h.solar_day == day as i32)
+            .find(|h| h.solar_day == day as i32 && h.category != ECLIPSE_CATEGORY)
+    }
+
+    pub fn eclipse_for_day(&self, day: u32) -> Option<&HolidayDto> {
+        self.month_holidays
+            .iter()
+            .find(|h| h.solar_day == day as i32 && h.category == ECLIPSE_CATEGORY)
     }
 
     pub fn is_today(&self, day: u32) -> bool {
@@ -599,4 +630,20 @@ mod tests {
 
         assert_eq!(app.selected_insight_cache_key(), Some((2024, 2, 29)));
     }
+
+    #[test]
+    fn eclipse_is_kept_apart_from_holidays() {
+        // 2024-09-18: Rằm tháng 8 and a partial lunar eclipse
+        let app = App::new_with_date(Some(
+            NaiveDate::from_ymd_opt(2024, 9, 18).expect("valid test date"),
+        ));
+
+        let eclipse = app.eclipse_for_day(18).expect("eclipse on 18/9");
+        assert_eq!(eclipse.name, "Nguyệt thực một phần");
+        assert_ne!(
+            app.holiday_for_day(18).map(|h| h.category.as_str()),
+            Some("astronomy")
+        );
+        assert!(app.eclipse_for_day(17).is_none());
+    }
 }
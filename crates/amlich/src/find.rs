@@ -0,0 +1,102 @@
+use amlich_api::{find_good_days, DateDto, GoodDayDto, GoodDayQuery, PersonDto};
+use amlich_core::lunar::SolarDate;
+use chrono::NaiveDate;
+
+use crate::headless::parse_date;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FindFormat {
+    Json,
+    Text,
+}
+
+/// Parse a person given as `YYYY-MM-DD:GENDER[:HOUR]`, e.g. `1990-06-01:male:8`
+pub fn parse_person(input: &str) -> Result<PersonDto, String> {
+    let invalid = || format!("invalid person '{input}', expected YYYY-MM-DD:male|female[:HOUR]");
+    let parts: Vec<&str> = input.split(':').collect();
+    let (date, gender, hour) = match parts.as_slice() {
+        [date, gender] => (date, gender, None),
+        [date, gender, hour] => (date, gender, Some(hour)),
+        _ => return Err(invalid()),
+    };
+
+    let birth = SolarDate::from(parse_date(date)?);
+    let gender = gender.to_lowercase();
+    if !matches!(gender.as_str(), "male" | "female") {
+        return Err(invalid());
+    }
+    let birth_hour = hour
+        .map(|hour| {
+            hour.parse::<u32>()
+                .ok()
+                .filter(|hour| *hour < 24)
+                .ok_or_else(invalid)
+        })
+        .transpose()?;
+
+    Ok(PersonDto {
+        birth_day: birth.day,
+        birth_month: birth.month,
+        birth_year: birth.year,
+        gender,
+        birth_hour,
+    })
+}
+
+pub fn date_dto(date: NaiveDate) -> DateDto {
+    let date = SolarDate::from(date);
+    DateDto {
+        day: date.day,
+        month: date.month,
+        year: date.year,
+    }
+}
+
+pub fn find(query: &GoodDayQuery, format: FindFormat, pretty: bool) -> Result<String, String> {
+    let picks = find_good_days(query)?;
+    match format {
+        FindFormat::Json if pretty => {
+            serde_json::to_string_pretty(&picks).map_err(|e| format!("failed to render json: {e}"))
+        }
+        FindFormat::Json => {
+            serde_json::to_string(&picks).map_err(|e| format!("failed to render json: {e}"))
+        }
+        FindFormat::Text => Ok(format_text(&picks)),
+    }
+}
+
+fn format_text(picks: &[GoodDayDto]) -> String {
+    if picks.is_empty() {
+        return "no matching days".to_string();
+    }
+
+    picks
+        .iter()
+        .enumerate()
+        .map(|(rank, pick)| {
+            let hours = pick
+                .good_hours
+                .iter()
+                .map(|hour| format!("{} {}", hour.hour_chi, hour.time_range))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let mut lines = vec![
+                format!(
+                    "{}. {} ({}) | lunar {} | {} | {} {} ({})",
+                    rank + 1,
+                    pick.solar.date_string,
+                    pick.solar.day_of_week_name,
+                    pick.lunar.date_string,
+                    pick.day_canchi,
+                    pick.score.activity_name,
+                    pick.score.score,
+                    pick.score.verdict
+                ),
+                format!("   hours: {hours}"),
+            ];
+            lines.extend(pick.reasons.iter().map(|reason| format!("   - {reason}")));
+            lines.join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -3,8 +3,15 @@ use std::fs;
 use std::path::PathBuf;
 use std::str::FromStr;
 
-use amlich_api::{get_day_info_for_date, DayInfoDto};
-use chrono::{Datelike, Local, NaiveDate};
+use amlich_api::{
+    get_day_info, DateQuery, DayInfoDto, HourReckoningDto, LocationDto, HISTORICAL_VIETNAM_TIMEZONE,
+};
+use amlich_core::chrono_interop::naive_date_from_jd;
+use amlich_core::historical::TimezonePolicy;
+use amlich_core::julian::{is_valid_date, jd_from_date};
+use amlich_core::lunar::SolarDate;
+use amlich_core::timezone::TimeZone;
+use chrono::{Local, NaiveDate, Timelike, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::waybar;
@@ -72,9 +79,78 @@ pub struct QueryResult {
     pub warning: Option<String>,
 }
 
+/// Parse a YYYY-MM-DD date; like the core, dates before 1582-10-15 are
+/// Julian, so 1500-02-29 exists and the ten days skipped by the Gregorian
+/// reform are rejected
+///
+/// The `NaiveDate` is the same day on chrono's proleptic Gregorian
+/// calendar; read the calendar date back with `SolarDate::from`.
 pub fn parse_date(date_str: &str) -> Result<NaiveDate, String> {
-    NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
-        .map_err(|_| format!("invalid date '{date_str}', expected format YYYY-MM-DD"))
+    let invalid = || format!("invalid date '{date_str}', expected format YYYY-MM-DD");
+    let parts = date_str
+        .split('-')
+        .map(|part| part.parse::<i32>().map_err(|_| invalid()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let [year, month, day] = parts[..] else {
+        return Err(invalid());
+    };
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return Err(invalid());
+    }
+    if (year, month) == (1582, 10) && (5..=14).contains(&day) {
+        return Err(format!(
+            "invalid date '{date_str}', skipped by the Gregorian reform of 1582"
+        ));
+    }
+    if !is_valid_date(day, month, year) {
+        return Err(format!(
+            "invalid date '{date_str}', day {day} does not exist in {year}-{month:02}"
+        ));
+    }
+    naive_date_from_jd(jd_from_date(day, month, year)).ok_or_else(invalid)
+}
+
+/// Parse a location given as `LAT,LON` or `LAT,LON,ELEVATION`
+pub fn parse_location(input: &str) -> Result<LocationDto, String> {
+    let invalid = || format!("invalid location '{input}', expected LAT,LON[,ELEVATION]");
+    let parts = input
+        .split(',')
+        .map(|part| part.trim().parse::<f64>().map_err(|_| invalid()))
+        .collect::<Result<Vec<_>, _>>()?;
+    match parts.as_slice() {
+        [latitude, longitude] => Ok(LocationDto {
+            latitude: *latitude,
+            longitude: *longitude,
+            elevation: 0.0,
+        }),
+        [latitude, longitude, elevation] => Ok(LocationDto {
+            latitude: *latitude,
+            longitude: *longitude,
+            elevation: *elevation,
+        }),
+        _ => Err(invalid()),
+    }
+}
+
+/// Parse a time zone given as a UTC offset (`+07:00`), an IANA name or
+/// `historical-vn` (UTC+8 before 1967-08-08, as the North computed it)
+pub fn parse_timezone(input: &str) -> Result<TimezonePolicy, String> {
+    if input == HISTORICAL_VIETNAM_TIMEZONE {
+        return Ok(TimezonePolicy::HistoricalVietnam);
+    }
+    input
+        .parse()
+        .map(TimezonePolicy::Zone)
+        .map_err(|err: amlich_core::timezone::ParseTimeZoneError| err.to_string())
+}
+
+/// `timezone` value of an API query for a parsed `--tz`
+pub fn timezone_value(policy: TimezonePolicy) -> String {
+    match policy {
+        TimezonePolicy::Fixed(hours) => TimeZone::from_hours(hours).to_string(),
+        TimezonePolicy::HistoricalVietnam => HISTORICAL_VIETNAM_TIMEZONE.to_string(),
+        TimezonePolicy::Zone(zone) => zone.to_string(),
+    }
 }
 
 pub fn read_mode() -> DisplayMode {
@@ -101,9 +177,23 @@ pub fn query(
     format: QueryFormat,
     mode: Option<DisplayMode>,
     pretty: bool,
+    location: Option<LocationDto>,
+    timezone: Option<TimezonePolicy>,
+    hour_reckoning: Option<HourReckoningDto>,
 ) -> Result<QueryResult, String> {
-    let date = date.unwrap_or_else(|| Local::now().date_naive());
-    let info = get_day_info(date)?;
+    // "Now" is the time in the requested zone, not on this machine
+    let utc = Utc::now().naive_utc();
+    let now = match timezone {
+        Some(TimezonePolicy::Zone(zone)) => zone.local_datetime(utc),
+        Some(policy) => {
+            let today = SolarDate::from(utc.date());
+            TimeZone::from_hours(policy.offset_for(today.day, today.month, today.year))
+                .local_datetime(utc)
+        }
+        None => Local::now().naive_local(),
+    };
+    let date = date.unwrap_or(now.date());
+    let info = get_day_info_at(date, location, timezone, hour_reckoning)?;
 
     let result = match format {
         QueryFormat::DayInfoJson => {
@@ -121,7 +211,8 @@ pub fn query(
         }
         QueryFormat::Waybar => {
             let effective_mode = mode.unwrap_or_else(read_mode);
-            let payload = waybar::build_waybar_payload(&info, &effective_mode);
+            let minute_of_day = (now.hour() * 60 + now.minute()) as i32;
+            let payload = waybar::build_waybar_payload(&info, &effective_mode, minute_of_day);
             let output = if pretty {
                 serde_json::to_string_pretty(&payload)
                     .map_err(|e| format!("failed to render waybar json: {e}"))?
@@ -167,8 +258,21 @@ fn format_text(info: &DayInfoDto, mode: DisplayMode) -> String {
     )
 }
 
-fn get_day_info(date: NaiveDate) -> Result<DayInfoDto, String> {
-    get_day_info_for_date(date.day() as i32, date.month() as i32, date.year())
+fn get_day_info_at(
+    date: NaiveDate,
+    location: Option<LocationDto>,
+    timezone: Option<TimezonePolicy>,
+    hour_reckoning: Option<HourReckoningDto>,
+) -> Result<DayInfoDto, String> {
+    let date = SolarDate::from(date);
+    get_day_info(&DateQuery {
+        day: date.day,
+        month: date.month,
+        year: date.year,
+        timezone: timezone.map(timezone_value),
+        location,
+        hour_reckoning,
+    })
 }
 
 fn get_state_dir() -> PathBuf {
@@ -2,6 +2,7 @@ mod app;
 mod bookmark_store;
 mod date_jump;
 mod event;
+mod find;
 mod headless;
 mod history;
 mod search;
@@ -14,10 +15,16 @@ mod widgets;
 use std::ffi::OsString;
 use std::io::{stdin, stdout, IsTerminal};
 
+use amlich_api::HourReckoningDto;
+use amlich_core::almanac::types::Activity;
+use amlich_core::lunar::SolarDate;
+use chrono::NaiveDate;
 use clap::{Args, Parser, Subcommand, ValueEnum};
 
+use crate::find::{date_dto, find, parse_person, FindFormat};
 use crate::headless::{
-    parse_date, query, read_mode, set_mode, toggle_mode, DisplayMode, QueryFormat,
+    parse_date, parse_location, parse_timezone, query, read_mode, set_mode, timezone_value,
+    toggle_mode, DisplayMode, QueryFormat,
 };
 use crate::tui_runtime::run_tui;
 
@@ -39,6 +46,8 @@ enum Command {
     Tui(TuiArgs),
     /// Query date information without launching the TUI
     Query(QueryArgs),
+    /// Search a date range for good days for an activity
+    Find(FindArgs),
     /// Manage persistent user settings
     Config(ConfigArgs),
 }
@@ -48,6 +57,34 @@ struct TuiArgs {
     /// Start TUI focused on a specific date in YYYY-MM-DD format
     #[arg(long, value_name = "DATE")]
     date: Option<String>,
+
+    /// Observer location as LAT,LON[,ELEVATION], used by --hours
+    #[arg(long, value_parser = parse_location, value_name = "LAT,LON")]
+    location: Option<amlich_api::LocationDto>,
+
+    /// How hour branches are placed on the clock
+    #[arg(long, value_enum, value_name = "RECKONING", requires = "location")]
+    hours: Option<HoursArg>,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum HoursArg {
+    /// Two clock hours per branch, Tý at 23:00-01:00
+    Clock,
+    /// Local mean solar time at the location's longitude
+    LocalMean,
+    /// Apparent solar time (local mean time plus the equation of time)
+    Apparent,
+}
+
+impl From<HoursArg> for HourReckoningDto {
+    fn from(value: HoursArg) -> Self {
+        match value {
+            HoursArg::Clock => HourReckoningDto::Clock,
+            HoursArg::LocalMean => HourReckoningDto::LocalMean,
+            HoursArg::Apparent => HourReckoningDto::Apparent,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, ValueEnum)]
@@ -69,7 +106,8 @@ impl From<OutputFormat> for QueryFormat {
 
 #[derive(Args)]
 struct QueryArgs {
-    /// Date in YYYY-MM-DD format (defaults to today)
+    /// Date in YYYY-MM-DD format (defaults to today); Julian calendar
+    /// before 1582-10-15, years 1000-3000
     #[arg(value_name = "DATE")]
     date: Option<String>,
 
@@ -84,6 +122,109 @@ struct QueryArgs {
     /// Pretty-print JSON formats
     #[arg(long)]
     pretty: bool,
+
+    /// Observer location as LAT,LON[,ELEVATION] for sunrise and sunset
+    #[arg(long, value_parser = parse_location, value_name = "LAT,LON")]
+    location: Option<amlich_api::LocationDto>,
+
+    /// Time zone as a UTC offset (+07:00), IANA name (Australia/Sydney) or
+    /// historical-vn (the North's calendar, UTC+8 before 1967-08-08);
+    /// defaults to Vietnam, UTC+7
+    #[arg(long, value_parser = parse_timezone, value_name = "ZONE")]
+    tz: Option<amlich_core::historical::TimezonePolicy>,
+
+    /// How hour branches are placed on the clock; the solar reckonings
+    /// follow the --location longitude
+    #[arg(long, value_enum, value_name = "RECKONING", requires = "location")]
+    hours: Option<HoursArg>,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum WeekdayArg {
+    Sun,
+    Mon,
+    Tue,
+    Wed,
+    Thu,
+    Fri,
+    Sat,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum FindOutputFormat {
+    Json,
+    Text,
+}
+
+impl From<FindOutputFormat> for FindFormat {
+    fn from(value: FindOutputFormat) -> Self {
+        match value {
+            FindOutputFormat::Json => FindFormat::Json,
+            FindOutputFormat::Text => FindFormat::Text,
+        }
+    }
+}
+
+#[derive(Args)]
+struct FindArgs {
+    /// Activity to find days for, e.g. cuoi-hoi or xuat-hanh
+    #[arg(value_parser = parse_activity, value_name = "ACTIVITY")]
+    activity: Activity,
+
+    /// First date searched, YYYY-MM-DD
+    #[arg(long, value_name = "DATE")]
+    from: String,
+
+    /// Last date searched (inclusive), YYYY-MM-DD
+    #[arg(long, value_name = "DATE")]
+    to: String,
+
+    /// Keep hoàng đạo days only
+    #[arg(long)]
+    hoang_dao: bool,
+
+    /// Drop days with any taboo, not only the activity's hard taboos
+    #[arg(long)]
+    no_taboos: bool,
+
+    /// Person whose tuổi must not clash, as YYYY-MM-DD:male|female[:HOUR];
+    /// repeat for several persons
+    #[arg(long = "person", value_parser = parse_person, value_name = "PERSON")]
+    persons: Vec<amlich_api::PersonDto>,
+
+    /// Days of the week to keep, e.g. --weekday sat,sun
+    #[arg(
+        long = "weekday",
+        value_enum,
+        value_delimiter = ',',
+        value_name = "DAY"
+    )]
+    weekdays: Vec<WeekdayArg>,
+
+    /// Maximum number of days listed
+    #[arg(long, default_value_t = 10)]
+    limit: usize,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = FindOutputFormat::Text)]
+    format: FindOutputFormat,
+
+    /// Pretty-print JSON output
+    #[arg(long)]
+    pretty: bool,
+
+    /// Time zone as a UTC offset (+07:00), IANA name or historical-vn;
+    /// defaults to Vietnam, UTC+7
+    #[arg(long, value_parser = parse_timezone, value_name = "ZONE")]
+    tz: Option<amlich_core::historical::TimezonePolicy>,
+
+    /// Observer location as LAT,LON[,ELEVATION], used by --hours
+    #[arg(long, value_parser = parse_location, value_name = "LAT,LON")]
+    location: Option<amlich_api::LocationDto>,
+
+    /// How hour branches are placed on the clock
+    #[arg(long, value_enum, value_name = "RECKONING", requires = "location")]
+    hours: Option<HoursArg>,
 }
 
 #[derive(Args)]
@@ -117,6 +258,21 @@ fn parse_mode(input: &str) -> Result<DisplayMode, String> {
     input.parse()
 }
 
+/// Activities are written in kebab-case on the command line (`cuoi-hoi`)
+/// for the ruleset's policy ids (`cuoi_hoi`).
+fn parse_activity(input: &str) -> Result<Activity, String> {
+    input.replace('-', "_").parse().map_err(|_| {
+        let expected: Vec<String> = Activity::ALL
+            .iter()
+            .map(|activity| activity.id().replace('_', "-"))
+            .collect();
+        format!(
+            "unknown activity '{input}', expected one of: {}",
+            expected.join(", ")
+        )
+    })
+}
+
 fn main() {
     let args = match rewrite_headless_alias(std::env::args_os().collect()) {
         Ok(args) => args,
@@ -137,10 +293,12 @@ fn main() {
 fn run(cli: Cli) -> Result<(), String> {
     match cli.command {
         Some(Command::Tui(args)) => {
-            let date = args.date.as_deref().map(parse_date).transpose()?;
-            run_tui(date).map_err(|e| format!("failed to run TUI: {e}"))?;
+            let date = args.date.as_deref().map(parse_tui_date).transpose()?;
+            run_tui(date, args.location, args.hours.map(Into::into))
+                .map_err(|e| format!("failed to run TUI: {e}"))?;
         }
         Some(Command::Query(args)) => run_query(args)?,
+        Some(Command::Find(args)) => run_find(args)?,
         Some(Command::Config(args)) => run_config(args)?,
         None => run_auto_mode()?,
     }
@@ -148,9 +306,25 @@ fn run(cli: Cli) -> Result<(), String> {
     Ok(())
 }
 
+/// The TUI lays out its month grid with chrono, so its date carries the
+/// calendar labels the user typed rather than the absolute day.
+fn parse_tui_date(s: &str) -> Result<NaiveDate, String> {
+    let solar = SolarDate::from(parse_date(s)?);
+    NaiveDate::from_ymd_opt(solar.year, solar.month as u32, solar.day as u32)
+        .ok_or_else(|| format!("date '{s}' cannot be shown on the TUI calendar grid"))
+}
+
 fn run_query(args: QueryArgs) -> Result<(), String> {
     let date = args.date.as_deref().map(parse_date).transpose()?;
-    let result = query(date, args.format.into(), args.mode, args.pretty)?;
+    let result = query(
+        date,
+        args.format.into(),
+        args.mode,
+        args.pretty,
+        args.location,
+        args.tz,
+        args.hours.map(Into::into),
+    )?;
     if let Some(warning) = result.warning {
         eprintln!("Warning: {warning}");
     }
@@ -158,6 +332,24 @@ fn run_query(args: QueryArgs) -> Result<(), String> {
     Ok(())
 }
 
+fn run_find(args: FindArgs) -> Result<(), String> {
+    let query = amlich_api::GoodDayQuery {
+        activity: args.activity.id().to_string(),
+        start: date_dto(parse_date(&args.from)?),
+        end: date_dto(parse_date(&args.to)?),
+        timezone: args.tz.map(timezone_value),
+        location: args.location,
+        hour_reckoning: args.hours.map(Into::into),
+        hoang_dao_only: args.hoang_dao,
+        exclude_taboos: args.no_taboos,
+        persons: args.persons,
+        weekdays: args.weekdays.iter().map(|&day| day as usize).collect(),
+        limit: Some(args.limit),
+    };
+    println!("{}", find(&query, args.format.into(), args.pretty)?);
+    Ok(())
+}
+
 fn run_config(args: ConfigArgs) -> Result<(), String> {
     match args.command {
         ConfigCommand::Mode(mode_args) => match mode_args.command {
@@ -180,12 +372,20 @@ fn run_config(args: ConfigArgs) -> Result<(), String> {
 
 fn run_auto_mode() -> Result<(), String> {
     if stdin().is_terminal() && stdout().is_terminal() {
-        run_tui(None).map_err(|e| format!("failed to run TUI: {e}"))?;
+        run_tui(None, None, None).map_err(|e| format!("failed to run TUI: {e}"))?;
         return Ok(());
     }
 
     let mode = read_mode();
-    let result = query(None, QueryFormat::Waybar, Some(mode), false)?;
+    let result = query(
+        None,
+        QueryFormat::Waybar,
+        Some(mode),
+        false,
+        None,
+        None,
+        None,
+    )?;
     println!("{}", result.output);
     Ok(())
 }
@@ -202,7 +402,7 @@ fn rewrite_headless_alias(args: Vec<OsString>) -> Result<Vec<OsString>, String>
         .get(1)
         .map(|value| value.to_string_lossy().to_string());
     if let Some(value) = next {
-        if matches!(value.as_str(), "query" | "tui" | "config") {
+        if matches!(value.as_str(), "query" | "tui" | "find" | "config") {
             return Err(
                 "--headless cannot be used with subcommands; use `amlich query ...`".into(),
             );
@@ -1,4 +1,5 @@
 use crate::history::HistoryEntry;
+use amlich_core::lunar::LunarDate;
 use chrono::{Local, NaiveDate};
 use deunicode::deunicode;
 use std::collections::HashMap;
@@ -71,16 +72,13 @@ pub fn search_entries(view_year: i32, raw_query: &str) -> Vec<SearchResult> {
             let name_matches = name_normalized.contains(query) || desc_normalized.contains(query);
 
             if name_matches {
-                if let Some(entry) =
-                    HistoryEntry::new(year, holiday.solar_month as u32, holiday.solar_day as u32)
-                {
-                    // Calculate lunar date
-                    let lunar = amlich_core::lunar::convert_solar_to_lunar(
-                        holiday.solar_day,
-                        holiday.solar_month,
-                        holiday.solar_year,
-                        amlich_core::VIETNAM_TIMEZONE,
-                    );
+                if let Some(date) = NaiveDate::from_ymd_opt(
+                    year,
+                    holiday.solar_month as u32,
+                    holiday.solar_day as u32,
+                ) {
+                    let entry = HistoryEntry::from_date(date);
+                    let lunar = LunarDate::from_solar(date);
                     let lunar_str = format_lunar(lunar.day, lunar.month);
 
                     let key = (entry.year, entry.month, entry.day);
@@ -98,13 +96,9 @@ pub fn search_entries(view_year: i32, raw_query: &str) -> Vec<SearchResult> {
         let tet_name = "Tết Nguyên Đán".to_string();
         for year in (view_year - 1)..=(view_year + 1) {
             for day in 20..=31 {
-                if let Some(entry) = HistoryEntry::new(year, 1, day) {
-                    let lunar = amlich_core::lunar::convert_solar_to_lunar(
-                        day as i32,
-                        1,
-                        year,
-                        amlich_core::VIETNAM_TIMEZONE,
-                    );
+                if let Some(date) = NaiveDate::from_ymd_opt(year, 1, day) {
+                    let entry = HistoryEntry::from_date(date);
+                    let lunar = LunarDate::from_solar(date);
                     let key = (entry.year, entry.month, entry.day);
                     results_map.entry(key).or_insert_with(|| {
                         SearchResult::new(
@@ -116,13 +110,9 @@ pub fn search_entries(view_year: i32, raw_query: &str) -> Vec<SearchResult> {
                 }
             }
             for day in 1..=19 {
-                if let Some(entry) = HistoryEntry::new(year, 2, day) {
-                    let lunar = amlich_core::lunar::convert_solar_to_lunar(
-                        day as i32,
-                        2,
-                        year,
-                        amlich_core::VIETNAM_TIMEZONE,
-                    );
+                if let Some(date) = NaiveDate::from_ymd_opt(year, 2, day) {
+                    let entry = HistoryEntry::from_date(date);
+                    let lunar = LunarDate::from_solar(date);
                     let key = (entry.year, entry.month, entry.day);
                     results_map.entry(key).or_insert_with(|| {
                         SearchResult::new(
@@ -1,5 +1,6 @@
 use std::io;
 
+use amlich_api::{HourReckoningDto, LocationDto};
 use chrono::NaiveDate;
 use crossterm::{
     cursor::Show,
@@ -20,7 +21,11 @@ impl Drop for TerminalCleanupGuard {
     }
 }
 
-pub fn run_tui(initial_date: Option<NaiveDate>) -> io::Result<()> {
+pub fn run_tui(
+    initial_date: Option<NaiveDate>,
+    location: Option<LocationDto>,
+    hour_reckoning: Option<HourReckoningDto>,
+) -> io::Result<()> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
@@ -28,7 +33,7 @@ pub fn run_tui(initial_date: Option<NaiveDate>) -> io::Result<()> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let result = run(&mut terminal, initial_date);
+    let result = run(&mut terminal, initial_date, location, hour_reckoning);
 
     let _ = terminal.show_cursor();
 
@@ -38,8 +43,10 @@ pub fn run_tui(initial_date: Option<NaiveDate>) -> io::Result<()> {
 fn run(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     initial_date: Option<NaiveDate>,
+    location: Option<LocationDto>,
+    hour_reckoning: Option<HourReckoningDto>,
 ) -> io::Result<()> {
-    let mut app = App::new_with_date(initial_date);
+    let mut app = App::new_with_date(initial_date).with_hours(location, hour_reckoning);
 
     while app.running {
         terminal.draw(|frame| ui::draw(frame, &app))?;
@@ -1,5 +1,6 @@
 use amlich_api::{get_holidays, DayInfoDto};
-use chrono::{Local, NaiveDate, Timelike};
+use amlich_core::gio_hoang_dao::hour_chi_at;
+use chrono::NaiveDate;
 use serde_json::Value;
 
 use crate::headless::DisplayMode;
@@ -42,13 +43,10 @@ fn format_minimal(info: &DayInfoDto) -> String {
     format!("{}/{}", info.lunar.day, info.lunar.month)
 }
 
-fn current_hour_chi_index() -> usize {
-    let hour = Local::now().hour() as usize;
-    if hour == 23 {
-        0
-    } else {
-        hour.div_ceil(2)
-    }
+/// Hour branch in effect at a clock minute, from the day's hour boundaries
+fn current_hour_chi_index(info: &DayInfoDto, minute_of_day: i32) -> usize {
+    let starts = std::array::from_fn(|i| info.gio_hoang_dao.all_hours[i].start_minute);
+    hour_chi_at(&starts, minute_of_day).index()
 }
 
 fn next_good_hours(info: &DayInfoDto, current_chi: usize, max_count: usize) -> Vec<String> {
@@ -118,7 +116,7 @@ fn format_holiday_line(info: &DayInfoDto) -> Option<String> {
     None
 }
 
-fn format_tooltip(info: &DayInfoDto) -> String {
+fn format_tooltip(info: &DayInfoDto, minute_of_day: i32) -> String {
     let mut lines = Vec::new();
 
     lines.push(format!(
@@ -143,8 +141,15 @@ fn format_tooltip(info: &DayInfoDto) -> String {
         "   Kinh độ mặt trời: {:.1}°",
         info.tiet_khi.current_longitude
     ));
+    if let Some(sun) = &info.sun_times {
+        lines.push(format!(
+            "🌅 Mặt trời mọc {} • lặn {}",
+            sun.sunrise.as_deref().unwrap_or("--:--"),
+            sun.sunset.as_deref().unwrap_or("--:--")
+        ));
+    }
 
-    let current_idx = current_hour_chi_index();
+    let current_idx = current_hour_chi_index(info, minute_of_day);
     let current = &info.gio_hoang_dao.all_hours[current_idx];
     let state = if current.is_good { "✅" } else { "❌" };
 
@@ -168,7 +173,7 @@ fn format_tooltip(info: &DayInfoDto) -> String {
     lines.join("\n")
 }
 
-pub fn build_waybar_payload(info: &DayInfoDto, mode: &DisplayMode) -> Value {
+pub fn build_waybar_payload(info: &DayInfoDto, mode: &DisplayMode, minute_of_day: i32) -> Value {
     let text = match mode {
         DisplayMode::Full => format_full(info),
         DisplayMode::Lunar => format_lunar(info),
@@ -176,7 +181,7 @@ pub fn build_waybar_payload(info: &DayInfoDto, mode: &DisplayMode) -> Value {
         DisplayMode::Minimal => format_minimal(info),
     };
 
-    let tooltip = format_tooltip(info);
+    let tooltip = format_tooltip(info, minute_of_day);
     let class = mode.to_string();
 
     serde_json::json!({
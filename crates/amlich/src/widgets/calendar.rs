@@ -88,7 +88,8 @@ impl Widget for CalendarWidget<'_> {
                 let is_selected = day == self.app.selected_day;
                 let is_today = self.app.is_today(day);
                 let is_weekend = col >= 5;
-                let has_holiday = self.app.holiday_for_day(day).is_some();
+                let has_holiday = self.app.holiday_for_day(day).is_some()
+                    || self.app.eclipse_for_day(day).is_some();
                 let is_bookmarked = self.app.bookmarks.contains(&HistoryEntry {
                     year: self.app.view_year,
                     month: self.app.view_month,
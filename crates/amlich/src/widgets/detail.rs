@@ -169,6 +169,29 @@ impl Widget for DetailWidget<'_> {
             ]));
         }
 
+        if let Some(eclipse) = self.app.eclipse_for_day(self.app.selected_day) {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "🌒 Thiên Văn",
+                Style::default()
+                    .fg(theme::TITLE_FG)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(vec![
+                Span::styled("   ", Style::default()),
+                Span::styled(
+                    &eclipse.name,
+                    Style::default()
+                        .fg(theme::HOLIDAY_FG)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("   ", Style::default()),
+                Span::styled(&eclipse.description, Style::default().fg(theme::LUNAR_FG)),
+            ]));
+        }
+
         let p = Paragraph::new(lines)
             .block(block)
             .wrap(Wrap { trim: false });
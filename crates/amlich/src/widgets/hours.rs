@@ -28,10 +28,11 @@ impl<'a> HoursWidget<'a> {
     }
 }
 
-/// Shorten "23:00-01:00" to "23-01"
+/// Shorten "23:00-01:00" to "23-01"; solar boundaries such as
+/// "22:57-00:57" keep their minutes
 fn shorten_time_range(range: &str) -> String {
     let parts: Vec<&str> = range.split('-').collect();
-    if parts.len() == 2 {
+    if parts.len() == 2 && parts.iter().all(|part| part.ends_with(":00")) {
         let start = parts[0].split(':').next().unwrap_or(parts[0]);
         let end = parts[1].split(':').next().unwrap_or(parts[1]);
         format!("{}-{}", start, end)
@@ -117,6 +118,12 @@ impl Widget for HoursWidget<'_> {
         } else {
             // Full mode
             lines.push(Line::from(""));
+            let time_width = ghd
+                .all_hours
+                .iter()
+                .map(|hour| shorten_time_range(&hour.time_range).len() + 2)
+                .max()
+                .unwrap_or(7);
 
             for hour in &ghd.all_hours {
                 let marker = if hour.is_good { "★" } else { "·" };
@@ -138,8 +145,8 @@ impl Widget for HoursWidget<'_> {
 
                 lines.push(Line::from(vec![
                     Span::styled(format!(" {} ", marker), star_style),
-                    Span::styled(format!("{:<4}", hour.hour_chi), style),
-                    Span::styled(format!("{:<7}", short_time), style),
+                    Span::styled(format!("{:<10}", hour.canchi.full), style),
+                    Span::styled(format!("{:<time_width$}", short_time), style),
                     Span::styled(&hour.star, style),
                 ]));
             }
@@ -345,6 +345,23 @@ impl<'a> InfoPanel<'a> {
             }
         }
 
+        if let Some(eclipse) = self.app.eclipse_for_day(self.app.selected_day) {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled("── Thiên văn ", theme::section_style()),
+                Span::styled(
+                    eclipse.name.clone(),
+                    Style::default()
+                        .fg(theme::HOLIDAY_FG)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]));
+            lines.push(Line::from(Span::styled(
+                format!("   {}", eclipse.description),
+                Style::default().fg(theme::SECONDARY_FG),
+            )));
+        }
+
         lines
     }
 }
@@ -292,3 +292,357 @@ fn invalid_date_returns_error() {
     let stderr = String::from_utf8_lossy(&output.stderr);
     assert!(stderr.contains("invalid date"));
 }
+
+#[test]
+fn query_location_adds_sun_times() {
+    let home = temp_home();
+    let output = run(
+        &home,
+        &["query", "2024-06-21", "--location", "21.0285,105.8542"],
+    );
+    assert!(
+        output.status.success(),
+        "command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: Value = serde_json::from_slice(&output.stdout).expect("stdout should be valid json");
+    assert_eq!(json["sun_times"]["sunrise"], "05:16");
+    assert_eq!(json["sun_times"]["sunset"], "18:41");
+
+    let output = run(
+        &home,
+        &[
+            "query",
+            "2024-06-21",
+            "--format",
+            "waybar",
+            "--location",
+            "21.0285,105.8542",
+        ],
+    );
+    let json: Value = serde_json::from_slice(&output.stdout).expect("stdout should be valid json");
+    let tooltip = json["tooltip"]
+        .as_str()
+        .expect("tooltip should be a string");
+    assert!(tooltip.contains("Mặt trời mọc 05:16 • lặn 18:41"));
+
+    let output = run(&home, &["query", "--location", "north"]);
+    assert!(!output.status.success(), "command should fail");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid location"));
+}
+
+#[test]
+fn query_tz_uses_local_new_moon() {
+    let home = temp_home();
+    // The 2024-04-08 new moon falls on the 8th in California, the 9th in Vietnam
+    let output = run(&home, &["query", "2024-04-08"]);
+    let json: Value = serde_json::from_slice(&output.stdout).expect("stdout should be valid json");
+    assert_eq!(json["lunar"]["date_string"], "30/2/2024");
+
+    let output = run(
+        &home,
+        &["query", "2024-04-08", "--tz", "America/Los_Angeles"],
+    );
+    assert!(
+        output.status.success(),
+        "command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: Value = serde_json::from_slice(&output.stdout).expect("stdout should be valid json");
+    assert_eq!(json["lunar"]["date_string"], "1/3/2024");
+
+    let output = run(&home, &["query", "--tz", "Mars/Olympus"]);
+    assert!(!output.status.success(), "command should fail");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown time zone"));
+}
+
+#[test]
+fn tz_historical_vn_uses_utc8_before_1967() {
+    let home = temp_home();
+    // New moon of 1956-11-02 16:43 UTC: 2 Nov at UTC+7, 3 Nov at UTC+8
+    let output = run(
+        &home,
+        &[
+            "query",
+            "1956-11-03",
+            "--format",
+            "text",
+            "--tz",
+            "historical-vn",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("lunar 1/10/1956"), "{stdout}");
+
+    let output = run(&home, &["query", "1956-11-03", "--format", "text"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("lunar 2/10/1956"), "{stdout}");
+
+    let output = run(
+        &home,
+        &[
+            "find",
+            "xuat-hanh",
+            "--from",
+            "1956-11-01",
+            "--to",
+            "1956-11-30",
+            "--format",
+            "json",
+            "--tz",
+            "historical-vn",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: Value = serde_json::from_slice(&output.stdout).expect("stdout should be valid json");
+    for pick in json.as_array().expect("top-level should be array") {
+        if pick["solar"]["day"] == 3 {
+            assert_eq!(pick["lunar"]["date_string"], "1/10/1956");
+        }
+    }
+
+    // Today's date is after 1967, so "now" is read at UTC+7
+    let output = run(&home, &["query", "--tz", "historical-vn"]);
+    assert!(output.status.success());
+}
+
+#[test]
+fn query_hours_uses_local_solar_time() {
+    let home = temp_home();
+    let output = run(
+        &home,
+        &[
+            "query",
+            "2024-11-03",
+            "--location",
+            "21.0285,105.8542",
+            "--hours",
+            "apparent",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: Value = serde_json::from_slice(&output.stdout).expect("stdout should be valid json");
+    assert_eq!(
+        json["gio_hoang_dao"]["all_hours"][6]["time_range"],
+        "10:40-12:40"
+    );
+
+    let output = run(&home, &["query", "--hours", "local-mean"]);
+    assert!(
+        !output.status.success(),
+        "--hours should require --location"
+    );
+}
+
+#[test]
+fn waybar_snippet_with_location_keeps_persisted_mode() {
+    let home = temp_home();
+    let set = run(&home, &["config", "mode", "set", "minimal"]);
+    assert!(set.status.success());
+
+    // The command documented in waybar/modules/amlich.jsonc
+    let output = run(
+        &home,
+        &[
+            "query",
+            "--format",
+            "waybar",
+            "--location",
+            "21.03,105.85",
+            "--hours",
+            "apparent",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be valid waybar json");
+    assert_eq!(json["class"], "minimal");
+    let tooltip = json["tooltip"]
+        .as_str()
+        .expect("tooltip should be a string");
+    assert!(tooltip.contains("Mặt trời mọc"), "{tooltip}");
+}
+
+#[test]
+fn query_supports_historical_dates_and_reports_range_errors() {
+    let home = temp_home();
+    let output = run(&home, &["query", "1450-01-14", "--format", "text"]);
+    assert!(
+        output.status.success(),
+        "command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("lunar 1/1/1450"));
+
+    let output = run(&home, &["query", "0999-06-01"]);
+    assert!(!output.status.success(), "command should fail");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("outside the supported range"));
+
+    let output = run(&home, &["query", "1582-10-10"]);
+    assert!(!output.status.success(), "command should fail");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Gregorian reform"));
+
+    // 1500 is a Julian leap year, though not a Gregorian one
+    let output = run(&home, &["query", "1500-02-29", "--format", "text"]);
+    assert!(
+        output.status.success(),
+        "command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("1500-02-29 | lunar 1/2/1500"));
+
+    let output = run(
+        &home,
+        &[
+            "find",
+            "xuat-hanh",
+            "--from",
+            "1500-02-29",
+            "--to",
+            "1500-03-05",
+            "--person",
+            "1480-01-01:male",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = run(&home, &["query", "1501-02-29"]);
+    assert!(!output.status.success(), "command should fail");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid date"));
+}
+
+#[test]
+fn find_lists_ranked_days_for_an_activity() {
+    let home = temp_home();
+    let output = run(
+        &home,
+        &[
+            "find",
+            "cuoi-hoi",
+            "--from",
+            "2024-03-01",
+            "--to",
+            "2024-03-31",
+            "--hoang-dao",
+            "--weekday",
+            "sat,sun",
+            "--person",
+            "1990-06-01:male:8",
+            "--limit",
+            "3",
+            "--format",
+            "json",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let json: Value = serde_json::from_slice(&output.stdout).expect("stdout should be valid json");
+    let picks = json.as_array().expect("top-level should be array");
+    assert!(!picks.is_empty() && picks.len() <= 3);
+    let scores: Vec<i64> = picks
+        .iter()
+        .map(|pick| pick["score"]["score"].as_i64().expect("score"))
+        .collect();
+    assert!(scores.windows(2).all(|pair| pair[0] >= pair[1]));
+    for pick in picks {
+        let weekday = pick["solar"]["day_of_week"].as_u64().expect("weekday");
+        assert!(weekday == 0 || weekday == 6);
+        assert_eq!(pick["score"]["activity"], "cuoi_hoi");
+        assert_ne!(pick["persons"][0]["verdict"], "xung");
+        assert!(!pick["reasons"].as_array().expect("reasons").is_empty());
+        assert!(!pick["good_hours"].as_array().expect("hours").is_empty());
+    }
+
+    let text = run(
+        &home,
+        &[
+            "find",
+            "an-tang",
+            "--from",
+            "2024-03-01",
+            "--to",
+            "2024-03-10",
+        ],
+    );
+    assert!(text.status.success());
+    let stdout = String::from_utf8_lossy(&text.stdout);
+    assert!(stdout.starts_with("1. 2024-03-"), "{stdout}");
+    assert!(stdout.contains("An táng"), "{stdout}");
+}
+
+#[test]
+fn find_reports_invalid_ranges_and_persons() {
+    let home = temp_home();
+    let output = run(
+        &home,
+        &[
+            "find",
+            "dong-tho",
+            "--from",
+            "2024-03-10",
+            "--to",
+            "2024-03-01",
+        ],
+    );
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("end date must not be before start date")
+    );
+
+    let output = run(
+        &home,
+        &[
+            "find",
+            "dong-tho",
+            "--from",
+            "2024-03-01",
+            "--to",
+            "2024-03-10",
+            "--person",
+            "1990-06-01:x",
+        ],
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid person"));
+
+    let output = run(
+        &home,
+        &[
+            "find",
+            "tam-bo",
+            "--from",
+            "2024-03-01",
+            "--to",
+            "2024-03-10",
+        ],
+    );
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("unknown activity 'tam-bo'"), "{stderr}");
+    assert!(stderr.contains("cuoi-hoi, dong-tho"), "{stderr}");
+}
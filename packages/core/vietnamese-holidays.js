@@ -11,7 +11,15 @@ function getVietnameseHolidays(solarYear) {
 
     // Function to convert lunar date to solar and add to holidays array
     function addLunarHoliday(name, lunarDay, lunarMonth, lunarYear, description = '') {
-        const solar = amlich.convertLunar2Solar(lunarDay, lunarMonth, lunarYear, 0, timeZone);
+        let solar = amlich.convertLunar2Solar(lunarDay, lunarMonth, lunarYear, 0, timeZone);
+        // Day-30 observances (Giao Thừa) fall on the last day of a 29-day month
+        if (lunarDay === 30 && solar[0] > 0) {
+            const lunar = amlich.convertSolar2Lunar(solar[0], solar[1], solar[2], timeZone);
+            if (lunar[0] !== 30) {
+                lunarDay = 29;
+                solar = amlich.convertLunar2Solar(lunarDay, lunarMonth, lunarYear, 0, timeZone);
+            }
+        }
         if (solar[0] > 0) { // Valid date
             holidays.push({
                 name,
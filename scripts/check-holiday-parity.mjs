@@ -78,7 +78,7 @@ const RUST_MAJOR_FIXTURE = {
     "2025-01-01|Tết Dương Lịch",
     "2025-01-09|Ngày Truyền Thống Học Sinh - Sinh Viên Việt Nam",
     "2025-01-22|Ông Táo chầu trời",
-    "2025-01-29|Giao Thừa",
+    "2025-01-28|Giao Thừa",
     "2025-01-29|Tết Nguyên Đán",
     "2025-01-30|Mùng 2 Tết",
     "2025-01-31|Mùng 3 Tết",
@@ -147,7 +147,7 @@ const RUST_MAJOR_FIXTURE = {
     "2026-02-03|Ngày Thành Lập Đảng Cộng Sản Việt Nam",
     "2026-02-10|Ông Táo chầu trời",
     "2026-02-14|Lễ Tình Nhân",
-    "2026-02-17|Giao Thừa",
+    "2026-02-16|Giao Thừa",
     "2026-02-17|Tết Nguyên Đán",
     "2026-02-18|Mùng 2 Tết",
     "2026-02-19|Mùng 3 Tết",
@@ -159,7 +159,7 @@ const RUST_MAJOR_FIXTURE = {
     "2026-03-22|Ngày Nước Thế Giới",
     "2026-03-26|Ngày Thành Lập Đoàn Thanh Niên Cộng Sản Hồ Chí Minh",
     "2026-04-01|Ngày Cá Tháng Tư",
-    "2026-04-05|Tết Thanh Minh",
+    "2026-04-06|Tết Thanh Minh",
     "2026-04-07|Ngày Sức Khỏe Thế Giới",
     "2026-04-19|Tết Hàn Thực",
     "2026-04-21|Ngày Sách và Văn Hóa Đọc Việt Nam",
@@ -184,7 +184,7 @@ const RUST_MAJOR_FIXTURE = {
     "2026-08-12|Ngày Quốc Tế Thanh Niên",
     "2026-08-19|Ngày Cách Mạng Tháng Tám",
     "2026-08-19|Ngày Truyền Thống Công An Nhân Dân",
-    "2026-08-27|Lễ Vu Lan",
+    "2026-08-26|Lễ Vu Lan",
     "2026-09-02|Ngày Quốc Khánh",
     "2026-09-08|Ngày Xóa Mù Chữ Quốc Tế",
     "2026-09-21|Ngày Quốc Tế Hòa Bình",
@@ -210,6 +210,15 @@ const RUST_MAJOR_FIXTURE = {
   ],
 };
 
+// Deliberate Rust/JS differences (docs/almanac/known-differences.md), as
+// Rust entry -> JS entry. JS fixes Thanh Minh on April 5 instead of the
+// solar term, and its new moon series follows the reference implementation
+// rather than Rust fast mode (docs/almanac/fast-vs-accurate.md).
+const KNOWN_JS_DIFFERENCES = {
+  "2026-04-06|Tết Thanh Minh": "2026-04-05|Tết Thanh Minh",
+  "2026-08-26|Lễ Vu Lan": "2026-08-27|Lễ Vu Lan",
+};
+
 const MAJOR_NAMES = new Set([
   ...lunarFestivals.festivals.map((festival) => festival.names.vi[0]),
   ...solarHolidays.holidays.filter((holiday) => holiday.isMajor).map((holiday) => holiday.names.vi[0]),
@@ -237,7 +246,7 @@ function diffSets(expected, actual) {
 let hasError = false;
 
 for (const year of [2024, 2025, 2026]) {
-  const expected = RUST_MAJOR_FIXTURE[year];
+  const expected = RUST_MAJOR_FIXTURE[year].map((item) => KNOWN_JS_DIFFERENCES[item] ?? item);
   const actual = getJsMajorSnapshot(year);
   const { missing, extra } = diffSets(expected, actual);
 